@@ -2,3 +2,5 @@ set -ex
 
 cd src/elastic
 cargo test
+cargo test --features string-as-keyword
+cargo test --features uuid-types
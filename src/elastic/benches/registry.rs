@@ -0,0 +1,84 @@
+//! Compare getting the cached json for a registered mapping with serialising it.
+//!
+//! Run with `cargo bench -p elastic --bench registry`.
+//! This bench doesn't use the unstable `#[bench]` harness, so it runs on stable Rust.
+
+extern crate elastic;
+#[macro_use]
+extern crate elastic_derive;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+
+use elastic::types::prelude::*;
+use std::{
+    hint::black_box,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+const ITERATIONS: u32 = 10_000;
+
+#[derive(Serialize, ElasticType)]
+struct Article {
+    pub id: Keyword<DefaultKeywordMapping>,
+    pub title: String,
+    pub summary: String,
+    pub body: String,
+    pub author: String,
+    pub tags: Vec<Keyword<DefaultKeywordMapping>>,
+    pub views: i64,
+    pub rating: f32,
+    pub published: bool,
+    pub created_at: Date<DefaultDateMapping>,
+    pub comments: Vec<Comment>,
+}
+
+#[derive(Serialize, ElasticType)]
+struct Comment {
+    pub author: String,
+    pub body: String,
+    pub created_at: Date<DefaultDateMapping>,
+}
+
+fn bench<F>(name: &str, mut f: F) -> Duration
+where
+    F: FnMut(),
+{
+    // Warm up, so the json for the registered mapping is already cached
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+
+    println!("{:<24} {:>10.0?} per iteration", name, elapsed / ITERATIONS);
+
+    elapsed
+}
+
+fn main() {
+    let mut registry = MappingRegistry::new();
+    registry.register::<Article>().unwrap();
+
+    let mapping = registry.get("article").unwrap();
+
+    let serialised = bench("serde_json::to_string", || {
+        black_box(serde_json::to_string(black_box(mapping)).unwrap());
+    });
+
+    let cached = bench("get_json", || {
+        black_box(registry.get_json(black_box("article")).unwrap());
+    });
+
+    println!(
+        "get_json is {:.0}x faster",
+        serialised.as_secs_f64() / cached.as_secs_f64()
+    );
+}
@@ -47,10 +47,14 @@ use crate::{
         SyncBody,
     },
     params::{
+        Id,
         Index,
         Type,
     },
-    types::document::DEFAULT_DOC_TYPE,
+    types::document::{
+        DocumentType,
+        DEFAULT_DOC_TYPE,
+    },
 };
 
 /**
@@ -791,6 +795,109 @@ impl BulkBody for Vec<u8> {
     }
 }
 
+/**
+A standalone bulk request body.
+
+The body is built from bulk operations without a `Client`, in the same newline-delimited format as a bulk request.
+Each operation is written as a json action line, followed by a json document line if it has one.
+Every line ends with a newline, including the last one.
+
+# Examples
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::prelude::*;
+# use elastic::client::requests::bulk::BulkBodyBuilder;
+# fn main() -> Result<(), Box<dyn ::std::error::Error>> {
+#[derive(Serialize, ElasticType)]
+#[elastic(index = "my_index")]
+struct MyType {
+    #[elastic(id)]
+    pub id: String,
+    pub title: String,
+}
+
+let body = BulkBodyBuilder::new()
+    .index(MyType {
+        id: "1".to_owned(),
+        title: "A title".to_owned(),
+    })
+    .delete::<MyType, _>("2")
+    .build()?;
+# Ok(())
+# }
+```
+*/
+pub struct BulkBodyBuilder {
+    body: WrappedBody<Vec<u8>>,
+}
+
+impl Default for BulkBodyBuilder {
+    fn default() -> Self {
+        BulkBodyBuilder::new()
+    }
+}
+
+impl BulkBodyBuilder {
+    /** Create an empty bulk body. */
+    pub fn new() -> Self {
+        BulkBodyBuilder {
+            body: WrappedBody::new(Vec::new()),
+        }
+    }
+
+    /** Push an operation to index a document. */
+    pub fn index<TDocument>(self, doc: TDocument) -> Self
+    where
+        TDocument: DocumentType + Serialize,
+    {
+        self.push(bulk::<TDocument>().index(doc))
+    }
+
+    /** Push an operation to delete a document with the given id. */
+    pub fn delete<TDocument, TId>(self, id: TId) -> Self
+    where
+        TDocument: DocumentType,
+        TId: Into<Id<'static>>,
+    {
+        self.push(bulk::<TDocument>().delete(id))
+    }
+
+    /** Push a bulk operation onto the end of the body. */
+    pub fn push<TDocument>(mut self, op: BulkOperation<TDocument>) -> Self
+    where
+        TDocument: Serialize,
+    {
+        self.body.with_inner_mut(|body| BulkBody::push(body, op));
+        self
+    }
+
+    /** Push multiple bulk operations onto the end of the body. */
+    pub fn extend<TIter, TDocument>(mut self, ops: TIter) -> Self
+    where
+        TIter: IntoIterator<Item = BulkOperation<TDocument>>,
+        TDocument: Serialize,
+    {
+        for op in ops {
+            self = self.push(op);
+        }
+
+        self
+    }
+
+    /**
+    Get the newline-delimited body.
+
+    # Errors
+
+    If any of the operations couldn't be serialised then this method will return an error.
+    */
+    pub fn build(self) -> Result<Vec<u8>, Error> {
+        self.body.try_into_inner()
+    }
+}
+
 /** A future returned by calling `send`. */
 pub type Pending<TResponse> = BasePending<TResponse>;
 
@@ -837,6 +944,7 @@ impl<TIndex, TType, TId, TNewId> ChangeId<TNewId> for BulkErrorsResponse<TIndex,
 
 #[cfg(test)]
 mod tests {
+    use super::BulkBodyBuilder;
     use crate::{
         prelude::*,
         tests::*,
@@ -885,6 +993,53 @@ mod tests {
         assert_eq!("/test-idx/new-ty/_bulk", req.url.as_ref());
     }
 
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", index = "bulk_idx")]
+    struct BulkDoc {
+        #[elastic(id)]
+        id: String,
+        title: String,
+    }
+
+    #[test]
+    fn build_bulk_body_index() {
+        let body = BulkBodyBuilder::new()
+            .index(BulkDoc {
+                id: "1".to_owned(),
+                title: "A title".to_owned(),
+            })
+            .build()
+            .unwrap();
+
+        let expected = concat!(
+            r#"{"index":{"_index":"bulk_idx","_type":"_doc","_id":"1"}}"#,
+            "\n",
+            r#"{"id":"1","title":"A title"}"#,
+            "\n"
+        );
+
+        assert_eq!(expected, String::from_utf8(body).unwrap());
+    }
+
+    #[test]
+    fn build_bulk_body_delete() {
+        let body = BulkBodyBuilder::new()
+            .delete::<BulkDoc, _>("1")
+            .extend(vec![bulk_raw().delete().id("2")])
+            .build()
+            .unwrap();
+
+        // Delete operations don't have a document line
+        let expected = concat!(
+            r#"{"delete":{"_index":"bulk_idx","_type":"_doc","_id":"1"}}"#,
+            "\n",
+            r#"{"delete":{"_id":"2"}}"#,
+            "\n"
+        );
+
+        assert_eq!(expected, String::from_utf8(body).unwrap());
+    }
+
     #[test]
     fn specify_ty_without_index() {
         let client = SyncClientBuilder::new().build().unwrap();
@@ -13,13 +13,34 @@ use chrono::{
     DateTime,
     Utc,
 };
-use serde::Serialize;
-use serde_json;
+use serde::{
+    ser::{
+        Error as SerError,
+        SerializeMap,
+    },
+    Serialize,
+    Serializer,
+};
+use serde_json::{
+    self,
+    Map,
+};
+use std::marker::PhantomData;
 
-use crate::types::private::field::{
-    FieldMapping,
-    FieldType,
-    SerializeFieldMapping,
+use crate::types::{
+    document::mapping::{
+        NESTED_DATATYPE,
+        OBJECT_DATATYPE,
+    },
+    private::field::SerializeFieldMapping,
+};
+
+pub use crate::types::private::{
+    field::{
+        FieldMapping,
+        FieldType,
+    },
+    WrappedFieldType,
 };
 
 pub use crate::types::{
@@ -52,6 +73,252 @@ pub use chrono::format::{
     Pad,
 };
 pub use serde::ser::SerializeStruct;
+pub use serde_json::{
+    Error as JsonError,
+    Value,
+};
+
+/**
+Get the `_meta` for a document with required fields, like `{"required":["id"]}`.
+
+The required fields of flattened fields are merged in after the document's own required fields.
+*/
+pub fn required_meta(required: &[&'static str], flattened: &[Option<Value>]) -> Option<Value> {
+    let flattened = flattened
+        .iter()
+        .filter_map(|meta| meta.as_ref())
+        .filter_map(|meta| meta.get("required"))
+        .filter_map(|required| required.as_array())
+        .flat_map(|required| required.iter().cloned());
+
+    let required: Vec<Value> = required
+        .iter()
+        .map(|&field| Value::String(field.to_owned()))
+        .chain(flattened)
+        .collect();
+
+    if required.is_empty() {
+        return None;
+    }
+
+    let mut meta = serde_json::Map::new();
+    meta.insert("required".to_owned(), Value::Array(required));
+
+    Some(Value::Object(meta))
+}
+
+/**
+A field marked with `#[serde(flatten)]` on a derived type.
+
+Flattened document types have their properties merged into the parent by `FlattenedProperties`.
+Anything else, like a `HashMap<String, Value>` catch-all, is mapped like any other field by `FlattenedFallback`.
+The derive calls the methods on `&Flatten::<T>::new()`, so `FlattenedProperties` is used whenever `T` implements it,
+and `FlattenedFallback` is only used when it doesn't.
+*/
+pub struct Flatten<TField>(PhantomData<TField>);
+
+impl<TField> Flatten<TField> {
+    /** Create a flattened field. */
+    pub fn new() -> Self {
+        Flatten(PhantomData)
+    }
+}
+
+impl<TField> Default for Flatten<TField> {
+    fn default() -> Self {
+        Flatten::new()
+    }
+}
+
+/** Merge the properties of a flattened document type into its parent. */
+pub trait FlattenedProperties {
+    /** The number of properties that are merged into the parent. */
+    fn flattened_props_len(&self) -> usize;
+
+    /** Serialise the merged properties. */
+    fn flattened_props_ser<S>(&self, state: &mut S, field: &'static str) -> Result<(), S::Error>
+    where
+        S: SerializeStruct;
+
+    /** Add the dynamic templates for the merged properties. */
+    fn flattened_templates(
+        &self,
+        path: &str,
+        field: &'static str,
+        templates: &mut Vec<Value>,
+    ) -> Result<(), serde_json::Error>;
+
+    /** The `_meta` of the flattened type, which has its required fields merged into the parent. */
+    fn flattened_meta(&self) -> Option<Value>;
+}
+
+impl<TField> FlattenedProperties for Flatten<TField>
+where
+    TField: ObjectFieldType,
+{
+    fn flattened_props_len(&self) -> usize {
+        <<TField::Mapping as ObjectMapping>::Properties as PropertiesMapping>::props_len()
+    }
+
+    fn flattened_props_ser<S>(&self, state: &mut S, _: &'static str) -> Result<(), S::Error>
+    where
+        S: SerializeStruct,
+    {
+        <<TField::Mapping as ObjectMapping>::Properties as PropertiesMapping>::serialize_props(
+            state,
+        )
+    }
+
+    fn flattened_templates(
+        &self,
+        path: &str,
+        _: &'static str,
+        templates: &mut Vec<Value>,
+    ) -> Result<(), serde_json::Error> {
+        <<TField::Mapping as ObjectMapping>::Properties as PropertiesMapping>::dynamic_templates(
+            path, templates,
+        )
+    }
+
+    fn flattened_meta(&self) -> Option<Value> {
+        TField::Mapping::meta()
+    }
+}
+
+/** Map a flattened field that isn't a document type as a field with its own name. */
+pub trait FlattenedFallback {
+    /** The type of the flattened field. */
+    type Field;
+
+    /** A flattened field is mapped as a single property. */
+    fn flattened_props_len(&self) -> usize {
+        1
+    }
+
+    /** Serialise the field like any other field. */
+    fn flattened_props_ser<TMapping, TPivot, S>(
+        &self,
+        state: &mut S,
+        field: &'static str,
+    ) -> Result<(), S::Error>
+    where
+        Self::Field: FieldType<TMapping, TPivot>,
+        TMapping: FieldMapping<TPivot>,
+        S: SerializeStruct,
+        SerializeFieldMapping<TMapping, TPivot>: Serialize,
+    {
+        field_ser::<Self::Field, TMapping, TPivot, S>(state, field)
+    }
+
+    /** Add the dynamic templates for the field like any other field. */
+    fn flattened_templates<TMapping, TPivot>(
+        &self,
+        path: &str,
+        field: &'static str,
+        templates: &mut Vec<Value>,
+    ) -> Result<(), serde_json::Error>
+    where
+        Self::Field: FieldType<TMapping, TPivot>,
+        TMapping: FieldMapping<TPivot>,
+    {
+        field_templates::<Self::Field, TMapping, TPivot>(path, field, templates)
+    }
+
+    /** The field doesn't have any required fields of its own. */
+    fn flattened_meta(&self) -> Option<Value> {
+        None
+    }
+}
+
+impl<TField> FlattenedFallback for &Flatten<TField> {
+    type Field = TField;
+}
+
+/**
+Serialisation for the mapped property fields on a type in the order they're declared.
+
+This is implemented for derived types with flattened fields, so their properties can be sorted by `sorted_props_ser`.
+*/
+pub trait UnsortedPropertiesMapping {
+    /** Serialise the mapped property fields, including the properties of flattened fields, in declaration order. */
+    fn serialize_unsorted_props<S>(state: &mut S) -> Result<(), S::Error>
+    where
+        S: SerializeStruct;
+}
+
+/**
+Serialise the mapped property fields on a type sorted by name.
+
+The names of properties merged from flattened fields are only known at runtime,
+so the properties are serialised once to collect their names, and then once for each name in order.
+*/
+pub fn sorted_props_ser<TProps, S>(state: &mut S) -> Result<(), S::Error>
+where
+    TProps: UnsortedPropertiesMapping,
+    S: SerializeStruct,
+{
+    let mut names = PropNames(Vec::new());
+    TProps::serialize_unsorted_props(&mut names).map_err(S::Error::custom)?;
+
+    let mut names = names.0;
+    names.sort_unstable();
+    names.dedup();
+
+    for name in names {
+        TProps::serialize_unsorted_props(&mut OnlyProp { state, name })?;
+    }
+
+    Ok(())
+}
+
+// Collects the names of properties without serialising them
+struct PropNames(Vec<&'static str>);
+
+impl SerializeStruct for PropNames {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, _: &T) -> Result<(), Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.0.push(key);
+        Ok(())
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+// Only serialises properties with the given name, so duplicates are still caught
+struct OnlyProp<'a, S> {
+    state: &'a mut S,
+    name: &'static str,
+}
+
+impl<S> SerializeStruct for OnlyProp<'_, S>
+where
+    S: SerializeStruct,
+{
+    type Ok = ();
+    type Error = S::Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), S::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        if key == self.name {
+            self.state.serialize_field(key, value)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn end(self) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
 
 /** Serialise a field mapping as a field using the given serialiser. */
 #[inline]
@@ -68,6 +335,165 @@ where
     state.serialize_field(field, &SerializeFieldMapping::<TMapping, TPivot>::default())
 }
 
+/**
+Serialise a field mapping as a field, overriding some of its parameters.
+
+This is used for parameters that are set with attributes on a field, like `#[elastic(index = false)]`.
+Parameters that aren't supported by the field's type, like an `analyzer` on a `keyword`, are an error.
+*/
+#[inline]
+pub fn field_ser_with_params<TField, TMapping, TPivot, S>(
+    state: &mut S,
+    field: &'static str,
+    params: &[(&'static str, Value)],
+) -> Result<(), S::Error>
+where
+    TField: FieldType<TMapping, TPivot>,
+    TMapping: FieldMapping<TPivot>,
+    S: SerializeStruct,
+    SerializeFieldMapping<TMapping, TPivot>: Serialize,
+{
+    mapping_ser_with_params::<TMapping, TPivot, S>(state, field, params)
+}
+
+/**
+Serialise a given field mapping as a field using the given serialiser.
+
+This is used for fields with a mapping set by `#[elastic(mapping = "MyMapping")]`,
+instead of the mapping for the field's type.
+*/
+#[inline]
+pub fn mapping_ser<TMapping, TPivot, S>(state: &mut S, field: &'static str) -> Result<(), S::Error>
+where
+    TMapping: FieldMapping<TPivot>,
+    S: SerializeStruct,
+    SerializeFieldMapping<TMapping, TPivot>: Serialize,
+{
+    state.serialize_field(field, &SerializeFieldMapping::<TMapping, TPivot>::default())
+}
+
+/** Serialise a given field mapping as a field, overriding some of its parameters. */
+pub fn mapping_ser_with_params<TMapping, TPivot, S>(
+    state: &mut S,
+    field: &'static str,
+    params: &[(&'static str, Value)],
+) -> Result<(), S::Error>
+where
+    TMapping: FieldMapping<TPivot>,
+    S: SerializeStruct,
+    SerializeFieldMapping<TMapping, TPivot>: Serialize,
+{
+    let mapping = serde_json::to_value(&SerializeFieldMapping::<TMapping, TPivot>::default())
+        .map_err(S::Error::custom)?;
+
+    let mut mapping = match mapping {
+        Value::Object(mapping) => mapping,
+        _ => {
+            return Err(S::Error::custom(
+                "expected the field mapping to be an object",
+            ))
+        }
+    };
+
+    // An `object` with properties doesn't always serialise its `type`
+    let ty = match mapping.get("type") {
+        Some(Value::String(ty)) => Some(ty.clone()),
+        _ if mapping.contains_key("properties") => Some(OBJECT_DATATYPE.to_owned()),
+        _ => None,
+    };
+
+    for &(name, ref value) in params {
+        if let Some(ref ty) = ty {
+            if !supports_param(ty, name) {
+                return Err(S::Error::custom(format_args!(
+                    "the `{}` parameter on `{}` isn't supported by `{}` fields",
+                    name, field, ty
+                )));
+            }
+        }
+
+        mapping.insert(name.to_owned(), value.clone());
+    }
+
+    state.serialize_field(field, &FieldMappingWithParams(mapping))
+}
+
+// Analyzers can only be set on fields that are analysed, and objects aren't indexed themselves
+fn supports_param(ty: &str, name: &str) -> bool {
+    match name {
+        "index" => match ty {
+            OBJECT_DATATYPE | NESTED_DATATYPE => false,
+            _ => true,
+        },
+        "analyzer" => match ty {
+            "text" | "search_as_you_type" | "completion" | "token_count" => true,
+            _ => false,
+        },
+        "search_analyzer" => match ty {
+            "text" | "search_as_you_type" | "completion" => true,
+            _ => false,
+        },
+        _ => true,
+    }
+}
+
+// A field mapping with its `type` first, followed by its other parameters
+struct FieldMappingWithParams(Map<String, Value>);
+
+impl Serialize for FieldMappingWithParams {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.0.len()))?;
+
+        if let Some(ty) = self.0.get("type") {
+            state.serialize_entry("type", ty)?;
+        }
+
+        for (name, value) in self.0.iter().filter(|&(name, _)| name != "type") {
+            state.serialize_entry(name, value)?;
+        }
+
+        state.end()
+    }
+}
+
+/** Add the dynamic templates for a field, like the values of a map. */
+#[inline]
+pub fn field_templates<TField, TMapping, TPivot>(
+    path: &str,
+    field: &'static str,
+    templates: &mut Vec<Value>,
+) -> Result<(), serde_json::Error>
+where
+    TField: FieldType<TMapping, TPivot>,
+    TMapping: FieldMapping<TPivot>,
+{
+    TMapping::dynamic_templates(&field_path(path, field), templates)
+}
+
+/** Add the dynamic templates for a field with a custom mapping. */
+#[inline]
+pub fn mapping_templates<TMapping, TPivot>(
+    path: &str,
+    field: &'static str,
+    templates: &mut Vec<Value>,
+) -> Result<(), serde_json::Error>
+where
+    TMapping: FieldMapping<TPivot>,
+{
+    TMapping::dynamic_templates(&field_path(path, field), templates)
+}
+
+fn field_path(path: &str, field: &'static str) -> String {
+    if path.is_empty() {
+        field.to_owned()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
 /**
 Serialize a field individually.
 
@@ -155,6 +155,22 @@ mod tests {
     struct MyBooleanMapping;
     impl BooleanMapping for MyBooleanMapping {}
 
+    #[derive(Default)]
+    pub struct NullBooleanMapping;
+    impl BooleanMapping for NullBooleanMapping {
+        fn null_value() -> Option<bool> {
+            Some(false)
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct BooleanArrays {
+        pub flag: bool,
+        pub flags: Vec<bool>,
+        pub custom_flags: Vec<Boolean<NullBooleanMapping>>,
+    }
+
     #[test]
     fn can_change_boolean_mapping() {
         fn takes_custom_mapping(_: Boolean<MyBooleanMapping>) -> bool {
@@ -175,6 +191,21 @@ mod tests {
         assert_eq!("true", ser);
     }
 
+    #[test]
+    fn serialise_mapping_boolean_arrays() {
+        let ser = serde_json::to_value(&BooleanArrays::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "flag": { "type": "boolean" },
+                "flags": { "type": "boolean" },
+                "custom_flags": { "type": "boolean", "null_value": false }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
     #[test]
     fn deserialise_elastic_boolean() {
         let boolean: Boolean<DefaultBooleanMapping> = serde_json::from_str("true").unwrap();
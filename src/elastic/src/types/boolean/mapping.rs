@@ -1,5 +1,7 @@
 /*! Mapping for the Elasticsearch `boolean` type. */
 
+use crate::types::boost::Boost;
+
 /** A field that will be mapped as a `boolean`. */
 pub trait BooleanFieldType<TMapping> {}
 
@@ -18,8 +20,8 @@ Define a custom `BooleanMapping`:
 struct MyBooleanMapping;
 impl BooleanMapping for MyBooleanMapping {
     //Overload the mapping functions here
-    fn boost() -> Option<f32> {
-        Some(1.5)
+    fn boost() -> Option<Boost> {
+        Some(Boost::new(1.5).unwrap())
     }
 }
 ```
@@ -33,8 +35,8 @@ This will produce the following mapping:
 # struct MyBooleanMapping;
 # impl BooleanMapping for MyBooleanMapping {
 #     //Overload the mapping functions here
-#     fn boost() -> Option<f32> {
-#         Some(1.5)
+#     fn boost() -> Option<Boost> {
+#         Some(Boost::new(1.5).unwrap())
 #     }
 # }
 # let json = json!(
@@ -48,8 +50,8 @@ This will produce the following mapping:
 ```
 */
 pub trait BooleanMapping {
-    /** Field-level index time boosting. Accepts a floating point number, defaults to `1.0`. */
-    fn boost() -> Option<f32> {
+    /** Field-level index time boosting. Accepts a positive floating point number, defaults to `1.0`. */
+    fn boost() -> Option<Boost> {
         None
     }
 
@@ -0,0 +1,127 @@
+/*!
+Field-level index time boosting.
+
+Elasticsearch rejects a `boost` that isn't a positive number,
+so mappings use a `Boost` that can only be created with a valid value.
+
+# Examples
+
+Boost a field in a custom mapping:
+
+```
+# use elastic::types::prelude::*;
+#[derive(Default)]
+struct MyMapping;
+impl BooleanMapping for MyMapping {
+    fn boost() -> Option<Boost> {
+        Some(Boost::new(1.5).unwrap())
+    }
+}
+```
+*/
+
+use crate::types::error::MappingError;
+use serde::{
+    Serialize,
+    Serializer,
+};
+use std::hash::{
+    Hash,
+    Hasher,
+};
+
+/** A positive `boost` value. */
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Boost(f32);
+
+impl Boost {
+    /**
+    Create a new `Boost`.
+
+    Returns an error if the value is `NaN`, or isn't greater than `0`.
+    */
+    pub fn new(boost: f32) -> Result<Self, MappingError> {
+        if boost > 0.0 {
+            Ok(Boost(boost))
+        } else {
+            Err(MappingError::InvalidParameter {
+                name: "boost",
+                reason: format!("must be greater than 0, but was {}", boost),
+            })
+        }
+    }
+
+    /** Get the value of this boost. */
+    pub fn value(&self) -> f32 {
+        self.0
+    }
+}
+
+// A `Boost` is never `NaN` or `-0.0`, so its bits are a canonical representation
+impl Eq for Boost {}
+
+impl Hash for Boost {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.0.to_bits().hash(state)
+    }
+}
+
+// Whole boosts are serialised as integers, like `2` instead of `2.0`, to match Elasticsearch
+impl Serialize for Boost {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.0.fract() == 0.0 && self.0 <= ::std::u32::MAX as f32 {
+            serializer.serialize_u64(self.0 as u64)
+        } else {
+            serializer.serialize_f32(self.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_boost() {
+        assert_eq!(2.0, Boost::new(2.0).unwrap().value());
+    }
+
+    #[test]
+    fn invalid_boost() {
+        for boost in &[0.0, -0.0, -1.0, ::std::f32::NAN] {
+            assert!(Boost::new(*boost).is_err());
+        }
+    }
+
+    #[test]
+    fn serialise_boost() {
+        let ser = serde_json::to_value(&Boost::new(1.5).unwrap()).unwrap();
+
+        assert_eq!(json!(1.5), ser);
+    }
+
+    #[test]
+    fn serialise_whole_boost_as_integer() {
+        assert_eq!(
+            "2",
+            serde_json::to_string(&Boost::new(2.0).unwrap()).unwrap()
+        );
+        assert_eq!(
+            "2.5",
+            serde_json::to_string(&Boost::new(2.5).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn serialise_large_boost() {
+        let ser = serde_json::to_string(&Boost::new(1e20).unwrap()).unwrap();
+
+        assert_eq!(1e20, ser.parse::<f32>().unwrap());
+    }
+}
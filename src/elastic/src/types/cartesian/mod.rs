@@ -0,0 +1,26 @@
+/*!
+Implementation of the Elasticsearch Cartesian types.
+
+Use [`point`](point/index.html) for indexing points in a two-dimensional planar coordinate system.
+Use [`shape`](shape/index.html) for indexing `geojson` in a two-dimensional planar coordinate system.[^geo]
+Unlike the `geo` types, Cartesian types aren't tied to the surface of the earth.
+
+[^geo]: requires building with the `geo-types` feature (which is enabled by default)
+*/
+
+pub mod point;
+#[cfg(feature = "geo-types")]
+pub mod shape;
+
+pub mod prelude {
+    /*!
+    Includes all types for the Cartesian `point` and `shape` types.
+
+    This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+    */
+
+    pub use super::point::prelude::*;
+
+    #[cfg(feature = "geo-types")]
+    pub use super::shape::prelude::*;
+}
@@ -0,0 +1,52 @@
+use super::mapping::{
+    DefaultPointMapping,
+    PointFieldType,
+};
+
+/**
+A point in a two-dimensional planar coordinate system.
+
+# Examples
+
+```
+# use elastic::types::prelude::*;
+let point = CartesianPoint::new(-71.34, 41.12);
+```
+*/
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CartesianPoint {
+    /** The `x` coordinate. */
+    pub x: f64,
+    /** The `y` coordinate. */
+    pub y: f64,
+}
+
+impl CartesianPoint {
+    /** Creates a new `CartesianPoint` from the given `x` and `y` coordinates. */
+    pub fn new(x: f64, y: f64) -> Self {
+        CartesianPoint { x, y }
+    }
+}
+
+impl PointFieldType<DefaultPointMapping> for CartesianPoint {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::prelude::*;
+
+    #[test]
+    fn serialise_cartesian_point() {
+        let ser = serde_json::to_value(&CartesianPoint::new(-71.34, 41.12)).unwrap();
+
+        assert_eq!(json!({ "x": -71.34, "y": 41.12 }), ser);
+    }
+
+    #[test]
+    fn deserialise_cartesian_point() {
+        let point: CartesianPoint = serde_json::from_str(r#"{"x":-71.34,"y":41.12}"#).unwrap();
+
+        assert_eq!(CartesianPoint::new(-71.34, 41.12), point);
+    }
+}
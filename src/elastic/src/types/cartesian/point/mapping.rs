@@ -0,0 +1,196 @@
+/*! Mapping for the Elasticsearch `point` type. */
+
+use super::impls::CartesianPoint;
+
+/** A field that will be mapped as a `point`. */
+pub trait PointFieldType<TMapping> {}
+
+/**
+The base requirements for mapping a `point` type.
+
+Custom mappings can be defined by implementing `PointMapping`.
+
+# Examples
+
+Define a custom `PointMapping`:
+
+```
+# #[macro_use] use elastic::types::prelude::*;
+#[derive(Default)]
+struct MyPointMapping;
+impl PointMapping for MyPointMapping {
+    //Overload the mapping functions here
+    fn ignore_malformed() -> Option<bool> {
+        Some(true)
+    }
+}
+```
+
+This will produce the following mapping:
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# #[derive(Default)]
+# struct MyPointMapping;
+# impl PointMapping for MyPointMapping {
+#     //Overload the mapping functions here
+#     fn ignore_malformed() -> Option<bool> {
+#         Some(true)
+#     }
+# }
+# let json = json!(
+{
+    "type": "point",
+    "ignore_malformed": true
+}
+# );
+# let mapping = elastic::types::__derive::standalone_field_ser(MyPointMapping).unwrap();
+# assert_eq!(json, mapping);
+```
+*/
+pub trait PointMapping {
+    /**
+    If `true`, malformed points are ignored.
+    If `false` (default), malformed points throw an exception and reject the whole document.
+    */
+    fn ignore_malformed() -> Option<bool> {
+        None
+    }
+
+    /**
+    If `true` (default), three dimension points are accepted but only `x` and `y` values are indexed,
+    and the third dimension is ignored.
+    If `false`, points with more than two dimensions throw an exception and reject the whole document.
+    */
+    fn ignore_z_value() -> Option<bool> {
+        None
+    }
+
+    /**
+    A point which is substituted for any explicit null values.
+    Defaults to `null`, which means the field is treated as missing.
+    */
+    fn null_value() -> Option<CartesianPoint> {
+        None
+    }
+}
+
+/** Default mapping for a `point`. */
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DefaultPointMapping;
+impl PointMapping for DefaultPointMapping {}
+
+mod private {
+    use super::{
+        PointFieldType,
+        PointMapping,
+    };
+    use crate::types::private::field::{
+        ser_ignore_z_value,
+        FieldMapping,
+        FieldType,
+        SerializeFieldMapping,
+        StaticSerialize,
+    };
+    use serde::{
+        ser::SerializeStruct,
+        Serialize,
+        Serializer,
+    };
+
+    #[derive(Default)]
+    pub struct PointPivot;
+
+    impl<TField, TMapping> FieldType<TMapping, PointPivot> for TField
+    where
+        TMapping: PointMapping,
+        TField: PointFieldType<TMapping> + Serialize,
+    {
+    }
+
+    impl<TMapping> FieldMapping<PointPivot> for TMapping
+    where
+        TMapping: PointMapping,
+    {
+        type SerializeFieldMapping = SerializeFieldMapping<TMapping, PointPivot>;
+
+        fn data_type() -> &'static str {
+            "point"
+        }
+    }
+
+    impl<TMapping> StaticSerialize for SerializeFieldMapping<TMapping, PointPivot>
+    where
+        TMapping: FieldMapping<PointPivot> + PointMapping,
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("mapping", 4)?;
+
+            state.serialize_field("type", TMapping::data_type())?;
+
+            ser_field!(state, "ignore_malformed", TMapping::ignore_malformed());
+            ser_ignore_z_value(&mut state, TMapping::ignore_z_value())?;
+            ser_field!(state, "null_value", TMapping::null_value());
+
+            state.end()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::{
+        prelude::*,
+        private::field,
+    };
+
+    #[derive(Default, Clone)]
+    pub struct MyPointMapping;
+    impl PointMapping for MyPointMapping {
+        fn ignore_malformed() -> Option<bool> {
+            Some(true)
+        }
+
+        fn ignore_z_value() -> Option<bool> {
+            Some(false)
+        }
+
+        fn null_value() -> Option<CartesianPoint> {
+            Some(CartesianPoint::new(0.0, 0.0))
+        }
+    }
+
+    #[test]
+    fn serialise_mapping_default() {
+        let ser = serde_json::to_value(&field::serialize(DefaultPointMapping)).unwrap();
+
+        let expected = json!({
+            "type": "point"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_custom() {
+        let ser = serde_json::to_value(&field::serialize(MyPointMapping)).unwrap();
+
+        let expected = json!({
+            "type": "point",
+            "ignore_malformed": true,
+            "ignore_z_value": false,
+            "null_value": {
+                "x": 0.0,
+                "y": 0.0
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+}
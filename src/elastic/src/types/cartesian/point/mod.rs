@@ -0,0 +1,37 @@
+/*!
+Implementation of the Elasticsearch `point` type.
+
+# Examples
+
+For defining your own point mapping, see [mapping details](mapping/trait.PointMapping.html#derive-mapping).
+
+Map with a default `point`:
+
+```
+# use elastic::types::prelude::*;
+struct MyType {
+    pub field: CartesianPoint
+}
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/point.html)
+*/
+pub mod mapping;
+
+mod impls;
+pub use self::impls::*;
+
+pub mod prelude {
+    /*!
+    Includes all types for the `point` type.
+
+    This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+    */
+
+    pub use super::{
+        impls::*,
+        mapping::*,
+    };
+}
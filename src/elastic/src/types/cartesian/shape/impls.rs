@@ -0,0 +1,86 @@
+use super::mapping::{
+    DefaultShapeMapping,
+    ShapeFieldType,
+};
+use geojson::Geometry;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+
+/**
+A shape in a two-dimensional planar coordinate system.
+
+# Examples
+
+```
+# extern crate geojson;
+use geojson::{ Geometry, Value };
+
+# use elastic::types::prelude::*;
+# fn main() {
+let shape = CartesianShape(Geometry::new(Value::Point(vec![ 1.0, 1.0 ])));
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct CartesianShape(pub Geometry);
+
+impl ShapeFieldType<DefaultShapeMapping> for CartesianShape {}
+
+impl From<Geometry> for CartesianShape {
+    fn from(geometry: Geometry) -> Self {
+        CartesianShape(geometry)
+    }
+}
+
+impl Serialize for CartesianShape {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CartesianShape {
+    fn deserialize<D>(deserializer: D) -> Result<CartesianShape, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Geometry::deserialize(deserializer).map(CartesianShape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geojson::{
+        Geometry,
+        Value,
+    };
+    use serde_json;
+
+    use crate::types::prelude::*;
+
+    #[test]
+    fn serialise_cartesian_shape() {
+        let shape = CartesianShape(Geometry::new(Value::Point(vec![1.0, 1.0])));
+
+        let ser = serde_json::to_value(&shape).unwrap();
+
+        assert_eq!(json!({ "type": "Point", "coordinates": [1.0, 1.0] }), ser);
+    }
+
+    #[test]
+    fn deserialise_cartesian_shape() {
+        let shape: CartesianShape =
+            serde_json::from_str(r#"{"type":"Point","coordinates":[1.0,1.0]}"#).unwrap();
+
+        assert_eq!(
+            CartesianShape(Geometry::new(Value::Point(vec![1.0, 1.0]))),
+            shape
+        );
+    }
+}
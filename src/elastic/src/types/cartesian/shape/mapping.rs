@@ -0,0 +1,224 @@
+/*! Mapping for the Elasticsearch `shape` type. */
+
+use crate::types::geo::shape::mapping::Orientation;
+
+/** A field that will be mapped as a `shape`. */
+pub trait ShapeFieldType<TMapping> {}
+
+/**
+The base requirements for mapping a `shape` type.
+
+Custom mappings can be defined by implementing `ShapeMapping`.
+Options that only apply to `geo_shape`, like `tree` or `precision`, aren't available.
+
+# Examples
+
+Define a custom `ShapeMapping`:
+
+```
+# #[macro_use] use elastic::types::prelude::*;
+#[derive(Default)]
+struct MyShapeMapping;
+impl ShapeMapping for MyShapeMapping {
+    //Overload the mapping functions here
+    fn coerce() -> Option<bool> {
+        Some(true)
+    }
+}
+```
+
+This will produce the following mapping:
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# #[derive(Default)]
+# struct MyShapeMapping;
+# impl ShapeMapping for MyShapeMapping {
+#     //Overload the mapping functions here
+#     fn coerce() -> Option<bool> {
+#         Some(true)
+#     }
+# }
+# let json = json!(
+{
+    "type": "shape",
+    "coerce": true
+}
+# );
+# let mapping = elastic::types::__derive::standalone_field_ser(MyShapeMapping).unwrap();
+# assert_eq!(json, mapping);
+```
+*/
+pub trait ShapeMapping {
+    /**
+    Optionally define how to interpret vertex order for polygons and multipolygons.
+    Defaults to counterclockwise.
+    */
+    fn orientation() -> Option<Orientation> {
+        None
+    }
+
+    /**
+    If `true`, malformed shapes are ignored.
+    If `false` (default), malformed shapes throw an exception and reject the whole document.
+    */
+    fn ignore_malformed() -> Option<bool> {
+        None
+    }
+
+    /**
+    If `true` (default), three dimension points are accepted but only `x` and `y` values are indexed,
+    and the third dimension is ignored.
+    If `false`, points with more than two dimensions throw an exception and reject the whole document.
+    */
+    fn ignore_z_value() -> Option<bool> {
+        None
+    }
+
+    /**
+    If `true`, unclosed linear rings in polygons will be automatically closed.
+    Defaults to `false`.
+    */
+    fn coerce() -> Option<bool> {
+        None
+    }
+}
+
+/** Default mapping for a `shape`. */
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DefaultShapeMapping;
+impl ShapeMapping for DefaultShapeMapping {}
+
+mod private {
+    use super::{
+        ShapeFieldType,
+        ShapeMapping,
+    };
+    use crate::types::private::field::{
+        ser_ignore_z_value,
+        FieldMapping,
+        FieldType,
+        SerializeFieldMapping,
+        StaticSerialize,
+    };
+    use serde::{
+        ser::SerializeStruct,
+        Serialize,
+        Serializer,
+    };
+
+    #[derive(Default)]
+    pub struct ShapePivot;
+
+    impl<TField, TMapping> FieldType<TMapping, ShapePivot> for TField
+    where
+        TMapping: ShapeMapping,
+        TField: ShapeFieldType<TMapping> + Serialize,
+    {
+    }
+
+    impl<TMapping> FieldMapping<ShapePivot> for TMapping
+    where
+        TMapping: ShapeMapping,
+    {
+        type SerializeFieldMapping = SerializeFieldMapping<TMapping, ShapePivot>;
+
+        fn data_type() -> &'static str {
+            "shape"
+        }
+    }
+
+    impl<TMapping> StaticSerialize for SerializeFieldMapping<TMapping, ShapePivot>
+    where
+        TMapping: FieldMapping<ShapePivot> + ShapeMapping,
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("mapping", 5)?;
+
+            state.serialize_field("type", TMapping::data_type())?;
+
+            ser_field!(state, "orientation", TMapping::orientation());
+            ser_field!(state, "ignore_malformed", TMapping::ignore_malformed());
+            ser_ignore_z_value(&mut state, TMapping::ignore_z_value())?;
+            ser_field!(state, "coerce", TMapping::coerce());
+
+            state.end()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::{
+        prelude::*,
+        private::field,
+    };
+
+    #[derive(Default, Clone)]
+    pub struct MyShapeMapping;
+    impl ShapeMapping for MyShapeMapping {
+        fn orientation() -> Option<Orientation> {
+            Some(Orientation::Clockwise)
+        }
+
+        fn ignore_malformed() -> Option<bool> {
+            Some(true)
+        }
+
+        fn ignore_z_value() -> Option<bool> {
+            Some(false)
+        }
+
+        fn coerce() -> Option<bool> {
+            Some(true)
+        }
+    }
+
+    #[test]
+    fn serialise_mapping_default() {
+        let ser = serde_json::to_value(&field::serialize(DefaultShapeMapping)).unwrap();
+
+        let expected = json!({
+            "type": "shape"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_custom() {
+        let ser = serde_json::to_value(&field::serialize(MyShapeMapping)).unwrap();
+
+        let expected = json!({
+            "type": "shape",
+            "orientation": "cw",
+            "ignore_malformed": true,
+            "ignore_z_value": false,
+            "coerce": true
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_has_no_geo_only_options() {
+        let ser = serde_json::to_value(&field::serialize(MyShapeMapping)).unwrap();
+
+        for geo_only in &[
+            "tree",
+            "precision",
+            "tree_levels",
+            "strategy",
+            "distance_error_pct",
+            "points_only",
+        ] {
+            assert!(ser.get(geo_only).is_none());
+        }
+    }
+}
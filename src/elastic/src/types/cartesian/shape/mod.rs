@@ -0,0 +1,55 @@
+/*!
+Implementation of the Elasticsearch `shape` type.[^geo]
+
+Shape is a wrapper for storing [geojson](http://geojson.org/) structures with Cartesian coordinates in Elasticsearch.
+
+# Examples
+
+For defining your own shape mapping, see [mapping details](mapping/trait.ShapeMapping.html#derive-mapping).
+
+Map with a default `shape`:
+
+```
+# use elastic::types::prelude::*;
+struct MyType {
+    pub field: CartesianShape
+}
+```
+
+Map a custom type as a `shape` field:
+
+```
+#[macro_use] extern crate serde_derive;
+# fn main() {
+# use elastic::types::prelude::*;
+#[derive(Serialize)]
+struct MyShapeField(String);
+
+impl ShapeFieldType<DefaultShapeMapping> for MyShapeField {}
+# }
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/shape.html)
+
+[^geo]: requires building with the `geo-types` feature (which is enabled by default)
+*/
+
+pub mod mapping;
+
+mod impls;
+pub use self::impls::*;
+
+pub mod prelude {
+    /*!
+    Includes all types for the `shape` type.
+
+    This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+    */
+
+    pub use super::{
+        impls::*,
+        mapping::*,
+    };
+}
@@ -1,12 +1,15 @@
 use super::{
     DateFormat,
+    DateFormatName,
     DateValue,
     FormattedDate,
     ParseError,
 };
 use chrono::{
     DateTime,
+    NaiveDate,
     NaiveDateTime,
+    TimeZone,
     Timelike,
     Utc,
 };
@@ -97,6 +100,106 @@ impl DateFormat for EpochMillis {
     }
 }
 
+/**
+Format for `strict_date_optional_time_nanos`.
+
+Formats dates as RFC 3339 timestamps with nanosecond precision, like `2015-07-03T14:55:02.123456789Z`.
+This is the default format for `date_nanos` fields.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct StrictDateOptionalTimeNanos;
+
+impl DateFormat for StrictDateOptionalTimeNanos {
+    fn name() -> &'static str {
+        "strict_date_optional_time_nanos"
+    }
+
+    fn parse(date: &str) -> Result<DateValue, ParseError> {
+        let date = DateTime::parse_from_rfc3339(date).map_err(|e| e.to_string())?;
+
+        Ok(date.with_timezone(&Utc).into())
+    }
+
+    fn format(date: &DateValue) -> FormattedDate {
+        date.format("%Y-%m-%dT%H:%M:%S%.9fZ").to_string().into()
+    }
+}
+
+/**
+Format for `date_optional_time`.
+
+Parses a date with an optional time, like `2015-07-03` or `2015-07-03T14:55:02.478Z`.
+Times without an offset are treated as UTC.
+Dates are formatted with millisecond precision, like `2015-07-03T14:55:02.478Z`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DateOptionalTime;
+
+impl DateFormat for DateOptionalTime {
+    fn name() -> &'static str {
+        DateFormatName::DateOptionalTime.as_str()
+    }
+
+    fn parse(date: &str) -> Result<DateValue, ParseError> {
+        parse_date_optional_time(date)
+    }
+
+    fn format(date: &DateValue) -> FormattedDate {
+        format_date_optional_time(date)
+    }
+}
+
+/**
+Format for `strict_date_optional_time`.
+
+This is the strict version of `DateOptionalTime`, and is the format Elasticsearch uses for dates by default.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct StrictDateOptionalTime;
+
+impl DateFormat for StrictDateOptionalTime {
+    fn name() -> &'static str {
+        DateFormatName::StrictDateOptionalTime.as_str()
+    }
+
+    fn parse(date: &str) -> Result<DateValue, ParseError> {
+        parse_date_optional_time(date)
+    }
+
+    fn format(date: &DateValue) -> FormattedDate {
+        format_date_optional_time(date)
+    }
+}
+
+fn parse_date_optional_time(date: &str) -> Result<DateValue, ParseError> {
+    if let Ok(date) = DateTime::parse_from_rfc3339(date) {
+        return Ok(date.with_timezone(&Utc).into());
+    }
+
+    if let Ok(date) = NaiveDateTime::parse_from_str(date, "%Y-%m-%dT%H:%M:%S%.f") {
+        return Ok(Utc.from_utc_datetime(&date).into());
+    }
+
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|e| e.to_string())?;
+
+    let date = date.and_hms_opt(0, 0, 0).expect("midnight is a valid time");
+
+    Ok(Utc.from_utc_datetime(&date).into())
+}
+
+fn format_date_optional_time(date: &DateValue) -> FormattedDate {
+    date.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string().into()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -382,6 +485,39 @@ mod tests {
         assert_eq!("0", &fmtd);
     }
 
+    #[test]
+    fn date_optional_time() {
+        let date =
+            parse::<DefaultDateMapping<DateOptionalTime>>("2015-07-03T14:55:02.478Z").unwrap();
+
+        assert_eq!(
+            (2015i32, 7u32, 3u32, 14u32, 55u32, 2u32, 478u32),
+            (
+                date.year(),
+                date.month(),
+                date.day(),
+                date.hour(),
+                date.minute(),
+                date.second(),
+                date.nanosecond() / 1_000_000
+            )
+        );
+
+        let fmtd = format(&date).to_string();
+        assert_eq!("2015-07-03T14:55:02.478Z", &fmtd);
+
+        let date = parse::<DefaultDateMapping<DateOptionalTime>>("2015-07-03").unwrap();
+
+        let fmtd = format(&date).to_string();
+        assert_eq!("2015-07-03T00:00:00.000Z", &fmtd);
+    }
+
+    #[test]
+    fn date_optional_time_names() {
+        assert_eq!("date_optional_time", DateOptionalTime::name());
+        assert_eq!("strict_date_optional_time", StrictDateOptionalTime::name());
+    }
+
     #[test]
     fn custom_format() {
         #[derive(Default)]
@@ -6,7 +6,10 @@ use super::{
         FormattedDate,
         ParseError,
     },
-    formats::ChronoFormat,
+    formats::{
+        ChronoFormat,
+        EpochMillis,
+    },
     mapping::{
         DateFieldType,
         DateMapping,
@@ -37,6 +40,7 @@ use std::{
     },
     marker::PhantomData,
     ops::Deref,
+    time::SystemTime,
 };
 
 pub use chrono::{
@@ -55,6 +59,15 @@ impl From<ChronoDateTime> for FormattableDateValue<ChronoFormat> {
     }
 }
 
+// `serde` serialises a `SystemTime` as a struct, so fields should also use the `system_time` module
+impl DateFieldType<DefaultDateMapping<EpochMillis>> for SystemTime {}
+
+impl From<SystemTime> for FormattableDateValue<EpochMillis> {
+    fn from(time: SystemTime) -> Self {
+        FormattableDateValue::from(DateValue::from(ChronoDateTime::from(time)))
+    }
+}
+
 /**
 An Elasticsearch `date` type with a required `time` component.
 
@@ -6,8 +6,15 @@ use super::{
     DefaultDateFormat,
     FormattableDateValue,
 };
+use crate::types::boost::Boost;
 use std::marker::PhantomData;
 
+/** Elasticsearch datatype name. */
+pub const DATE_DATATYPE: &str = "date";
+
+/** Elasticsearch datatype name. */
+pub const DATE_NANOS_DATATYPE: &str = "date_nanos";
+
 /** A field that will be mapped as a `date`. */
 pub trait DateFieldType<TMapping>
 where
@@ -34,8 +41,8 @@ impl DateMapping for MyDateMapping {
     type Format = EpochMillis;
 
     //Overload the mapping functions here
-    fn boost() -> Option<f32> {
-        Some(1.5)
+    fn boost() -> Option<Boost> {
+        Some(Boost::new(1.5).unwrap())
     }
 }
 ```
@@ -50,8 +57,8 @@ This will produce the following mapping:
 # struct MyDateMapping;
 # impl DateMapping for MyDateMapping {
 #     type Format = EpochMillis;
-#     fn boost() -> Option<f32> {
-#         Some(1.5)
+#     fn boost() -> Option<Boost> {
+#         Some(Boost::new(1.5).unwrap())
 #     }
 # }
 # let mapping = elastic::types::__derive::standalone_field_ser(MyDateMapping).unwrap();
@@ -95,8 +102,27 @@ pub trait DateMapping {
     */
     type Format: DateFormat;
 
-    /** Field-level index time boosting. Accepts a floating point number, defaults to `1.0`. */
-    fn boost() -> Option<f32> {
+    /**
+    Get the type name for this mapping, like `date` or `date_nanos`.
+
+    A `date_nanos` field stores dates with nanosecond resolution instead of milliseconds,
+    see `DefaultDateNanosMapping`.
+    */
+    fn data_type() -> &'static str {
+        DATE_DATATYPE
+    }
+
+    /** Field-level index time boosting. Accepts a positive floating point number, defaults to `1.0`. */
+    fn boost() -> Option<Boost> {
+        None
+    }
+
+    /**
+    Copy the value of this field into one or more target fields,
+    which can then be queried as a single field.
+    Targets are the full dotted paths of fields declared on the same document.
+    */
+    fn copy_to() -> Option<&'static [&'static str]> {
         None
     }
 
@@ -140,6 +166,19 @@ pub trait DateMapping {
         None
     }
 
+    /**
+    The locale to use when parsing dates, like `en-US` or `de`.
+    Some formats, like those with month or day names, depend on the locale.
+    Defaults to the `ROOT` locale.
+
+    There's no `time_zone` parameter for a `date` mapping.
+    Dates without an offset are always indexed as UTC,
+    and a `time_zone` can only be given at query time.
+    */
+    fn locale() -> Option<&'static str> {
+        None
+    }
+
     /**
     Accepts a date value in one of the configured format's as the field which is substituted for any explicit null values.
     Defaults to `null`, which means the field is treated as missing.
@@ -210,7 +249,7 @@ mod private {
         type SerializeFieldMapping = SerializeFieldMapping<TMapping, DatePivot>;
 
         fn data_type() -> &'static str {
-            "date"
+            <TMapping as DateMapping>::data_type()
         }
     }
 
@@ -223,17 +262,19 @@ mod private {
         where
             S: Serializer,
         {
-            let mut state = serializer.serialize_struct("mapping", 9)?;
+            let mut state = serializer.serialize_struct("mapping", 11)?;
 
-            state.serialize_field("type", TMapping::data_type())?;
+            state.serialize_field("type", <TMapping as DateMapping>::data_type())?;
             state.serialize_field("format", TMapping::Format::name())?;
 
             ser_field!(state, "boost", TMapping::boost());
+            ser_field!(state, "copy_to", TMapping::copy_to());
             ser_field!(state, "doc_values", TMapping::doc_values());
             ser_field!(state, "include_in_all", TMapping::include_in_all());
             ser_field!(state, "index", TMapping::index());
             ser_field!(state, "store", TMapping::store());
             ser_field!(state, "ignore_malformed", TMapping::ignore_malformed());
+            ser_field!(state, "locale", TMapping::locale());
             ser_field!(state, "null_value", TMapping::null_value());
 
             state.end()
@@ -312,4 +353,56 @@ mod tests {
 
         assert_eq!(expected, ser);
     }
+
+    #[test]
+    fn serialise_mapping_strict_and_lenient_formats() {
+        let strict = serde_json::to_value(&field::serialize(DefaultDateMapping::<
+            StrictDateOptionalTime,
+        >::default()))
+        .unwrap();
+
+        let lenient = serde_json::to_value(&field::serialize(
+            DefaultDateMapping::<DateOptionalTime>::default(),
+        ))
+        .unwrap();
+
+        assert_eq!(
+            json!({
+                "type": "date",
+                "format": "strict_date_optional_time"
+            }),
+            strict
+        );
+        assert_eq!(
+            json!({
+                "type": "date",
+                "format": "date_optional_time"
+            }),
+            lenient
+        );
+    }
+
+    #[derive(Default, Clone)]
+    pub struct MyLocaleDateMapping;
+    impl DateMapping for MyLocaleDateMapping {
+        type Format = DateOptionalTime;
+
+        fn locale() -> Option<&'static str> {
+            Some("de")
+        }
+    }
+
+    #[test]
+    fn serialise_mapping_locale() {
+        let ser = serde_json::to_value(&field::serialize(MyLocaleDateMapping)).unwrap();
+
+        let expected = json!({
+            "type": "date",
+            "format": "date_optional_time",
+            "locale": "de"
+        });
+
+        assert_eq!(expected, ser);
+        assert!(ser.get("time_zone").is_none());
+    }
 }
@@ -101,14 +101,19 @@ impl DateFormat for Rfc3339Format {
 */
 
 pub mod mapping;
+pub mod system_time;
 
 mod format;
 mod formats;
 mod impls;
+mod names;
+mod nanos;
 pub use self::{
     format::*,
     formats::*,
     impls::*,
+    names::*,
+    nanos::*,
 };
 
 pub mod prelude {
@@ -128,6 +133,8 @@ pub mod prelude {
         formats::*,
         impls::*,
         mapping::*,
+        names::*,
+        nanos::*,
         DefaultDateFormat,
     };
 }
@@ -0,0 +1,265 @@
+use serde::{
+    Serialize,
+    Serializer,
+};
+use std::fmt::{
+    Display,
+    Formatter,
+    Result as FmtResult,
+};
+
+// The built-in names are declared once, so `BUILT_IN` and `as_str` always cover every variant
+macro_rules! date_format_names {
+    ($(#[$attr:meta])* pub enum $name:ident {
+        $($(#[$variant_attr:meta])* $variant:ident => $str:expr,)*
+    }) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$variant_attr])* $variant,)*
+            /** A custom format pattern, like `yyyy-MM-dd`. */
+            Custom(&'static str),
+        }
+
+        const BUILT_IN: &[$name] = &[$($name::$variant,)*];
+
+        impl $name {
+            /** Get the name of this format as it's sent to Elasticsearch. */
+            pub fn as_str(&self) -> &'static str {
+                match *self {
+                    $($name::$variant => $str,)*
+                    $name::Custom(pattern) => pattern,
+                }
+            }
+        }
+    };
+}
+
+date_format_names! {
+    /**
+    The name of a built-in Elasticsearch date format.
+
+    Many built-in formats come in two families: a lenient format, like `date_optional_time`,
+    and a strict format with a `strict_` prefix, like `strict_date_optional_time`.
+    Strict formats require years, months and days to be fully zero-padded.
+    Use `strict` and `lenient` to move a format between the two families.
+
+    The name can be used as the `name` of a `DateFormat`.
+    Formats that aren't built-in, like `yyyy-MM-dd`, can be given as a `Custom` name.
+
+    # Examples
+
+    ```
+    # use elastic::types::prelude::*;
+    let format = DateFormatName::DateOptionalTime;
+
+    assert_eq!("strict_date_optional_time", format.strict().as_str());
+    assert_eq!(format, format.strict().lenient());
+    ```
+
+    Convert a raw format string into a name.
+    Names of built-in formats are recognised, and anything else is a custom format:
+
+    ```
+    # use elastic::types::prelude::*;
+    assert_eq!(DateFormatName::EpochMillis, DateFormatName::from("epoch_millis"));
+    assert_eq!(DateFormatName::custom("yyyy-MM-dd"), DateFormatName::from("yyyy-MM-dd"));
+    ```
+
+    # Links
+    - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping-date-format.html#built-in-date-formats)
+    */
+    pub enum DateFormatName {
+        /** `basic_date`, like `20150703`. */
+        BasicDate => "basic_date",
+        /** `basic_date_time`, like `20150703T145502.478Z`. */
+        BasicDateTime => "basic_date_time",
+        /** `basic_date_time_no_millis`, like `20150703T145502Z`. */
+        BasicDateTimeNoMillis => "basic_date_time_no_millis",
+        /** `date`, like `2015-07-03`. */
+        Date => "date",
+        /** `strict_date`. */
+        StrictDate => "strict_date",
+        /** `date_hour_minute_second`, like `2015-07-03T14:55:02`. */
+        DateHourMinuteSecond => "date_hour_minute_second",
+        /** `strict_date_hour_minute_second`. */
+        StrictDateHourMinuteSecond => "strict_date_hour_minute_second",
+        /** `date_optional_time`, a date with an optional time, like `2015-07-03` or `2015-07-03T14:55:02Z`. */
+        DateOptionalTime => "date_optional_time",
+        /** `strict_date_optional_time`. */
+        StrictDateOptionalTime => "strict_date_optional_time",
+        /** `strict_date_optional_time_nanos`, a `strict_date_optional_time` with nanosecond precision. */
+        StrictDateOptionalTimeNanos => "strict_date_optional_time_nanos",
+        /** `date_time`, like `2015-07-03T14:55:02.478Z`. */
+        DateTime => "date_time",
+        /** `strict_date_time`. */
+        StrictDateTime => "strict_date_time",
+        /** `date_time_no_millis`, like `2015-07-03T14:55:02Z`. */
+        DateTimeNoMillis => "date_time_no_millis",
+        /** `strict_date_time_no_millis`. */
+        StrictDateTimeNoMillis => "strict_date_time_no_millis",
+        /** `year_month_day`, like `2015-07-03`. */
+        YearMonthDay => "year_month_day",
+        /** `strict_year_month_day`. */
+        StrictYearMonthDay => "strict_year_month_day",
+        /** `epoch_millis`, the number of milliseconds since the epoch. */
+        EpochMillis => "epoch_millis",
+        /** `epoch_second`, the number of seconds since the epoch. */
+        EpochSecond => "epoch_second",
+    }
+}
+
+impl DateFormatName {
+    /**
+    A custom format pattern, like `yyyy-MM-dd`.
+
+    The pattern is passed through to Elasticsearch as-is.
+    Use `DateFormatName::from` instead to recognise the names of built-in formats.
+    */
+    pub fn custom(pattern: &'static str) -> Self {
+        DateFormatName::Custom(pattern)
+    }
+
+    /**
+    Get the strict version of this format.
+
+    Formats that are already strict, or don't have a strict version, are returned unchanged.
+    */
+    pub fn strict(self) -> Self {
+        match self {
+            DateFormatName::Date => DateFormatName::StrictDate,
+            DateFormatName::DateHourMinuteSecond => DateFormatName::StrictDateHourMinuteSecond,
+            DateFormatName::DateOptionalTime => DateFormatName::StrictDateOptionalTime,
+            DateFormatName::DateTime => DateFormatName::StrictDateTime,
+            DateFormatName::DateTimeNoMillis => DateFormatName::StrictDateTimeNoMillis,
+            DateFormatName::YearMonthDay => DateFormatName::StrictYearMonthDay,
+            format => format,
+        }
+    }
+
+    /**
+    Get the lenient version of this format.
+
+    Formats that are already lenient, or don't have a lenient version, are returned unchanged.
+    */
+    pub fn lenient(self) -> Self {
+        match self {
+            DateFormatName::StrictDate => DateFormatName::Date,
+            DateFormatName::StrictDateHourMinuteSecond => DateFormatName::DateHourMinuteSecond,
+            DateFormatName::StrictDateOptionalTime => DateFormatName::DateOptionalTime,
+            DateFormatName::StrictDateTime => DateFormatName::DateTime,
+            DateFormatName::StrictDateTimeNoMillis => DateFormatName::DateTimeNoMillis,
+            DateFormatName::StrictYearMonthDay => DateFormatName::YearMonthDay,
+            format => format,
+        }
+    }
+
+    /** Whether this is a strict built-in format, with a `strict_` prefix. */
+    pub fn is_strict(&self) -> bool {
+        self.is_built_in() && self.as_str().starts_with("strict_")
+    }
+
+    /** Whether this is a built-in format, rather than a custom pattern. */
+    pub fn is_built_in(&self) -> bool {
+        !matches!(*self, DateFormatName::Custom(_))
+    }
+}
+
+impl From<&'static str> for DateFormatName {
+    fn from(name: &'static str) -> Self {
+        BUILT_IN
+            .iter()
+            .find(|format| format.as_str() == name)
+            .cloned()
+            .unwrap_or(DateFormatName::Custom(name))
+    }
+}
+
+impl Serialize for DateFormatName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Display for DateFormatName {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::prelude::*;
+
+    #[test]
+    fn serialise_strict_and_lenient_names() {
+        let ser = serde_json::to_value(&[
+            DateFormatName::StrictDateOptionalTime,
+            DateFormatName::DateOptionalTime,
+        ])
+        .unwrap();
+
+        assert_eq!(
+            json!(["strict_date_optional_time", "date_optional_time"]),
+            ser
+        );
+    }
+
+    #[test]
+    fn toggle_strictness() {
+        let lenient = DateFormatName::DateOptionalTime;
+        let strict = lenient.strict();
+
+        assert_eq!(DateFormatName::StrictDateOptionalTime, strict);
+        assert!(strict.is_strict());
+        assert!(!lenient.is_strict());
+        assert_eq!(lenient, strict.lenient());
+        assert_eq!(strict, strict.strict());
+
+        assert_eq!(
+            DateFormatName::EpochMillis,
+            DateFormatName::EpochMillis.strict()
+        );
+        assert_eq!(
+            DateFormatName::StrictDateOptionalTimeNanos,
+            DateFormatName::StrictDateOptionalTimeNanos.lenient()
+        );
+    }
+
+    #[test]
+    fn custom_format_from_str() {
+        let format = DateFormatName::from("yyyy-MM-dd");
+
+        assert_eq!(DateFormatName::custom("yyyy-MM-dd"), format);
+        assert!(!format.is_built_in());
+        assert_eq!(format, format.strict());
+
+        let ser = serde_json::to_value(&format).unwrap();
+
+        assert_eq!(json!("yyyy-MM-dd"), ser);
+    }
+
+    #[test]
+    fn named_format_from_str() {
+        let format = DateFormatName::from("strict_date_optional_time");
+
+        assert_eq!(DateFormatName::StrictDateOptionalTime, format);
+        assert!(format.is_built_in());
+        assert!(format.is_strict());
+
+        // Custom patterns are never strict, even if they look like a built-in name
+        assert!(!DateFormatName::custom("strict_yyyy").is_strict());
+    }
+
+    #[test]
+    fn built_in_names_round_trip() {
+        for format in super::BUILT_IN {
+            assert_eq!(*format, DateFormatName::from(format.as_str()));
+        }
+    }
+}
@@ -0,0 +1,191 @@
+/*!
+Implementation of the Elasticsearch `date_nanos` type.
+
+A `date_nanos` field stores timestamps with nanosecond resolution instead of milliseconds.
+Its mapping and values are the same as `date`, so a `DateNanos` is a `Date` whose `DateMapping` has a `date_nanos` type.
+
+# Examples
+
+Map a `date_nanos` field with the default format:
+
+```
+# use elastic::types::prelude::*;
+struct MyType {
+    pub field: DateNanos<DefaultDateNanosMapping>
+}
+```
+
+Define a custom `date_nanos` mapping by overriding the `data_type` of a `DateMapping`:
+
+```
+# use elastic::types::prelude::*;
+#[derive(Default)]
+struct MyDateNanosMapping;
+impl DateMapping for MyDateNanosMapping {
+    type Format = StrictDateOptionalTimeNanos;
+
+    fn data_type() -> &'static str {
+        DATE_NANOS_DATATYPE
+    }
+
+    //Overload the mapping functions here
+    fn doc_values() -> Option<bool> {
+        Some(false)
+    }
+}
+```
+
+This will produce the following mapping:
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# #[derive(Default)]
+# struct MyDateNanosMapping;
+# impl DateMapping for MyDateNanosMapping {
+#     type Format = StrictDateOptionalTimeNanos;
+#     fn data_type() -> &'static str {
+#         DATE_NANOS_DATATYPE
+#     }
+#     fn doc_values() -> Option<bool> {
+#         Some(false)
+#     }
+# }
+# let mapping = elastic::types::__derive::standalone_field_ser(MyDateNanosMapping).unwrap();
+# let json = json!(
+{
+    "type": "date_nanos",
+    "format": "strict_date_optional_time_nanos",
+    "doc_values": false
+}
+# );
+# assert_eq!(json, mapping);
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/date_nanos.html)
+*/
+
+use super::{
+    format::DateFormat,
+    formats::StrictDateOptionalTimeNanos,
+    impls::Date,
+    mapping::{
+        DateMapping,
+        DATE_NANOS_DATATYPE,
+    },
+};
+use std::marker::PhantomData;
+
+/** The default `date_nanos` format (`StrictDateOptionalTimeNanos`). */
+pub type DefaultDateNanosFormat = StrictDateOptionalTimeNanos;
+
+/**
+An Elasticsearch `date_nanos` type with a required `time` component.
+
+This is a `Date` with a `date_nanos` mapping, so it's created and formatted the same way.
+
+# Examples
+
+Create a `DateNanos` from a `DateValue`:
+
+```
+# use elastic::types::prelude::*;
+let date: DateNanos<DefaultDateNanosMapping> = DateNanos::new(DateValue::now());
+```
+*/
+pub type DateNanos<TMapping> = Date<TMapping>;
+
+/** Default mapping for `date_nanos`. */
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DefaultDateNanosMapping<TFormat = DefaultDateNanosFormat>
+where
+    TFormat: DateFormat,
+{
+    _f: PhantomData<TFormat>,
+}
+
+impl<TFormat> DateMapping for DefaultDateNanosMapping<TFormat>
+where
+    TFormat: DateFormat,
+{
+    type Format = TFormat;
+
+    fn data_type() -> &'static str {
+        DATE_NANOS_DATATYPE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::{
+        prelude::*,
+        private::field,
+    };
+
+    #[derive(Default, Clone)]
+    pub struct MyDateNanosMapping;
+    impl DateMapping for MyDateNanosMapping {
+        type Format = EpochMillis;
+
+        fn data_type() -> &'static str {
+            DATE_NANOS_DATATYPE
+        }
+
+        fn store() -> Option<bool> {
+            Some(true)
+        }
+    }
+
+    #[test]
+    fn serialise_mapping_default() {
+        let mapping = DefaultDateNanosMapping::<DefaultDateNanosFormat>::default();
+
+        let ser = serde_json::to_value(&field::serialize(mapping)).unwrap();
+
+        let expected = json!({
+            "type": "date_nanos",
+            "format": "strict_date_optional_time_nanos"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_custom_format() {
+        let ser = serde_json::to_value(&field::serialize(MyDateNanosMapping)).unwrap();
+
+        let expected = json!({
+            "type": "date_nanos",
+            "format": "epoch_millis",
+            "store": true
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn date_nanos_keep_nanosecond_precision() {
+        let date: DateNanos<DefaultDateNanosMapping> =
+            serde_json::from_str(r#""2015-07-03T14:55:02.123456789Z""#).unwrap();
+
+        assert_eq!(123_456_789, date.nanosecond());
+
+        let ser = serde_json::to_string(&date).unwrap();
+
+        assert_eq!(r#""2015-07-03T14:55:02.123456789Z""#, ser);
+    }
+
+    #[test]
+    fn date_nanos_remap_to_date() {
+        let date: DateNanos<DefaultDateNanosMapping> =
+            serde_json::from_str(r#""2015-07-03T14:55:02.123456789Z""#).unwrap();
+
+        let date: Date<DefaultDateMapping<StrictDateOptionalTimeNanos>> = Date::remap(date);
+
+        assert_eq!(123_456_789, date.nanosecond());
+    }
+}
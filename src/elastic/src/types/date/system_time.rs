@@ -0,0 +1,108 @@
+/*!
+Serialisation for `std::time::SystemTime` fields.
+
+A `SystemTime` is mapped as a `date` with the `epoch_millis` format,
+but `serde` serialises it as a struct of seconds and nanoseconds.
+Use this module with `#[serde(with)]` to serialise it the same way as a `Date<DefaultDateMapping<EpochMillis>>`.
+
+# Examples
+
+Map a `SystemTime` field as a `date`:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use std::time::SystemTime;
+# use elastic::types::prelude::*;
+#[derive(Serialize, Deserialize, ElasticType)]
+struct MyType {
+    #[serde(with = "elastic::types::date::system_time")]
+    pub timestamp: SystemTime,
+}
+```
+*/
+
+use super::{
+    mapping::DefaultDateMapping,
+    ChronoDateTime,
+    Date,
+    DateValue,
+    EpochMillis,
+};
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use std::time::SystemTime;
+
+type SystemTimeDate = Date<DefaultDateMapping<EpochMillis>>;
+
+/** Serialise a `SystemTime` as an `epoch_millis` date. */
+pub fn serialize<S>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    SystemTimeDate::new(DateValue::from(ChronoDateTime::from(*time))).serialize(serializer)
+}
+
+/** Deserialise a `SystemTime` from an `epoch_millis` date. */
+pub fn deserialize<'de, D>(deserializer: D) -> Result<SystemTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let date = SystemTimeDate::deserialize(deserializer)?;
+
+    Ok(SystemTime::from(*date))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use std::time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH,
+    };
+
+    use crate::types::prelude::*;
+
+    #[derive(Serialize, Deserialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct SystemTimeType {
+        #[serde(with = "crate::types::date::system_time")]
+        pub timestamp: SystemTime,
+    }
+
+    #[test]
+    fn serialise_mapping_system_time() {
+        let ser = serde_json::to_value(&SystemTimeType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "timestamp": {
+                    "type": "date",
+                    "format": "epoch_millis"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_system_time() {
+        let doc = SystemTimeType {
+            timestamp: UNIX_EPOCH + Duration::from_millis(1435935302478),
+        };
+
+        let ser = serde_json::to_string(&doc).unwrap();
+
+        assert_eq!(r#"{"timestamp":"1435935302478"}"#, ser);
+
+        let de: SystemTimeType = serde_json::from_str(&ser).unwrap();
+
+        assert_eq!(doc.timestamp, de.timestamp);
+    }
+}
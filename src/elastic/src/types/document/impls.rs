@@ -1,12 +1,34 @@
-use super::mapping::{
-    ObjectFieldType,
-    ObjectMapping,
-    PropertiesMapping,
+use super::{
+    mapping::{
+        self,
+        Dynamic,
+        ObjectFieldType,
+        ObjectMapping,
+        PropertiesMapping,
+    },
+    resolved::DocumentMapping,
+    update::UpdateMappingBody,
+    visitor::{
+        self,
+        MappingVisitor,
+    },
+};
+use serde::{
+    ser::SerializeStruct,
+    Serialize,
+};
+use serde_json::{
+    self,
+    Value,
 };
-use serde::ser::SerializeStruct;
-use serde_json::Value;
 use std::{
     borrow::Cow,
+    fmt::{
+        Debug,
+        Display,
+        Formatter,
+        Result as FmtResult,
+    },
     marker::PhantomData,
 };
 
@@ -39,6 +61,16 @@ pub trait DocumentType: ObjectFieldType {
         IndexDocumentMapping::default()
     }
 
+    /** Get a serialisable instance of just the `properties` of the type mapping. */
+    fn properties_mapping() -> Properties<<Self as ObjectFieldType>::Mapping> {
+        Properties::default()
+    }
+
+    /** Get a serialisable instance of the type mapping as an update to an existing index. */
+    fn update_mapping() -> UpdateMappingBody<<Self as ObjectFieldType>::Mapping> {
+        UpdateMappingBody::default()
+    }
+
     /** Get the name of the index this document belongs to. */
     fn index(&self) -> Index;
 
@@ -127,12 +159,168 @@ where
     }
 }
 
-/** Mapping for an anonymous json object. */
+impl<TMapping> IndexDocumentMapping<TMapping>
+where
+    TMapping: ObjectMapping,
+{
+    /**
+    Serialise the mapping as indented json.
+
+    This is the same mapping produced by `serde_json::to_string`, just easier for humans to read.
+    */
+    pub fn to_pretty_string(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /**
+    Preview the json for this mapping.
+
+    The preview formats as the compact json that's sent to Elasticsearch,
+    so it can be used in assertions and logs.
+
+    # Examples
+
+    ```
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::types::prelude::*;
+    #[derive(Serialize, ElasticType)]
+    struct MyType {
+        pub count: i32,
+    }
+
+    let preview = MyType::index_mapping().preview().to_string();
+
+    assert_eq!(r#"{"properties":{"count":{"type":"integer"}}}"#, preview);
+    ```
+    */
+    pub fn preview(&self) -> DisplayJson<Self> {
+        DisplayJson::new(self)
+    }
+
+    /**
+    Resolve the json for this mapping, so it can be stored and inspected at runtime.
+
+    The mapping is validated before it's resolved.
+    */
+    pub fn resolve(&self) -> Result<DocumentMapping, serde_json::Error> {
+        let mapping = serde_json::to_value(self)?;
+
+        DocumentMapping::from_value(mapping).map_err(serde::ser::Error::custom)
+    }
+
+    /**
+    Walk the fields of this mapping with the given visitor.
+
+    The mapping isn't validated before it's visited.
+    */
+    pub fn visit<V>(&self, visitor: &mut V) -> Result<(), serde_json::Error>
+    where
+        V: MappingVisitor + ?Sized,
+    {
+        let properties = mapping::resolve_properties::<TMapping>()?;
+        visitor::walk_properties(&properties, visitor);
+
+        Ok(())
+    }
+
+    /**
+    Get the resolved mapping for the field at a dotted path, like `address.city.keyword`.
+
+    Returns `None` if there's no field at the given path.
+    */
+    pub fn get_field(&self, path: &str) -> Result<Option<Value>, serde_json::Error> {
+        let properties = mapping::resolve_properties::<TMapping>()?;
+
+        Ok(visitor::get_field(&properties, path).cloned())
+    }
+}
+
+/**
+A wrapper that formats a mapping as the json it serialises to.
+
+Both `Display` and `Debug` format the compact json.
+If the mapping can't be serialised then the error is formatted instead.
+*/
+pub struct DisplayJson<'a, T>
+where
+    T: ?Sized,
+{
+    value: &'a T,
+}
+
+impl<'a, T> DisplayJson<'a, T>
+where
+    T: Serialize + ?Sized,
+{
+    /** Wrap a value so it formats as json. */
+    pub fn new(value: &'a T) -> Self {
+        DisplayJson { value }
+    }
+}
+
+impl<'a, T> Display for DisplayJson<'a, T>
+where
+    T: Serialize + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match serde_json::to_string(self.value) {
+            Ok(json) => f.write_str(&json),
+            Err(e) => write!(f, "<invalid mapping: {}>", e),
+        }
+    }
+}
+
+impl<'a, T> Debug for DisplayJson<'a, T>
+where
+    T: Serialize + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Display::fmt(self, f)
+    }
+}
+
+/**
+A wrapper type for serialising just the `properties` of user types.
+
+Serialising `Properties` will produce an object with a single `properties` field,
+without any of the root parameters of the mapping.
+This is suitable as the body for the [Put Mapping API](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-put-mapping.html)
+when adding new fields to an existing index.
+
+The properties aren't validated when they're serialised,
+because they may refer to fields that are already mapped on the index.
+*/
+pub struct Properties<TMapping>(PhantomData<TMapping>);
+
+impl<TMapping> Default for Properties<TMapping>
+where
+    TMapping: ObjectMapping,
+{
+    fn default() -> Self {
+        Properties(Default::default())
+    }
+}
+
+/**
+Mapping for an anonymous json object.
+
+The shape of a `serde_json::Value` isn't known statically,
+so it's mapped as an `object` with `dynamic: true` and no properties.
+*/
 #[derive(Default)]
 pub struct ValueObjectMapping;
 
 impl ObjectMapping for ValueObjectMapping {
     type Properties = EmptyPropertiesMapping;
+
+    fn data_type() -> &'static str {
+        mapping::OBJECT_DATATYPE
+    }
+
+    fn dynamic() -> Option<Dynamic> {
+        Some(Dynamic::True)
+    }
 }
 
 impl ObjectFieldType for Value {
@@ -266,14 +454,20 @@ mod tests {
         StaticIndex,
         StaticType,
     };
-    use crate::types::prelude::*;
+    use crate::types::{
+        error::MappingError,
+        prelude::*,
+    };
     use serde_json::{
         self,
         Value,
     };
     use std::{
         borrow::Cow,
-        collections::HashSet,
+        collections::{
+            HashMap,
+            HashSet,
+        },
     };
 
     // Make sure we can derive with no `uses`.
@@ -360,10 +554,64 @@ mod tests {
         pub field7: HashSet<String>,
     }
 
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct ValueType {
+        pub extra: Value,
+    }
+
     #[derive(Serialize, ElasticType)]
     #[elastic(crate_root = "crate::types")]
     pub struct NoProps {}
 
+    #[derive(Default)]
+    pub struct CopyToFullNameMapping;
+    impl KeywordMapping for CopyToFullNameMapping {
+        fn copy_to() -> Option<&'static [&'static str]> {
+            Some(&["full_name"])
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct CopyToType {
+        pub first_name: Keyword<CopyToFullNameMapping>,
+        pub full_name: Text<DefaultStringMapping>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct DanglingCopyToType {
+        pub first_name: Keyword<CopyToFullNameMapping>,
+    }
+
+    #[derive(Default)]
+    pub struct CopyToTotalsMapping;
+    impl LongMapping for CopyToTotalsMapping {
+        fn copy_to() -> Option<&'static [&'static str]> {
+            Some(&["totals"])
+        }
+    }
+
+    #[derive(Default)]
+    pub struct CopyToEventsMapping;
+    impl DateMapping for CopyToEventsMapping {
+        type Format = EpochMillis;
+
+        fn copy_to() -> Option<&'static [&'static str]> {
+            Some(&["events"])
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct NumericCopyToType {
+        pub created: Date<CopyToEventsMapping>,
+        pub events: Date<DefaultDateMapping<EpochMillis>>,
+        pub count: Long<CopyToTotalsMapping>,
+        pub totals: i64,
+    }
+
     #[derive(Default, Serialize)]
     pub struct Index {
         mappings: Mappings,
@@ -477,6 +725,33 @@ mod tests {
         assert_eq!(expected, ser);
     }
 
+    #[derive(Default)]
+    pub struct DynamicNoPropsMapping;
+    impl ObjectMapping for DynamicNoPropsMapping {
+        type Properties = NoProps;
+
+        fn dynamic() -> Option<Dynamic> {
+            Some(Dynamic::True)
+        }
+    }
+
+    #[test]
+    fn serialise_dynamic_document_with_no_props() {
+        let ser = serde_json::to_value(&IndexDocumentMapping::<DynamicNoPropsMapping>::default())
+            .unwrap();
+
+        let expected = json!({
+            "dynamic": true,
+            "properties": {}
+        });
+
+        assert_eq!(expected, ser);
+
+        let ser = serde_json::to_string(&DocumentMapping::default()).unwrap();
+
+        assert_eq!("{}", ser);
+    }
+
     #[test]
     fn serialise_document_for_custom_mapping() {
         let ser = serde_json::to_value(&CustomType::index_mapping()).unwrap();
@@ -495,6 +770,37 @@ mod tests {
         assert_eq!(expected, ser);
     }
 
+    #[test]
+    fn preview_index_mapping() {
+        let mapping = ValueType::index_mapping();
+
+        assert_eq!(
+            serde_json::to_string(&mapping).unwrap(),
+            mapping.preview().to_string()
+        );
+        assert_eq!(
+            r#"{"properties":{"extra":{"type":"object","dynamic":true}}}"#,
+            format!("{:?}", mapping.preview())
+        );
+    }
+
+    #[test]
+    fn serialise_mapping_with_value_field() {
+        let ser = serde_json::to_value(&ValueType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "extra": {
+                    "type": "object",
+                    "dynamic": true
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[cfg(not(feature = "string-as-keyword"))]
     #[test]
     fn serialise_mapping_with_wrapped_types() {
         let ser = serde_json::to_value(&Wrapped::index_mapping()).unwrap();
@@ -517,7 +823,8 @@ mod tests {
                     }
                 },
                 "field4": {
-                    "type": "nested"
+                    "type": "object",
+                    "dynamic": true
                 },
                 "field5": {
                     "type": "nested",
@@ -528,7 +835,8 @@ mod tests {
                     }
                 },
                 "field6": {
-                    "type": "nested"
+                    "type": "object",
+                    "dynamic": true
                 },
                 "field7": {
                     "type": "text",
@@ -546,26 +854,145 @@ mod tests {
     }
 
     #[test]
-    fn serialise_index_mapping() {
-        let ser = serde_json::to_value(&Index::default()).unwrap();
+    fn serialise_mapping_pretty() {
+        let compact = serde_json::to_string(&SimpleType::index_mapping()).unwrap();
+        let pretty = SimpleType::index_mapping().to_pretty_string().unwrap();
+
+        assert!(pretty.contains('\n'));
+        assert_eq!(
+            serde_json::from_str::<Value>(&compact).unwrap(),
+            serde_json::from_str::<Value>(&pretty).unwrap()
+        );
+    }
+
+    #[test]
+    fn get_top_level_field() {
+        let field = Wrapped::index_mapping().get_field("field1").unwrap();
+
+        assert_eq!(Some(json!({ "type": "integer" })), field);
+    }
+
+    #[test]
+    fn get_nested_field() {
+        let field = Wrapped::index_mapping().get_field("field5.field").unwrap();
+
+        assert_eq!(Some(json!({ "type": "integer" })), field);
+    }
+
+    // Strings are mapped with a `keyword` multi-field unless the `string-as-keyword` feature is enabled
+    #[cfg(not(feature = "string-as-keyword"))]
+    #[test]
+    fn get_multi_field() {
+        let field = Wrapped::index_mapping()
+            .get_field("field3.keyword")
+            .unwrap();
+
+        assert_eq!(
+            Some(json!({ "type": "keyword", "ignore_above": 256 })),
+            field
+        );
+    }
+
+    #[test]
+    fn get_missing_field() {
+        assert_eq!(
+            None,
+            Wrapped::index_mapping()
+                .get_field("field5.missing")
+                .unwrap()
+        );
+    }
+
+    #[derive(Default)]
+    pub struct MetaTypeMapping;
+    impl ObjectMapping for MetaTypeMapping {
+        type Properties = MetaType;
+
+        fn meta() -> Option<Value> {
+            Some(json!({ "version": 3 }))
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", mapping = "MetaTypeMapping")]
+    pub struct MetaType {
+        pub field: i32,
+    }
+
+    #[test]
+    fn serialise_mapping_with_meta() {
+        let ser = serde_json::to_string(&MetaType::index_mapping()).unwrap();
+        let de: Value = serde_json::from_str(&ser).unwrap();
+
+        assert_eq!(Some(&json!({ "version": 3 })), de.get("_meta"));
+
+        // Inner objects don't get `_meta`
+        let ser = serde_json::to_value(&MetaType::field_mapping()).unwrap();
+
+        assert!(ser.get("_meta").is_none());
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct RequiredType {
+        #[elastic(required)]
+        pub id: String,
+        #[elastic(required)]
+        #[serde(rename = "name")]
+        pub full_name: String,
+        pub age: i32,
+    }
+
+    #[test]
+    fn serialise_mapping_with_required_fields() {
+        let ser = serde_json::to_value(&RequiredType::index_mapping()).unwrap();
+
+        assert_eq!(
+            Some(&json!({ "required": ["id", "name"] })),
+            ser.get("_meta")
+        );
+
+        // Types without required fields don't get `_meta`
+        let ser = serde_json::to_value(&SimpleType::index_mapping()).unwrap();
+
+        assert!(ser.get("_meta").is_none());
+    }
+
+    #[derive(Default)]
+    pub struct DetectionTypeMapping;
+    impl ObjectMapping for DetectionTypeMapping {
+        type Properties = DetectionType;
+
+        fn date_detection() -> Option<bool> {
+            Some(false)
+        }
+
+        fn dynamic_date_formats() -> Option<Vec<&'static str>> {
+            Some(vec![EpochMillis::name(), BasicDateTime::name()])
+        }
+
+        fn numeric_detection() -> Option<bool> {
+            Some(true)
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", mapping = "DetectionTypeMapping")]
+    pub struct DetectionType {
+        pub field: i32,
+    }
+
+    #[test]
+    fn serialise_mapping_with_detection() {
+        let ser = serde_json::to_value(&DetectionType::index_mapping()).unwrap();
 
         let expected = json!({
-            "mappings": {
-                "simpletype": {
-                    "properties": {
-                        "field1": {
-                            "type": "date",
-                            "format": "epoch_millis"
-                        },
-                        "field2": {
-                            "type": "nested",
-                            "properties": {
-                                "field": {
-                                    "type": "integer"
-                                }
-                            }
-                        }
-                    }
+            "date_detection": false,
+            "dynamic_date_formats": ["epoch_millis", "basic_date_time"],
+            "numeric_detection": true,
+            "properties": {
+                "field": {
+                    "type": "integer"
                 }
             }
         });
@@ -573,23 +1000,722 @@ mod tests {
         assert_eq!(expected, ser);
     }
 
-    #[test]
-    fn serialise_mapping_dynamic() {
-        let d_opts: Vec<String> = vec![Dynamic::True, Dynamic::False, Dynamic::Strict]
-            .iter()
-            .map(|i| serde_json::to_string(i).unwrap())
-            .collect();
+    #[derive(Default)]
+    pub struct DisabledBranchMapping;
+    impl ObjectMapping for DisabledBranchMapping {
+        type Properties = DisabledBranch;
 
-        let expected_opts = vec![r#"true"#, r#"false"#, r#""strict""#];
+        fn data_type() -> &'static str {
+            OBJECT_DATATYPE
+        }
 
-        let mut success = true;
-        for i in 0..d_opts.len() {
-            if expected_opts[i] != d_opts[i] {
-                success = false;
-                break;
-            }
+        fn enabled() -> Option<bool> {
+            Some(false)
         }
+    }
 
-        assert!(success);
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", mapping = "DisabledBranchMapping")]
+    pub struct DisabledBranch {
+        pub raw: i32,
+    }
+
+    #[derive(Default)]
+    pub struct DisabledNestedMapping;
+    impl ObjectMapping for DisabledNestedMapping {
+        type Properties = DisabledNested;
+
+        fn enabled() -> Option<bool> {
+            Some(false)
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", mapping = "DisabledNestedMapping")]
+    pub struct DisabledNested {
+        pub raw: i32,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct IndexedBranch {
+        pub indexed: i32,
+        pub disabled: DisabledBranch,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct PartlyDisabledType {
+        pub title: i32,
+        pub branch: IndexedBranch,
+        pub nested: DisabledNested,
+    }
+
+    #[test]
+    fn serialise_mapping_with_disabled_sub_objects() {
+        let ser = serde_json::to_value(&PartlyDisabledType::index_mapping()).unwrap();
+
+        // Only the disabled branches are ignored, the rest of the document is indexed
+        let expected = json!({
+            "properties": {
+                "title": {
+                    "type": "integer"
+                },
+                "branch": {
+                    "type": "nested",
+                    "properties": {
+                        "indexed": {
+                            "type": "integer"
+                        },
+                        "disabled": {
+                            "enabled": false,
+                            "properties": {
+                                "raw": {
+                                    "type": "integer"
+                                }
+                            }
+                        }
+                    }
+                },
+                "nested": {
+                    "type": "nested",
+                    "enabled": false,
+                    "properties": {
+                        "raw": {
+                            "type": "integer"
+                        }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[derive(Default)]
+    pub struct ExplicitObjectMapping;
+    impl ObjectMapping for ExplicitObjectMapping {
+        type Properties = ExplicitObject;
+
+        fn data_type() -> &'static str {
+            OBJECT_DATATYPE
+        }
+
+        fn explicit_type() -> bool {
+            true
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", mapping = "ExplicitObjectMapping")]
+    pub struct ExplicitObject {
+        pub raw: i32,
+    }
+
+    #[test]
+    fn serialise_implicit_object_mapping() {
+        let ser = serde_json::to_value(&DisabledBranch::field_mapping()).unwrap();
+
+        let expected = json!({
+            "enabled": false,
+            "properties": {
+                "raw": {
+                    "type": "integer"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_explicit_object_mapping() {
+        let ser = serde_json::to_string(&ExplicitObject::field_mapping()).unwrap();
+
+        assert_eq!(
+            r#"{"type":"object","properties":{"raw":{"type":"integer"}}}"#,
+            ser
+        );
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct Email(String);
+
+    #[derive(Default)]
+    pub struct EmailKeywordMapping;
+    impl KeywordMapping for EmailKeywordMapping {}
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", mapping = "EmailKeywordMapping")]
+    pub struct KeywordEmail(String);
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct NewtypeType {
+        pub email: Email,
+        pub emails: Vec<Email>,
+        pub keyword_email: KeywordEmail,
+        pub name: String,
+    }
+
+    #[test]
+    fn serialise_mapping_newtype() {
+        let ser = serde_json::to_value(&NewtypeType::index_mapping()).unwrap();
+
+        let string = &ser["properties"]["name"];
+
+        // Newtypes are mapped like their inner type
+        assert_eq!(string, &ser["properties"]["email"]);
+        assert_eq!(string, &ser["properties"]["emails"]);
+
+        assert_eq!(
+            json!({ "type": "keyword" }),
+            ser["properties"]["keyword_email"]
+        );
+    }
+
+    #[derive(Default)]
+    pub struct FlatTypeMapping;
+    impl ObjectMapping for FlatTypeMapping {
+        type Properties = FlatType;
+
+        fn data_type() -> &'static str {
+            OBJECT_DATATYPE
+        }
+
+        fn subobjects() -> Option<bool> {
+            Some(false)
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", mapping = "FlatTypeMapping")]
+    pub struct FlatType {
+        #[serde(rename = "metrics.cpu")]
+        pub cpu: f32,
+        #[serde(rename = "metrics.cpu.total")]
+        pub cpu_total: f32,
+    }
+
+    #[test]
+    fn serialise_mapping_without_subobjects() {
+        let ser = serde_json::to_value(&FlatType::index_mapping()).unwrap();
+
+        // Dotted names are kept as flat leaf fields instead of being expanded into objects
+        let expected = json!({
+            "subobjects": false,
+            "properties": {
+                "metrics.cpu": {
+                    "type": "float"
+                },
+                "metrics.cpu.total": {
+                    "type": "float"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+
+        let ser = serde_json::to_value(&FlatType::field_mapping()).unwrap();
+
+        assert_eq!(Some(&json!(false)), ser.get("subobjects"));
+
+        assert_eq!(
+            Some(json!({ "type": "float" })),
+            FlatType::index_mapping()
+                .get_field("metrics.cpu.total")
+                .unwrap()
+        );
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct OptionalType {
+        pub plain: String,
+        pub optional: Option<String>,
+    }
+
+    #[test]
+    fn optional_fields_map_like_their_inner_type() {
+        let mapping = OptionalType::index_mapping();
+
+        assert_eq!(
+            mapping.get_field("plain").unwrap(),
+            mapping.get_field("optional").unwrap()
+        );
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct ArrayType {
+        pub plain: i32,
+        pub array: Vec<i32>,
+    }
+
+    #[test]
+    fn array_fields_map_like_their_element_type() {
+        let mapping = ArrayType::index_mapping();
+
+        assert_eq!(
+            Some(json!({ "type": "integer" })),
+            mapping.get_field("array").unwrap()
+        );
+        assert_eq!(
+            mapping.get_field("plain").unwrap(),
+            mapping.get_field("array").unwrap()
+        );
+    }
+
+    #[test]
+    fn serialise_mapping_with_copy_to() {
+        let ser = serde_json::to_value(&CopyToType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "first_name": {
+                    "type": "keyword",
+                    "copy_to": ["full_name"]
+                },
+                "full_name": {
+                    "type": "text",
+                    "fields": {
+                        "keyword":{
+                            "type": "keyword",
+                            "ignore_above": 256
+                        }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_with_numeric_copy_to() {
+        let ser = serde_json::to_value(&NumericCopyToType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "count": {
+                    "type": "long",
+                    "copy_to": ["totals"]
+                },
+                "created": {
+                    "type": "date",
+                    "format": "epoch_millis",
+                    "copy_to": ["events"]
+                },
+                "events": {
+                    "type": "date",
+                    "format": "epoch_millis"
+                },
+                "totals": {
+                    "type": "long"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_properties_mapping() {
+        let ser = serde_json::to_value(&SimpleType::properties_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "field1": {
+                    "type": "date",
+                    "format": "epoch_millis"
+                },
+                "field2": {
+                    "type": "nested",
+                    "properties": {
+                        "field": {
+                            "type": "integer"
+                        }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_properties_mapping_without_root_params() {
+        let ser = serde_json::to_value(&DetectionType::properties_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "field": {
+                    "type": "integer"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_with_dangling_copy_to_fails() {
+        let err = serde_json::to_value(&DanglingCopyToType::index_mapping()).unwrap_err();
+
+        assert!(err.to_string().contains("full_name"));
+    }
+
+    #[test]
+    fn serialise_index_mapping() {
+        let ser = serde_json::to_value(&Index::default()).unwrap();
+
+        let expected = json!({
+            "mappings": {
+                "simpletype": {
+                    "properties": {
+                        "field1": {
+                            "type": "date",
+                            "format": "epoch_millis"
+                        },
+                        "field2": {
+                            "type": "nested",
+                            "properties": {
+                                "field": {
+                                    "type": "integer"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_dynamic() {
+        let d_opts: Vec<String> = vec![Dynamic::True, Dynamic::False, Dynamic::Strict]
+            .iter()
+            .map(|i| serde_json::to_string(i).unwrap())
+            .collect();
+
+        let expected_opts = vec![r#"true"#, r#"false"#, r#""strict""#];
+
+        let mut success = true;
+        for i in 0..d_opts.len() {
+            if expected_opts[i] != d_opts[i] {
+                success = false;
+                break;
+            }
+        }
+
+        assert!(success);
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct OrderedType {
+        pub count: i32,
+        pub published: Date<DefaultDateMapping>,
+        pub title: Text<DefaultStringMapping>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct ReorderedType {
+        pub title: Text<DefaultStringMapping>,
+        pub count: i32,
+        pub published: Date<DefaultDateMapping>,
+    }
+
+    #[test]
+    fn serialise_mapping_in_canonical_order() {
+        let golden = include_str!("ordered_mapping.json").trim();
+
+        let ordered = OrderedType::index_mapping().to_pretty_string().unwrap();
+        let reordered = ReorderedType::index_mapping().to_pretty_string().unwrap();
+
+        assert_eq!(golden, ordered);
+        assert_eq!(golden, reordered);
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct UnindexedType {
+        #[elastic(index = "no")]
+        pub title: Text<DefaultStringMapping>,
+        #[elastic(index = false)]
+        pub count: i32,
+        #[elastic(index = "true")]
+        pub tag: Keyword<DefaultKeywordMapping>,
+    }
+
+    #[test]
+    fn serialise_mapping_with_index_attribute() {
+        let ser = serde_json::to_value(&UnindexedType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "count": {
+                    "type": "integer",
+                    "index": false
+                },
+                "tag": {
+                    "type": "keyword",
+                    "index": true
+                },
+                "title": {
+                    "type": "text",
+                    "index": false,
+                    "fields": {
+                        "keyword": {
+                            "type": "keyword",
+                            "ignore_above": 256
+                        }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct UnindexedNestedType {
+        #[elastic(index = false)]
+        pub inner: SimpleNestedType,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct UnindexedObjectType {
+        #[elastic(index = false)]
+        pub inner: ExplicitObject,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct UnindexedImplicitObjectType {
+        #[elastic(index = false)]
+        pub inner: DisabledBranch,
+    }
+
+    #[test]
+    fn serialise_mapping_with_index_attribute_on_object_fails() {
+        let sers = [
+            serde_json::to_value(&UnindexedNestedType::index_mapping()),
+            serde_json::to_value(&UnindexedObjectType::index_mapping()),
+            serde_json::to_value(&UnindexedImplicitObjectType::index_mapping()),
+        ];
+
+        for ser in sers.iter() {
+            let err = ser.as_ref().unwrap_err().to_string();
+
+            assert!(
+                err.contains("the `index` parameter on `inner` isn't supported"),
+                "{}",
+                err
+            );
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct AnalyzedType {
+        #[elastic(analyzer = "english", search_analyzer = "standard")]
+        pub title: Text<DefaultTextMapping>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct AnalyzedKeywordType {
+        #[elastic(analyzer = "english")]
+        pub tag: Keyword<DefaultKeywordMapping>,
+    }
+
+    #[test]
+    fn serialise_mapping_with_analyzer_attributes() {
+        let ser = serde_json::to_value(&AnalyzedType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "title": {
+                    "type": "text",
+                    "analyzer": "english",
+                    "search_analyzer": "standard"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_with_analyzer_on_keyword_fails() {
+        let ser = serde_json::to_value(&AnalyzedKeywordType::index_mapping());
+
+        assert!(ser.is_err());
+    }
+
+    #[derive(Default)]
+    pub struct OverriddenKeywordMapping;
+    impl KeywordMapping for OverriddenKeywordMapping {
+        fn ignore_above() -> Option<u32> {
+            Some(64)
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct OverriddenMappingType {
+        #[elastic(mapping = "OverriddenKeywordMapping")]
+        pub tag: String,
+        #[elastic(mapping = "DefaultKeywordMapping", index = false)]
+        pub code: String,
+    }
+
+    #[test]
+    fn serialise_mapping_with_mapping_attribute() {
+        let ser = serde_json::to_value(&OverriddenMappingType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "code": {
+                    "type": "keyword",
+                    "index": false
+                },
+                "tag": {
+                    "type": "keyword",
+                    "ignore_above": 64
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct AuditType {
+        pub created_by: Keyword<DefaultKeywordMapping>,
+        pub created_at: Date<DefaultDateMapping<EpochMillis>>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct FlattenedType {
+        pub title: Keyword<DefaultKeywordMapping>,
+        #[serde(flatten)]
+        pub audit: AuditType,
+    }
+
+    #[test]
+    fn serialise_mapping_with_flattened_field() {
+        let ser = serde_json::to_string(&FlattenedType::index_mapping()).unwrap();
+
+        // Flattened properties are sorted along with the document's own properties
+        let expected = concat!(
+            r#"{"properties":{"#,
+            r#""created_at":{"type":"date","format":"epoch_millis"},"#,
+            r#""created_by":{"type":"keyword"},"#,
+            r#""title":{"type":"keyword"}"#,
+            r#"}}"#
+        );
+
+        assert_eq!(expected, ser);
+        assert_eq!(3, <FlattenedType as PropertiesMapping>::props_len());
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct CatchAllType {
+        pub title: Keyword<DefaultKeywordMapping>,
+        #[serde(flatten)]
+        pub extra: HashMap<String, Value>,
+    }
+
+    #[test]
+    fn serialise_mapping_with_flattened_map() {
+        let ser = serde_json::to_value(&CatchAllType::index_mapping()).unwrap();
+
+        // Flattened fields that aren't document types are mapped like any other field
+        let expected = json!({
+            "type": "object",
+            "dynamic": true
+        });
+
+        assert_eq!(Some(&expected), ser["properties"].get("extra"));
+        assert_eq!(
+            Some(&json!({ "type": "keyword" })),
+            ser["properties"].get("title")
+        );
+        assert_eq!(2, <CatchAllType as PropertiesMapping>::props_len());
+        assert!(ser.get("_meta").is_none());
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct RequiredAuditType {
+        #[elastic(required)]
+        pub created_by: Keyword<DefaultKeywordMapping>,
+        pub created_at: Date<DefaultDateMapping<EpochMillis>>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct RequiredFlattenedType {
+        #[elastic(required)]
+        pub id: Keyword<DefaultKeywordMapping>,
+        #[serde(flatten)]
+        pub audit: RequiredAuditType,
+    }
+
+    #[test]
+    fn serialise_mapping_with_required_flattened_field() {
+        let ser = serde_json::to_value(&RequiredFlattenedType::index_mapping()).unwrap();
+
+        assert_eq!(
+            Some(&json!({ "required": ["id", "created_by"] })),
+            ser.get("_meta")
+        );
+
+        // Flattened fields without required fields don't add `_meta`
+        let ser = serde_json::to_value(&FlattenedType::index_mapping()).unwrap();
+
+        assert!(ser.get("_meta").is_none());
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct AuthorType {
+        pub name: Keyword<DefaultKeywordMapping>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct PublisherType {
+        pub name: Text<DefaultTextMapping>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct ConflictingFlattenedType {
+        #[serde(flatten)]
+        pub author: AuthorType,
+        #[serde(flatten)]
+        pub publisher: PublisherType,
+    }
+
+    #[test]
+    fn serialise_mapping_with_conflicting_flattened_fields_fails() {
+        let err = serde_json::to_value(&ConflictingFlattenedType::index_mapping()).unwrap_err();
+
+        assert_eq!(
+            MappingError::DuplicateProperty { name: "name" }.to_string(),
+            err.to_string()
+        );
     }
 }
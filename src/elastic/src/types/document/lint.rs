@@ -0,0 +1,483 @@
+/*!
+Lints for common mistakes in resolved document mappings.
+
+Elasticsearch accepts these mappings, but they often don't behave the way they were intended to,
+like a `text` field that can't be used in aggregations, or a `keyword` that will reject long values.
+Lints are suggestions rather than errors, so they're reported instead of failing the mapping.
+
+# Examples
+
+Lint the mapping for a document type:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+struct MyType {
+    pub title: String,
+}
+
+let mapping = MyType::index_mapping().resolve().unwrap();
+
+for lint in lint(&mapping) {
+    println!("{}", lint);
+}
+```
+*/
+
+use super::{
+    resolved::DocumentMapping,
+    visitor::MappingVisitor,
+};
+use crate::types::string::keyword::mapping::MAX_IGNORE_ABOVE;
+use serde_json::Value;
+use std::fmt::{
+    Display,
+    Formatter,
+    Result as FmtResult,
+};
+
+// Names of fields that usually hold identifiers rather than prose
+const IDENTIFIER_NAMES: &[&str] = &[
+    "id", "uuid", "guid", "email", "sku", "slug", "status", "tag", "tags",
+];
+
+/** How serious a lint is. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintSeverity {
+    /** The mapping works, but might not do everything it's expected to. */
+    Info,
+    /** The mapping is likely to cause problems when documents are indexed or queried. */
+    Warning,
+}
+
+impl LintSeverity {
+    /** Get the name of this severity. */
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            LintSeverity::Info => "info",
+            LintSeverity::Warning => "warning",
+        }
+    }
+}
+
+impl Display for LintSeverity {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+/** The kind of problem a lint reports. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintKind {
+    /** A `text` field doesn't have a `keyword` multi-field, so it can't be sorted or aggregated on. */
+    TextWithoutKeyword,
+    /** A `keyword` field doesn't have an `ignore_above`, so long values can fail to index. */
+    KeywordWithoutIgnoreAbove,
+    /** A `keyword` field has an `ignore_above` that's larger than a single term can hold. */
+    KeywordIgnoreAboveTooLarge,
+    /** A `text` field has `fielddata` enabled, which can use a lot of heap memory. */
+    TextFielddata,
+    /** A field is analysed as `text` but looks like it holds an exact value, like an identifier. */
+    AnalyzedExactValue,
+    /** A field is stored separately even though its value is already kept in the `_source`. */
+    RedundantStore,
+    /** A `text` or `completion` field has a `search_analyzer` but no `analyzer` to index with. */
+    SearchAnalyzerWithoutAnalyzer,
+}
+
+impl LintKind {
+    /** Get the severity of this kind of lint. */
+    pub fn severity(&self) -> LintSeverity {
+        match *self {
+            LintKind::TextWithoutKeyword
+            | LintKind::KeywordWithoutIgnoreAbove
+            | LintKind::RedundantStore => LintSeverity::Info,
+            LintKind::KeywordIgnoreAboveTooLarge
+            | LintKind::TextFielddata
+            | LintKind::AnalyzedExactValue
+            | LintKind::SearchAnalyzerWithoutAnalyzer => LintSeverity::Warning,
+        }
+    }
+}
+
+/** A possible problem with a field in a mapping. */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Lint {
+    field: String,
+    kind: LintKind,
+    message: String,
+}
+
+impl Lint {
+    fn new(field: &str, kind: LintKind, message: impl Into<String>) -> Self {
+        Lint {
+            field: field.to_owned(),
+            kind,
+            message: message.into(),
+        }
+    }
+
+    /** Get the dotted path of the field the lint is for. */
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /** Get the kind of problem this lint reports. */
+    pub fn kind(&self) -> LintKind {
+        self.kind
+    }
+
+    /** Get how serious this lint is. */
+    pub fn severity(&self) -> LintSeverity {
+        self.kind.severity()
+    }
+
+    /** Get a description of the problem and how to fix it. */
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}: `{}` {}", self.severity(), self.field, self.message)
+    }
+}
+
+/**
+Lint a resolved document mapping.
+
+Lints are returned in the order their fields appear in the mapping.
+An empty list means no problems were found.
+*/
+pub fn lint(mapping: &DocumentMapping) -> Vec<Lint> {
+    let source_enabled = mapping
+        .as_map()
+        .get("_source")
+        .and_then(|source| source.get("enabled"))
+        .and_then(Value::as_bool)
+        != Some(false);
+
+    let mut linter = Linter {
+        source_enabled,
+        lints: Vec::new(),
+    };
+
+    mapping.visit(&mut linter);
+
+    linter.lints
+}
+
+struct Linter {
+    source_enabled: bool,
+    lints: Vec<Lint>,
+}
+
+impl Linter {
+    fn lint_store(&mut self, path: &str, mapping: &Value) {
+        if self.source_enabled && mapping.get("store").and_then(Value::as_bool) == Some(true) {
+            self.lints.push(Lint::new(
+                path,
+                LintKind::RedundantStore,
+                "has `store` enabled, but its value is already kept in the `_source`; stored fields use extra disk space",
+            ));
+        }
+    }
+
+    fn lint_keyword(&mut self, path: &str, mapping: &Value) {
+        match mapping.get("ignore_above").and_then(Value::as_u64) {
+            None => self.lints.push(Lint::new(
+                path,
+                LintKind::KeywordWithoutIgnoreAbove,
+                "has no `ignore_above`, so values longer than a single term will fail to index",
+            )),
+            Some(ignore_above) if ignore_above > u64::from(MAX_IGNORE_ABOVE) => {
+                self.lints.push(Lint::new(
+                    path,
+                    LintKind::KeywordIgnoreAboveTooLarge,
+                    format!(
+                        "has an `ignore_above` of {}, which is more than the {} characters that fit in a single term",
+                        ignore_above, MAX_IGNORE_ABOVE
+                    ),
+                ))
+            }
+            Some(_) => (),
+        }
+    }
+
+    fn lint_search_analyzer(&mut self, path: &str, mapping: &Value) {
+        if mapping.get("search_analyzer").is_some() && mapping.get("analyzer").is_none() {
+            self.lints.push(Lint::new(
+                path,
+                LintKind::SearchAnalyzerWithoutAnalyzer,
+                "has a `search_analyzer` but no `analyzer`, so it's indexed with the default analyzer instead",
+            ));
+        }
+    }
+
+    fn lint_text(&mut self, path: &str, mapping: &Value) {
+        self.lint_search_analyzer(path, mapping);
+
+        if mapping.get("fielddata").and_then(Value::as_bool) == Some(true) {
+            self.lints.push(Lint::new(
+                path,
+                LintKind::TextFielddata,
+                "has `fielddata` enabled, which loads the terms into heap memory; use a `keyword` multi-field instead",
+            ));
+        }
+    }
+}
+
+impl MappingVisitor for Linter {
+    fn visit_field(&mut self, path: &str, mapping: &Value) {
+        self.lint_store(path, mapping);
+
+        match data_type(mapping) {
+            Some("keyword") => self.lint_keyword(path, mapping),
+            Some("completion") => self.lint_search_analyzer(path, mapping),
+            Some("text") => {
+                self.lint_text(path, mapping);
+
+                let has_keyword = match mapping.get("fields") {
+                    Some(Value::Object(fields)) => fields
+                        .values()
+                        .any(|field| data_type(field) == Some("keyword")),
+                    _ => false,
+                };
+
+                if !has_keyword {
+                    self.lints.push(Lint::new(
+                        path,
+                        LintKind::TextWithoutKeyword,
+                        "has no `keyword` multi-field, so it can't be used for sorting or aggregations",
+                    ));
+                }
+
+                let name = path.rsplit('.').next().unwrap_or(path);
+                let keyword_analyzer =
+                    mapping.get("analyzer").and_then(Value::as_str) == Some("keyword");
+
+                if keyword_analyzer || is_identifier_name(name) {
+                    self.lints.push(Lint::new(
+                        path,
+                        LintKind::AnalyzedExactValue,
+                        "is analysed as `text` but looks like an exact value; map it as a `keyword` instead",
+                    ));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn visit_multi_field(&mut self, path: &str, mapping: &Value) {
+        // Multi-fields are alternative ways to index their parent,
+        // so they don't need their own `keyword`
+        self.lint_store(path, mapping);
+
+        match data_type(mapping) {
+            Some("keyword") => self.lint_keyword(path, mapping),
+            Some("completion") => self.lint_search_analyzer(path, mapping),
+            Some("text") => self.lint_text(path, mapping),
+            _ => (),
+        }
+    }
+}
+
+fn data_type(mapping: &Value) -> Option<&str> {
+    mapping.get("type").and_then(Value::as_str)
+}
+
+fn is_identifier_name(name: &str) -> bool {
+    let name = name.to_lowercase();
+
+    IDENTIFIER_NAMES.contains(&name.as_str()) || name.ends_with("_id")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::prelude::*;
+
+    fn lints(mapping: serde_json::Value) -> Vec<(String, LintKind)> {
+        let mapping = DocumentMapping::from_value(mapping).unwrap();
+
+        lint(&mapping)
+            .into_iter()
+            .map(|lint| (lint.field().to_owned(), lint.kind()))
+            .collect()
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct LintedType {
+        pub title: Text<DefaultStringMapping>,
+        pub count: i32,
+    }
+
+    #[test]
+    fn lint_default_mapping() {
+        let mapping = LintedType::index_mapping().resolve().unwrap();
+
+        assert!(lint(&mapping).is_empty());
+    }
+
+    #[test]
+    fn lint_text_without_keyword() {
+        let lints = lints(json!({
+            "properties": {
+                "body": {
+                    "type": "text",
+                    "fields": {
+                        "english": { "type": "text", "analyzer": "english" }
+                    }
+                }
+            }
+        }));
+
+        assert_eq!(
+            vec![("body".to_owned(), LintKind::TextWithoutKeyword)],
+            lints
+        );
+    }
+
+    #[test]
+    fn lint_keyword_ignore_above() {
+        let lints = lints(json!({
+            "properties": {
+                "code": { "type": "keyword" },
+                "blob": { "type": "keyword", "ignore_above": 100000 },
+                "name": { "type": "keyword", "ignore_above": 256 }
+            }
+        }));
+
+        assert_eq!(
+            vec![
+                ("blob".to_owned(), LintKind::KeywordIgnoreAboveTooLarge),
+                ("code".to_owned(), LintKind::KeywordWithoutIgnoreAbove),
+            ],
+            lints
+        );
+    }
+
+    #[test]
+    fn lint_text_fielddata() {
+        let mapping = DocumentMapping::from_value(json!({
+            "properties": {
+                "body": {
+                    "type": "text",
+                    "fielddata": true,
+                    "fields": {
+                        "keyword": { "type": "keyword", "ignore_above": 256 }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let lints = lint(&mapping);
+
+        assert_eq!(1, lints.len());
+        assert_eq!(LintKind::TextFielddata, lints[0].kind());
+        assert_eq!(LintSeverity::Warning, lints[0].severity());
+        assert!(lints[0]
+            .to_string()
+            .starts_with("warning: `body` has `fielddata` enabled"));
+    }
+
+    #[test]
+    fn lint_analyzed_exact_value() {
+        let lints = lints(json!({
+            "properties": {
+                "user_id": {
+                    "type": "text",
+                    "fields": {
+                        "keyword": { "type": "keyword", "ignore_above": 256 }
+                    }
+                },
+                "country": {
+                    "type": "text",
+                    "analyzer": "keyword",
+                    "fields": {
+                        "keyword": { "type": "keyword", "ignore_above": 256 }
+                    }
+                },
+                "summary": {
+                    "type": "text",
+                    "fields": {
+                        "keyword": { "type": "keyword", "ignore_above": 256 }
+                    }
+                }
+            }
+        }));
+
+        assert_eq!(
+            vec![
+                ("country".to_owned(), LintKind::AnalyzedExactValue),
+                ("user_id".to_owned(), LintKind::AnalyzedExactValue),
+            ],
+            lints
+        );
+    }
+
+    #[test]
+    fn lint_redundant_store() {
+        let lints = lints(json!({
+            "properties": {
+                "count": { "type": "integer", "store": true },
+                "total": { "type": "integer", "store": false }
+            }
+        }));
+
+        assert_eq!(vec![("count".to_owned(), LintKind::RedundantStore)], lints);
+    }
+
+    #[test]
+    fn lint_store_with_disabled_source() {
+        let lints = lints(json!({
+            "_source": {
+                "enabled": false
+            },
+            "properties": {
+                "count": { "type": "integer", "store": true }
+            }
+        }));
+
+        assert!(lints.is_empty());
+    }
+
+    #[test]
+    fn lint_search_analyzer_without_analyzer() {
+        let lints = lints(json!({
+            "properties": {
+                "suggest": {
+                    "type": "completion",
+                    "search_analyzer": "simple"
+                },
+                "body": {
+                    "type": "text",
+                    "analyzer": "english",
+                    "search_analyzer": "english_search",
+                    "fields": {
+                        "keyword": { "type": "keyword", "ignore_above": 256 },
+                        "search": { "type": "text", "search_analyzer": "standard" }
+                    }
+                }
+            }
+        }));
+
+        assert_eq!(
+            vec![
+                (
+                    "body.search".to_owned(),
+                    LintKind::SearchAnalyzerWithoutAnalyzer
+                ),
+                (
+                    "suggest".to_owned(),
+                    LintKind::SearchAnalyzerWithoutAnalyzer
+                ),
+            ],
+            lints
+        );
+    }
+}
@@ -1,10 +1,21 @@
 /*! Mapping for Elasticsearch document types. */
 
+use super::runtime::RuntimeFieldMapping;
+use crate::types::error::MappingError;
 use serde::{
     ser::SerializeStruct,
     Serialize,
     Serializer,
 };
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult,
+    },
+};
 
 /** A field that will be mapped as a nested document. */
 pub trait ObjectFieldType {
@@ -31,6 +42,17 @@ pub trait ObjectMapping {
         NESTED_DATATYPE
     }
 
+    /**
+    Whether the `type` of an `object` mapping is serialised.
+
+    Elasticsearch infers the `object` type for a field with `properties`,
+    so it's left out by default. Return `true` to always serialise `"type": "object"`.
+    An `object` without any properties, and any other type like `nested`, always serialises its `type`.
+    */
+    fn explicit_type() -> bool {
+        false
+    }
+
     /**
     Whether or not new properties should be added dynamically to an existing object.
     Accepts `true` (default), `false` and `strict`.
@@ -42,6 +64,8 @@ pub trait ObjectMapping {
     /**
     Whether the JSON value given for the object field should be parsed and indexed
     (`true`, default) or completely ignored (`false`).
+    This applies to each `object` or `nested` field separately,
+    so a branch of a document can be stored in `_source` without being indexed.
     */
     fn enabled() -> Option<bool> {
         None
@@ -54,6 +78,60 @@ pub trait ObjectMapping {
     fn include_in_all() -> Option<bool> {
         None
     }
+
+    /**
+    Whether dotted field names are expanded into inner objects (`true`, default)
+    or stored as flat fields with dots in their names (`false`).
+    This only applies to `object` mappings and the root of a document.
+    */
+    fn subobjects() -> Option<bool> {
+        None
+    }
+
+    /**
+    Arbitrary application metadata stored with the mapping, like a schema version.
+    This is only serialised when the mapping is the root of a document,
+    it's ignored for inner objects.
+    */
+    fn meta() -> Option<Value> {
+        None
+    }
+
+    /**
+    Whether strings that look like dates are dynamically mapped as `date` fields.
+    Accepts `true` (default) or `false`.
+    This is only serialised when the mapping is the root of a document.
+    */
+    fn date_detection() -> Option<bool> {
+        None
+    }
+
+    /**
+    The formats used to detect dates in dynamically mapped strings.
+    These are the names of date formats, like `EpochMillis::name()`.
+    This is only serialised when the mapping is the root of a document.
+    */
+    fn dynamic_date_formats() -> Option<Vec<&'static str>> {
+        None
+    }
+
+    /**
+    Whether strings that look like numbers are dynamically mapped as numeric fields.
+    Accepts `true` or `false` (default).
+    This is only serialised when the mapping is the root of a document.
+    */
+    fn numeric_detection() -> Option<bool> {
+        None
+    }
+
+    /**
+    Fields that are computed by a script when a document is queried, rather than indexed.
+    These are serialised in the `runtime` section of the mapping, separately from its properties.
+    This is only serialised when the mapping is the root of a document.
+    */
+    fn runtime() -> Option<BTreeMap<&'static str, RuntimeFieldMapping>> {
+        None
+    }
 }
 
 /**
@@ -77,6 +155,18 @@ pub trait PropertiesMapping {
     fn serialize_props<S>(state: &mut S) -> Result<(), S::Error>
     where
         S: SerializeStruct;
+
+    /**
+    Add the dynamic templates for the mapped property fields on this type.
+
+    The path of each property field is its name joined to the given `path` with a dot.
+    */
+    fn dynamic_templates(
+        _path: &str,
+        _templates: &mut Vec<Value>,
+    ) -> Result<(), serde_json::Error> {
+        Ok(())
+    }
 }
 
 /**
@@ -93,6 +183,23 @@ pub enum Dynamic {
     Strict,
 }
 
+impl Dynamic {
+    /** Get the value of this setting as a string. */
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Dynamic::True => "true",
+            Dynamic::False => "false",
+            Dynamic::Strict => "strict",
+        }
+    }
+}
+
+impl Display for Dynamic {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
 impl Serialize for Dynamic {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -106,6 +213,57 @@ impl Serialize for Dynamic {
     }
 }
 
+/**
+The longest field name that's accepted, in bytes.
+
+Elasticsearch limits the length of field names with the `index.mapping.field_name_length.limit` setting.
+*/
+pub const MAX_FIELD_NAME_LENGTH: usize = 255;
+
+/**
+Check that a field name will be accepted by Elasticsearch.
+
+Names can't be empty, start with an underscore, start or end with a `.`, contain consecutive dots,
+or be longer than `MAX_FIELD_NAME_LENGTH`.
+Names with dots in them are only accepted in objects with `subobjects: false`,
+which is checked when the properties of a document mapping are resolved.
+
+# Examples
+
+```
+# use elastic::types::prelude::*;
+assert!(validate_field_name("title").is_ok());
+assert!(validate_field_name("_title").is_err());
+```
+*/
+pub fn validate_field_name(name: &str) -> Result<(), MappingError> {
+    let invalid = |reason: &str| {
+        Err(MappingError::InvalidFieldName {
+            name: name.to_owned(),
+            reason: reason.to_owned(),
+        })
+    };
+
+    if name.trim().is_empty() {
+        invalid("names can't be empty")
+    } else if name.starts_with('_') {
+        invalid("names starting with `_` are reserved for metadata fields")
+    } else if name.starts_with('.') || name.ends_with('.') {
+        invalid("names can't start or end with a `.`")
+    } else if name.contains("..") {
+        invalid("names can't contain consecutive dots")
+    } else if name.len() > MAX_FIELD_NAME_LENGTH {
+        invalid(&format!(
+            "names can't be longer than {} bytes",
+            MAX_FIELD_NAME_LENGTH
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) use self::private::resolve_properties;
+
 mod private {
     use super::{
         ObjectFieldType,
@@ -115,9 +273,12 @@ mod private {
     };
     use crate::types::{
         document::{
+            validation,
             FieldDocumentMapping,
             IndexDocumentMapping,
+            Properties,
         },
+        error::MappingError,
         private::field::{
             FieldMapping,
             FieldType,
@@ -126,11 +287,22 @@ mod private {
         },
     };
     use serde::{
-        ser::SerializeStruct,
+        ser::{
+            Error as SerError,
+            SerializeStruct,
+        },
         Serialize,
         Serializer,
     };
-    use std::marker::PhantomData;
+    use serde_json::{
+        self,
+        Map,
+        Value,
+    };
+    use std::{
+        collections::HashSet,
+        marker::PhantomData,
+    };
 
     #[derive(Default)]
     pub struct ObjectPivot;
@@ -151,16 +323,23 @@ mod private {
         fn data_type() -> &'static str {
             <Self as ObjectMapping>::data_type()
         }
+
+        fn dynamic_templates(
+            path: &str,
+            templates: &mut Vec<Value>,
+        ) -> Result<(), serde_json::Error> {
+            TMapping::Properties::dynamic_templates(path, templates)
+        }
     }
 
-    struct Properties<TMapping>
+    struct SerializeProperties<TMapping>
     where
         TMapping: ObjectMapping,
     {
         _m: PhantomData<TMapping>,
     }
 
-    impl<TMapping> Serialize for Properties<TMapping>
+    impl<TMapping> Serialize for SerializeProperties<TMapping>
     where
         TMapping: ObjectMapping,
     {
@@ -168,10 +347,46 @@ mod private {
         where
             S: Serializer,
         {
-            let mut state =
+            let state =
                 serializer.serialize_struct("properties", TMapping::Properties::props_len())?;
+
+            let mut state = UniqueProperties {
+                state,
+                names: HashSet::new(),
+            };
             TMapping::Properties::serialize_props(&mut state)?;
-            state.end()
+            state.state.end()
+        }
+    }
+
+    // Properties from flattened fields could share a name with another property
+    struct UniqueProperties<S> {
+        state: S,
+        names: HashSet<&'static str>,
+    }
+
+    impl<S> SerializeStruct for UniqueProperties<S>
+    where
+        S: SerializeStruct,
+    {
+        type Ok = S::Ok;
+        type Error = S::Error;
+
+        fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), S::Error>
+        where
+            T: Serialize + ?Sized,
+        {
+            if !self.names.insert(key) {
+                return Err(S::Error::custom(MappingError::DuplicateProperty {
+                    name: key,
+                }));
+            }
+
+            self.state.serialize_field(key, value)
+        }
+
+        fn end(self) -> Result<S::Ok, S::Error> {
+            self.state.end()
         }
     }
 
@@ -188,25 +403,37 @@ mod private {
 
             let (is_object, has_props) = (ty == OBJECT_DATATYPE, props_len > 0);
 
+            // The `object` type is implied by `properties`
+            let has_type = !(is_object && has_props) || TMapping::explicit_type();
+
             let props_len = match (is_object, has_props) {
-                (true, true) => 5,
-                (true, false) | (false, true) => 4,
-                (false, false) => 3,
+                (true, true) => 6,
+                (true, false) => 5,
+                (false, true) => 5,
+                (false, false) => 4,
             };
 
+            let props_len = if has_type { props_len } else { props_len - 1 };
+
             let mut state = serializer.serialize_struct("mapping", props_len)?;
 
-            state.serialize_field("type", ty)?;
+            if has_type {
+                state.serialize_field("type", ty)?;
+            }
 
             ser_field!(state, "dynamic", TMapping::dynamic());
             ser_field!(state, "include_in_all", TMapping::include_in_all());
+            ser_field!(state, "enabled", TMapping::enabled());
 
             if is_object {
-                ser_field!(state, "enabled", TMapping::enabled());
+                ser_field!(state, "subobjects", TMapping::subobjects());
             }
 
             if has_props {
-                state.serialize_field("properties", &Properties::<TMapping> { _m: PhantomData })?;
+                state.serialize_field(
+                    "properties",
+                    &SerializeProperties::<TMapping> { _m: PhantomData },
+                )?;
             }
 
             state.end()
@@ -225,7 +452,60 @@ mod private {
         }
     }
 
+    /** Resolve the `properties` of a mapping without validating them. */
+    pub(crate) fn resolve_properties<TMapping>() -> Result<Map<String, Value>, serde_json::Error>
+    where
+        TMapping: ObjectMapping,
+    {
+        match serde_json::to_value(&SerializeProperties::<TMapping> { _m: PhantomData })? {
+            Value::Object(properties) => Ok(properties),
+            _ => Ok(Map::new()),
+        }
+    }
+
     impl<TMapping> Serialize for IndexDocumentMapping<TMapping>
+    where
+        TMapping: ObjectMapping,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let properties = SerializeProperties::<TMapping> { _m: PhantomData };
+
+            // Validate the resolved properties before serialising them
+            let resolved = resolve_properties::<TMapping>().map_err(S::Error::custom)?;
+            validation::validate_properties(&resolved, TMapping::subobjects().unwrap_or(true))
+                .map_err(S::Error::custom)?;
+
+            // Dynamic templates for the values of maps can only be set on the root
+            let mut dynamic_templates = Vec::new();
+            TMapping::Properties::dynamic_templates("", &mut dynamic_templates)
+                .map_err(S::Error::custom)?;
+
+            let mut state = serializer.serialize_struct("mapping", 9)?;
+
+            ser_field!(state, "_meta", TMapping::meta());
+            ser_field!(state, "dynamic", TMapping::dynamic());
+            ser_field!(state, "date_detection", TMapping::date_detection());
+            ser_field!(
+                state,
+                "dynamic_date_formats",
+                TMapping::dynamic_date_formats()
+            );
+            if !dynamic_templates.is_empty() {
+                state.serialize_field("dynamic_templates", &dynamic_templates)?;
+            }
+            ser_field!(state, "numeric_detection", TMapping::numeric_detection());
+            ser_field!(state, "runtime", TMapping::runtime());
+            ser_field!(state, "subobjects", TMapping::subobjects());
+            state.serialize_field("properties", &properties)?;
+
+            state.end()
+        }
+    }
+
+    impl<TMapping> Serialize for Properties<TMapping>
     where
         TMapping: ObjectMapping,
     {
@@ -235,7 +515,10 @@ mod private {
         {
             let mut state = serializer.serialize_struct("mapping", 1)?;
 
-            state.serialize_field("properties", &Properties::<TMapping> { _m: PhantomData })?;
+            state.serialize_field(
+                "properties",
+                &SerializeProperties::<TMapping> { _m: PhantomData },
+            )?;
 
             state.end()
         }
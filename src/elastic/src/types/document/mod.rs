@@ -64,6 +64,7 @@ This will produce the following field mapping:
     }
 }
 # );
+# #[cfg(not(feature = "string-as-keyword"))]
 # assert_eq!(json, mapping);
 ```
 
@@ -185,6 +186,157 @@ pub struct MyType {
 }
 ```
 
+### Recording required fields
+
+Elasticsearch doesn't have a concept of required fields, but it can be useful to keep track of them in the mapping.
+The `#[elastic(required)]` attribute on a field adds it to a `required` list in the document's `_meta`:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+pub struct MyType {
+    #[elastic(required)]
+    pub id: String,
+    #[elastic(required)]
+    pub name: String,
+    pub my_num: i32
+}
+```
+
+The fields are listed with their serialised names, like `{"required":["id","name"]}`.
+The attribute can't be used with a custom document `#[elastic(mapping)]`, which sets its own `meta`.
+
+### Disabling indexing for a field
+
+Fields are indexed by default, so they can be searched.
+The `#[elastic(index)]` attribute on a field can be used to stop a field from being indexed, without having to write a custom mapping for it:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+pub struct MyType {
+    #[elastic(index = "no")]
+    pub my_string: String,
+    #[elastic(index = false)]
+    pub my_num: i32
+}
+```
+
+The attribute accepts `true` or `false`, or the strings `"yes"` and `"no"`.
+It overrides any `index` parameter on the field's mapping.
+It can't be used on `object` or `nested` fields, which aren't indexed themselves.
+Use `enabled` on their `ObjectMapping` instead.
+
+### Setting the analyzers for a field
+
+The `#[elastic(analyzer)]` and `#[elastic(search_analyzer)]` attributes can be used to set the analyzers for a text field,
+without having to write a custom `TextMapping` for it:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+pub struct MyType {
+    #[elastic(analyzer = "english", search_analyzer = "standard")]
+    pub my_string: String
+}
+```
+
+Serialising the mapping fails if the field's type can't be analysed, like a `keyword`.
+
+### Overriding the mapping for a field
+
+A field's mapping is usually inferred from its type.
+The `#[elastic(mapping)]` attribute on a field can be used to give it a different mapping instead:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] use elastic::types::prelude::*;
+#[derive(Default)]
+pub struct MyKeywordMapping;
+impl KeywordMapping for MyKeywordMapping {
+    fn ignore_above() -> Option<u32> {
+        Some(64)
+    }
+}
+
+#[derive(Serialize, ElasticType)]
+pub struct MyType {
+    #[elastic(mapping = "MyKeywordMapping")]
+    pub my_string: String
+}
+```
+
+The mapping type must implement one of the mapping traits, like `KeywordMapping`.
+The field's value is serialised as normal, so it needs to be compatible with the mapping.
+
+### Newtype fields
+
+Deriving `ElasticType` on a newtype, like `struct Email(String)`, makes it a field that's mapped the same as its inner type:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+pub struct Email(String);
+
+#[derive(Serialize, ElasticType)]
+pub struct MyType {
+    pub email: Email
+}
+```
+
+The `#[elastic(mapping)]` attribute on the newtype can be used to give it a different mapping instead:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] use elastic::types::prelude::*;
+#[derive(Default)]
+pub struct EmailMapping;
+impl KeywordMapping for EmailMapping {}
+
+#[derive(Serialize, ElasticType)]
+#[elastic(mapping = "EmailMapping")]
+pub struct Email(String);
+```
+
+A newtype is a field, not a document, so it doesn't get a document mapping of its own.
+
+### Flattening fields
+
+Fields with the `#[serde(flatten)]` attribute have their properties merged into the parent's properties,
+the same way `serde` merges them when the document is serialised:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+pub struct Audit {
+    pub created_by: Keyword<DefaultKeywordMapping>,
+    pub created_at: Date<DefaultDateMapping>,
+}
+
+#[derive(Serialize, ElasticType)]
+pub struct MyType {
+    pub my_string: String,
+    #[serde(flatten)]
+    pub audit: Audit,
+}
+```
+
+Only the properties of types that derive `ElasticType` are merged.
+Other flattened fields, like a `HashMap<String, Value>` that collects any unknown fields, are mapped like any other field.
+If a flattened field has a property with the same name as another property, then serialising the mapping fails.
+
 ### Override Default Mapping Properties
 
 You can override the mapping meta properties for an object by providing your own mapping type with `#[elastic(mapping="{TypeName}")]`:
@@ -234,7 +386,6 @@ This will produce the following field mapping:
 # let mapping = elastic::types::__derive::standalone_field_ser(MyTypeMapping).unwrap();
 # let json = json!(
 {
-    "type": "object",
     "properties": {
         "my_date": {
             "type": "date",
@@ -255,9 +406,13 @@ This will produce the following field mapping:
     }
 }
 # );
+# #[cfg(not(feature = "string-as-keyword"))]
 # assert_eq!(json, mapping);
 ```
 
+The `object` type is implied by the `properties`, so it isn't serialised.
+Return `true` from `ObjectMapping::explicit_type` to serialise `"type": "object"` anyway.
+
 ### Ignore or Rename Fields
 
 You can then serialise type mappings with `#[serde]` attributes:
@@ -279,6 +434,17 @@ pub struct MyType {
 > NOTE: Fields with a `#[serde(skip_deserializing)]` attribute will still be mapped, because they can
 still be indexed in Elasticsearch.
 
+### Field ordering
+
+Serialised mappings always have the same field order, so they can be compared as text.
+Generated mappings are diff-friendly when they're checked in to source control.
+
+- A field mapping starts with its `type`, followed by its parameters in the order they're declared on the mapping trait. An `object` with `properties` leaves out its implied `type`.
+- The `properties` of a derived document are sorted by their serialised name, regardless of the order they're declared in on the struct. Properties merged from `#[serde(flatten)]` fields are sorted along with the document's own properties.
+- Maps like multi-`fields` and `_meta` are sorted by key.
+- A field mapping with parameters set by attributes, like `#[elastic(index = false)]`, has its parameters after its `type` sorted by name.
+- The root of a document mapping has its parameters, like `_meta` and `subobjects`, before its `properties`.
+
 ## Limitations
 
 Automatically deriving mapping has the following limitations:
@@ -293,9 +459,17 @@ So you can't share `MyTypeMapping` between `MyType` and `MyOtherType`.
 - [Document Types](https://www.elastic.co/guide/en/elasticsearch/reference/current/mapping.html)
 */
 
+pub mod lint;
 pub mod mapping;
+pub mod registry;
+pub mod resolved;
+pub mod runtime;
+pub mod update;
+pub mod visitor;
 
 mod impls;
+mod schema;
+mod validation;
 pub use self::impls::*;
 
 pub mod prelude {
@@ -312,6 +486,11 @@ pub mod prelude {
             StaticIndex,
             StaticType,
         },
+        lint::*,
         mapping::*,
+        registry::*,
+        resolved::*,
+        runtime::*,
+        update::*,
     };
 }
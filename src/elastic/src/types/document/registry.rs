@@ -0,0 +1,286 @@
+/*!
+A registry of the mappings for an application's document types.
+
+Applications with many document types can register each of them in a `MappingRegistry`,
+so their mappings can be looked up by name without knowing their Rust type.
+
+# Examples
+
+Register document types and look up their mappings:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+struct Article {
+    pub title: String,
+}
+
+#[derive(Serialize, ElasticType)]
+#[elastic(index = "comments")]
+struct Comment {
+    pub body: String,
+}
+
+let mut registry = MappingRegistry::new();
+
+registry.register::<Article>()?.register::<Comment>()?;
+
+assert!(registry.get("article").is_some());
+assert!(registry.get("comments").is_some());
+# Ok::<(), serde_json::Error>(())
+```
+
+Get the bodies for creating an index for each registered document type:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+# #[derive(Serialize, ElasticType)]
+# struct Article {
+#     pub title: String,
+# }
+# let mut registry = MappingRegistry::new();
+# registry.register::<Article>()?;
+for (index, body) in registry.create_index_bodies() {
+    let body = serde_json::to_string(&body)?;
+
+    // Send the body to the Create Index API for `index`
+}
+# Ok::<(), serde_json::Error>(())
+```
+*/
+
+use super::{
+    impls::StaticIndex,
+    resolved::DocumentMapping,
+};
+use crate::types::index::CreateIndexBody;
+use serde_json;
+use std::{
+    collections::BTreeMap,
+    sync::OnceLock,
+};
+
+/**
+A collection of document mappings, looked up by the name of their document type.
+
+Documents are registered by the name of their static index,
+which is the lowercased name of the Rust type unless it's set with `#[elastic(index)]`.
+*/
+#[derive(Debug, Clone, Default)]
+pub struct MappingRegistry {
+    mappings: BTreeMap<String, Registered>,
+}
+
+#[derive(Debug, Clone)]
+struct Registered {
+    mapping: DocumentMapping,
+    json: OnceLock<String>,
+}
+
+impl MappingRegistry {
+    /** Create an empty registry. */
+    pub fn new() -> Self {
+        MappingRegistry::default()
+    }
+
+    /**
+    Register the mapping for a document type.
+
+    The mapping is resolved and validated when it's registered.
+    Registering a type with the same name as one that's already registered replaces it.
+    */
+    pub fn register<TDocument>(&mut self) -> Result<&mut Self, serde_json::Error>
+    where
+        TDocument: StaticIndex,
+    {
+        let mapping = TDocument::index_mapping().resolve()?;
+
+        self.mappings.insert(
+            TDocument::static_index().to_string(),
+            Registered {
+                mapping,
+                json: OnceLock::new(),
+            },
+        );
+
+        Ok(self)
+    }
+
+    /** Get the mapping for the document type with the given name. */
+    pub fn get(&self, name: &str) -> Option<&DocumentMapping> {
+        self.mappings
+            .get(name)
+            .map(|registered| &registered.mapping)
+    }
+
+    /**
+    Get the json for the mapping of the document type with the given name.
+
+    The json is serialised the first time it's requested and cached,
+    so mappings that are sent with many requests are only serialised once.
+    Registering another type with the same name replaces the cached json.
+    The `registry` bench compares getting the cached json with serialising the mapping each time.
+    */
+    pub fn get_json(&self, name: &str) -> Option<&str> {
+        self.mappings.get(name).map(|registered| {
+            registered
+                .json
+                .get_or_init(|| {
+                    // Mappings only have string keys, so they can always be converted into json
+                    serde_json::to_string(&registered.mapping)
+                        .expect("failed to convert a mapping into json")
+                })
+                .as_str()
+        })
+    }
+
+    /** Iterate over the names and mappings of the registered document types, sorted by name. */
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &DocumentMapping)> {
+        self.mappings
+            .iter()
+            .map(|(name, registered)| (name.as_str(), &registered.mapping))
+    }
+
+    /** Get the number of registered document types. */
+    pub fn len(&self) -> usize {
+        self.mappings.len()
+    }
+
+    /**
+    Get the bodies for creating an index for each of the registered document types.
+
+    The bodies are keyed by the name of the index they create.
+    */
+    pub fn create_index_bodies(&self) -> BTreeMap<String, CreateIndexBody> {
+        self.mappings
+            .iter()
+            .map(|(name, registered)| {
+                (
+                    name.clone(),
+                    CreateIndexBody::new(registered.mapping.clone()),
+                )
+            })
+            .collect()
+    }
+
+    /** Whether there are no registered document types. */
+    pub fn is_empty(&self) -> bool {
+        self.mappings.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::prelude::*;
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct RegisteredArticle {
+        pub title: Keyword<DefaultKeywordMapping>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", index = "comments")]
+    pub struct RegisteredComment {
+        pub likes: i64,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", index = "comments")]
+    pub struct RegisteredCommentV2 {
+        pub likes: i32,
+    }
+
+    #[test]
+    fn register_and_get_mappings() {
+        let mut registry = MappingRegistry::new();
+
+        registry
+            .register::<RegisteredArticle>()
+            .unwrap()
+            .register::<RegisteredComment>()
+            .unwrap();
+
+        assert_eq!(2, registry.len());
+
+        let article = registry.get("registeredarticle").unwrap();
+        assert_eq!(
+            json!({ "properties": { "title": { "type": "keyword" } } }),
+            serde_json::to_value(article).unwrap()
+        );
+
+        let comment = registry.get("comments").unwrap();
+        assert_eq!(
+            json!({ "properties": { "likes": { "type": "long" } } }),
+            serde_json::to_value(comment).unwrap()
+        );
+
+        assert!(registry.get("missing").is_none());
+    }
+
+    #[test]
+    fn create_index_bodies_for_registered_types() {
+        let mut registry = MappingRegistry::new();
+
+        registry
+            .register::<RegisteredArticle>()
+            .unwrap()
+            .register::<RegisteredComment>()
+            .unwrap();
+
+        let bodies = registry.create_index_bodies();
+
+        assert_eq!(
+            vec!["comments", "registeredarticle"],
+            bodies.keys().collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            json!({ "mappings": { "properties": { "likes": { "type": "long" } } } }),
+            serde_json::to_value(&bodies["comments"]).unwrap()
+        );
+
+        assert_eq!(
+            json!({ "mappings": { "properties": { "title": { "type": "keyword" } } } }),
+            serde_json::to_value(&bodies["registeredarticle"]).unwrap()
+        );
+    }
+
+    #[test]
+    fn get_cached_mapping_json() {
+        let mut registry = MappingRegistry::new();
+
+        registry.register::<RegisteredComment>().unwrap();
+
+        let first = registry.get_json("comments").unwrap();
+        let second = registry.get_json("comments").unwrap();
+
+        assert_eq!(r#"{"properties":{"likes":{"type":"long"}}}"#, first);
+        assert!(std::ptr::eq(first, second));
+
+        assert!(registry.get_json("missing").is_none());
+    }
+
+    #[test]
+    fn register_replaces_cached_mapping_json() {
+        let mut registry = MappingRegistry::new();
+
+        registry.register::<RegisteredComment>().unwrap();
+        assert_eq!(
+            r#"{"properties":{"likes":{"type":"long"}}}"#,
+            registry.get_json("comments").unwrap()
+        );
+
+        registry.register::<RegisteredCommentV2>().unwrap();
+        assert_eq!(
+            r#"{"properties":{"likes":{"type":"integer"}}}"#,
+            registry.get_json("comments").unwrap()
+        );
+    }
+}
@@ -0,0 +1,1147 @@
+/*!
+Document mappings resolved into json.
+
+Mapping types are static, so the mappings for different document types can't be stored together.
+A `DocumentMapping` is the json for a document's mapping, resolved from its mapping type,
+that can be stored, inspected and combined at runtime.
+
+# Examples
+
+Resolve the mapping for a document type:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+struct MyType {
+    pub title: String,
+}
+
+let mapping = MyType::index_mapping().resolve().unwrap();
+
+# #[cfg(not(feature = "string-as-keyword"))]
+assert!(mapping.get_field("title.keyword").is_some());
+```
+*/
+
+use super::{
+    impls::DisplayJson,
+    schema,
+    visitor::{
+        self,
+        MappingVisitor,
+    },
+};
+use crate::types::error::MappingError;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use serde_json::{
+    self,
+    Map,
+    Value,
+};
+use std::{
+    collections::BTreeMap,
+    io::Write,
+};
+
+/** The default value of the `index.mapping.total_fields.limit` setting. */
+pub const DEFAULT_TOTAL_FIELDS_LIMIT: u32 = 1000;
+
+/** The default value of the `index.mapping.depth.limit` setting. */
+pub const DEFAULT_DEPTH_LIMIT: u32 = 20;
+
+/**
+The resolved json mapping for a document.
+
+This is the same mapping that's serialised by an `IndexDocumentMapping`,
+so it's suitable as the mapping for the Create Index API.
+
+Field names are stored as owned strings in a json map, so names that are repeated throughout a mapping,
+like the `keyword` multi-field of each `text` field, each have their own allocation.
+The json map doesn't support sharing its keys, so they can't be interned.
+Static mappings use `&'static str` names and don't allocate,
+so they're the more compact representation for large mappings.
+*/
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DocumentMapping {
+    mapping: Map<String, Value>,
+}
+
+impl DocumentMapping {
+    /**
+    Create a document mapping from its json.
+
+    The mapping is the root object with the `properties` of the document,
+    like the one returned by the Get Mapping API.
+    */
+    pub fn from_value(mapping: Value) -> Result<DocumentMapping, MappingError> {
+        match mapping {
+            Value::Object(mapping) => Ok(DocumentMapping { mapping }),
+            _ => Err(MappingError::InvalidParameter {
+                name: "mapping",
+                reason: "a document mapping must be a json object".to_owned(),
+            }),
+        }
+    }
+
+    /** Get the root json object of the mapping. */
+    pub fn as_map(&self) -> &Map<String, Value> {
+        &self.mapping
+    }
+
+    /** Get the `properties` of the mapping, if there are any. */
+    pub fn properties(&self) -> Option<&Map<String, Value>> {
+        match self.mapping.get("properties") {
+            Some(Value::Object(properties)) => Some(properties),
+            _ => None,
+        }
+    }
+
+    /**
+    Get the mapping for the field at a dotted path, like `address.city.keyword`.
+
+    Returns `None` if there's no field at the given path.
+    */
+    pub fn get_field(&self, path: &str) -> Option<&Value> {
+        self.properties()
+            .and_then(|properties| visitor::get_field(properties, path))
+    }
+
+    /** Walk the fields of this mapping with the given visitor. */
+    pub fn visit<V>(&self, visitor: &mut V)
+    where
+        V: MappingVisitor + ?Sized,
+    {
+        if let Some(properties) = self.properties() {
+            visitor::walk_properties(properties, visitor);
+        }
+    }
+
+    /**
+    Count the fields in this mapping.
+
+    Objects and multi-fields are counted as fields, the same way Elasticsearch counts them
+    against the `index.mapping.total_fields.limit` setting.
+    */
+    pub fn field_count(&self) -> usize {
+        struct FieldCount(usize);
+
+        impl MappingVisitor for FieldCount {
+            fn visit_field(&mut self, _: &str, _: &Value) {
+                self.0 += 1;
+            }
+        }
+
+        let mut count = FieldCount(0);
+        self.visit(&mut count);
+
+        count.0
+    }
+
+    /**
+    Check that this mapping doesn't have more fields than the given limit.
+
+    If there's no limit then the default of `1000` is used.
+    */
+    pub fn validate_total_fields(&self, limit: Option<u32>) -> Result<(), MappingError> {
+        let limit = limit.unwrap_or(DEFAULT_TOTAL_FIELDS_LIMIT);
+        let count = self.field_count();
+
+        if count > limit as usize {
+            return Err(MappingError::TooManyFields { count, limit });
+        }
+
+        Ok(())
+    }
+
+    /**
+    Get the names of the normalizers used by fields in this mapping, keyed by the path of the field.
+
+    Normalizers are only referenced by name in a mapping, so they aren't resolved here.
+    The names can be checked against the analysis settings for the index the mapping is used with.
+    */
+    pub fn normalizers(&self) -> BTreeMap<String, String> {
+        struct Normalizers(BTreeMap<String, String>);
+
+        impl MappingVisitor for Normalizers {
+            fn visit_field(&mut self, path: &str, mapping: &Value) {
+                if let Some(normalizer) = mapping.get("normalizer").and_then(Value::as_str) {
+                    self.0.insert(path.to_owned(), normalizer.to_owned());
+                }
+            }
+        }
+
+        let mut normalizers = Normalizers(BTreeMap::new());
+        self.visit(&mut normalizers);
+
+        normalizers.0
+    }
+
+    /**
+    Get the path and depth of the deepest field in this mapping.
+
+    A field in the root of the mapping has a depth of `1`, and each object it's nested in adds `1`.
+    Multi-fields and dots in field names don't add to the depth.
+    Returns `None` if the mapping has no fields.
+    */
+    pub fn max_depth(&self) -> Option<(String, usize)> {
+        let mut deepest = None;
+
+        if let Some(properties) = self.properties() {
+            deepest_field(properties, "", 1, &mut deepest);
+        }
+
+        deepest
+    }
+
+    /**
+    Check that no field in this mapping is nested deeper than the given limit.
+
+    If there's no limit then the default of `20` is used.
+    */
+    pub fn validate_depth(&self, limit: Option<u32>) -> Result<(), MappingError> {
+        let limit = limit.unwrap_or(DEFAULT_DEPTH_LIMIT);
+
+        match self.max_depth() {
+            Some((field, depth)) if depth > limit as usize => Err(MappingError::TooDeep {
+                field,
+                depth,
+                limit,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /**
+    Check that the given fields don't have `doc_values` disabled.
+
+    Fields that are used for sorting, aggregations or scripts need `doc_values`,
+    but Elasticsearch only rejects a field without them when it's queried.
+    The fields are dotted paths, like `address.city.keyword`.
+    Paths that aren't in the mapping are ignored.
+    */
+    pub fn validate_doc_values<I, S>(&self, fields: I) -> Result<(), MappingError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for field in fields {
+            let field = field.as_ref();
+
+            let disabled = self
+                .get_field(field)
+                .and_then(|mapping| mapping.get("doc_values"))
+                .and_then(Value::as_bool)
+                == Some(false);
+
+            if disabled {
+                return Err(MappingError::DocValuesDisabled {
+                    field: field.to_owned(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+    Merge the fields of another mapping into this one.
+
+    The `properties` of both mappings are combined, including the `properties` of objects that are in both.
+    A field that's in both mappings must have the same definition in each,
+    and so must root parameters like `dynamic`, otherwise merging fails.
+    The keys of `_meta` are combined, and so are `dynamic_templates` with different names,
+    but a key or template that's in both mappings must be the same in each.
+    */
+    pub fn merge(mut self, other: DocumentMapping) -> Result<DocumentMapping, MappingError> {
+        merge_root(&mut self.mapping, other.mapping)?;
+
+        Ok(self)
+    }
+
+    /**
+    Copy the values of the given source fields into a single target field.
+
+    This is the replacement for the `_all` field that was removed in Elasticsearch `6.x`,
+    a catch-all `text` field that can be queried instead of each of its sources.
+    The target is added to the `copy_to` of each source, keeping any targets it already copies to.
+    If the target isn't already in the mapping then it's added as a `text` field,
+    in the object that's its parent.
+
+    The sources and target are dotted paths, like `address.city`.
+    Mapping fails if a source or the parent of the target isn't in the mapping,
+    or if the target is already mapped as a type other than `text`.
+    */
+    pub fn copy_to<I, S>(
+        mut self,
+        sources: I,
+        target: &str,
+    ) -> Result<DocumentMapping, MappingError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let properties = match self
+            .mapping
+            .entry("properties")
+            .or_insert_with(|| Value::Object(Map::new()))
+        {
+            Value::Object(properties) => properties,
+            _ => {
+                return Err(MappingError::InvalidParameter {
+                    name: "properties",
+                    reason: "the properties of a mapping must be a json object".to_owned(),
+                })
+            }
+        };
+
+        insert_copy_to_target(properties, target)?;
+
+        for source in sources {
+            let source = source.as_ref();
+
+            if source == target {
+                return Err(MappingError::InvalidParameter {
+                    name: "copy_to",
+                    reason: format!("the field `{}` can't copy to itself", source),
+                });
+            }
+
+            let field = property_mut(properties, source)
+                .and_then(Value::as_object_mut)
+                .ok_or_else(|| MappingError::UnknownField {
+                    field: source.to_owned(),
+                })?;
+
+            let mut targets = match field.remove("copy_to") {
+                Some(Value::Array(targets)) => targets,
+                Some(existing) => vec![existing],
+                None => Vec::new(),
+            };
+
+            let target = Value::String(target.to_owned());
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+
+            field.insert("copy_to".to_owned(), Value::Array(targets));
+        }
+
+        Ok(self)
+    }
+
+    /**
+    Write the json for the mapping to a writer.
+
+    The mapping is serialised straight into the writer,
+    so large mappings don't need to be buffered as a string first.
+    */
+    pub fn write_to<W>(&self, writer: W) -> Result<(), serde_json::Error>
+    where
+        W: Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+
+    /** Preview the mapping as compact json, for assertions and logs. */
+    pub fn preview(&self) -> DisplayJson<Self> {
+        DisplayJson::new(self)
+    }
+
+    /**
+    Get a JSON Schema describing documents with this mapping.
+
+    The schema is best-effort, text and keyword fields are strings, numeric fields are integers or numbers,
+    and `nested` fields are objects.
+    Dates are described by their `format`, ISO 8601 dates are strings with a `date-time` format,
+    epoch dates are integers or strings of digits, and dates with other formats are strings.
+    Any field can be a single value or an array of values.
+    Fields with a datatype that doesn't have an equivalent in JSON Schema, like `geo_point`, accept any value.
+    Fields recorded as required in the `_meta` of the mapping are required by the schema.
+    */
+    pub fn to_json_schema(&self) -> Value {
+        schema::document_schema(&self.mapping)
+    }
+
+    /** Get the json for the mapping. */
+    pub fn into_value(self) -> Value {
+        Value::Object(self.mapping)
+    }
+}
+
+// Merge the root parameters of a mapping, combining their `properties`
+/**
+Find the deepest field in some `properties`.
+
+The depth is counted by walking the inner `properties` of objects rather than from the path,
+because a field name in an object with `subobjects: false` can contain dots.
+*/
+fn deepest_field(
+    properties: &Map<String, Value>,
+    prefix: &str,
+    depth: usize,
+    deepest: &mut Option<(String, usize)>,
+) {
+    for (name, mapping) in properties {
+        let path = format!("{}{}", prefix, name);
+
+        if deepest
+            .as_ref()
+            .map(|&(_, max)| depth > max)
+            .unwrap_or(true)
+        {
+            *deepest = Some((path.clone(), depth));
+        }
+
+        if let Some(Value::Object(properties)) = mapping.get("properties") {
+            deepest_field(properties, &format!("{}.", path), depth + 1, deepest);
+        }
+    }
+}
+
+fn merge_root(
+    mapping: &mut Map<String, Value>,
+    other: Map<String, Value>,
+) -> Result<(), MappingError> {
+    for (name, value) in other {
+        match (mapping.get_mut(&name), value) {
+            (Some(Value::Object(properties)), Value::Object(other)) if name == "properties" => {
+                merge_properties(properties, other, "")?
+            }
+            (Some(Value::Object(meta)), Value::Object(other)) if name == "_meta" => {
+                merge_meta(meta, other)?
+            }
+            (Some(Value::Array(templates)), Value::Array(other)) if name == "dynamic_templates" => {
+                merge_dynamic_templates(templates, other)?
+            }
+            (Some(existing), value) => {
+                if *existing != value {
+                    return Err(MappingError::ConflictingFieldMapping { field: name });
+                }
+            }
+            (None, value) => {
+                mapping.insert(name, value);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn merge_meta(
+    meta: &mut Map<String, Value>,
+    other: Map<String, Value>,
+) -> Result<(), MappingError> {
+    for (key, value) in other {
+        match meta.get(&key) {
+            Some(existing) if *existing != value => {
+                return Err(MappingError::ConflictingFieldMapping {
+                    field: format!("_meta.{}", key),
+                });
+            }
+            Some(_) => (),
+            None => {
+                meta.insert(key, value);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn merge_dynamic_templates(
+    templates: &mut Vec<Value>,
+    other: Vec<Value>,
+) -> Result<(), MappingError> {
+    // Each template is an object with its name as the only key
+    fn template_name(template: &Value) -> Option<&str> {
+        match template {
+            Value::Object(template) if template.len() == 1 => {
+                template.keys().next().map(String::as_str)
+            }
+            _ => None,
+        }
+    }
+
+    for template in other {
+        let existing = templates
+            .iter()
+            .find(|existing| match template_name(&template) {
+                Some(name) => template_name(existing) == Some(name),
+                None => **existing == template,
+            });
+
+        match existing {
+            Some(existing) if *existing != template => {
+                return Err(MappingError::ConflictingFieldMapping {
+                    field: format!(
+                        "dynamic_templates.{}",
+                        template_name(&template).unwrap_or_default()
+                    ),
+                });
+            }
+            Some(_) => (),
+            None => templates.push(template),
+        }
+    }
+
+    Ok(())
+}
+
+fn merge_properties(
+    properties: &mut Map<String, Value>,
+    other: Map<String, Value>,
+    parent: &str,
+) -> Result<(), MappingError> {
+    for (name, field) in other {
+        let path = if parent.is_empty() {
+            name.clone()
+        } else {
+            format!("{}.{}", parent, name)
+        };
+
+        let existing = match properties.get_mut(&name) {
+            Some(existing) => existing,
+            None => {
+                properties.insert(name, field);
+                continue;
+            }
+        };
+
+        let (existing, mut field) = match (existing, field) {
+            (Value::Object(existing), Value::Object(field)) => (existing, field),
+            _ => return Err(MappingError::ConflictingFieldMapping { field: path }),
+        };
+
+        let (existing_ty, ty) = (data_type(existing), data_type(&field));
+        if existing_ty != ty {
+            return Err(MappingError::ConflictingFieldType {
+                field: path,
+                existing: existing_ty,
+                requested: ty,
+            });
+        }
+
+        // Fields must have the same parameters, but objects can have different properties
+        let inner = field.remove("properties");
+
+        let mut existing_params = existing.clone();
+        existing_params.remove("properties");
+        existing_params.remove("type");
+        field.remove("type");
+
+        if existing_params != field {
+            return Err(MappingError::ConflictingFieldMapping { field: path });
+        }
+
+        match (existing.get_mut("properties"), inner) {
+            (Some(Value::Object(existing)), Some(Value::Object(inner))) => {
+                merge_properties(existing, inner, &path)?
+            }
+            (None, Some(inner)) => {
+                existing.insert("properties".to_owned(), inner);
+            }
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
+
+// Add a `text` field for the target of `copy_to`, if there isn't already one
+fn insert_copy_to_target(
+    properties: &mut Map<String, Value>,
+    target: &str,
+) -> Result<(), MappingError> {
+    if let Some(existing) = property(properties, target) {
+        let existing_ty = existing
+            .as_object()
+            .map(data_type)
+            .unwrap_or_else(|| "object".to_owned());
+
+        if existing_ty != "text" {
+            return Err(MappingError::ConflictingFieldType {
+                field: target.to_owned(),
+                existing: existing_ty,
+                requested: "text".to_owned(),
+            });
+        }
+
+        return Ok(());
+    }
+
+    let (parent, name) = match target.rfind('.') {
+        Some(i) => {
+            let parent = &target[..i];
+            let parent = property_mut(properties, parent)
+                .and_then(|parent| parent.as_object_mut())
+                .map(|parent| {
+                    parent
+                        .entry("properties")
+                        .or_insert_with(|| Value::Object(Map::new()))
+                })
+                .and_then(Value::as_object_mut)
+                .ok_or_else(|| MappingError::UnknownField {
+                    field: parent.to_owned(),
+                })?;
+
+            (parent, &target[i + 1..])
+        }
+        None => (properties, target),
+    };
+
+    let mut field = Map::new();
+    field.insert("type".to_owned(), Value::String("text".to_owned()));
+
+    parent.insert(name.to_owned(), Value::Object(field));
+
+    Ok(())
+}
+
+// Find a field at a dotted path through the `properties` of objects, but not multi-fields
+fn property<'a>(properties: &'a Map<String, Value>, path: &str) -> Option<&'a Value> {
+    if let Some(field) = properties.get(path) {
+        return Some(field);
+    }
+
+    path.match_indices('.').find_map(
+        |(i, _)| match properties.get(&path[..i])?.get("properties") {
+            Some(Value::Object(inner)) => property(inner, &path[i + 1..]),
+            _ => None,
+        },
+    )
+}
+
+fn property_mut<'a>(properties: &'a mut Map<String, Value>, path: &str) -> Option<&'a mut Value> {
+    if properties.contains_key(path) {
+        return properties.get_mut(path);
+    }
+
+    // Find where the path splits before borrowing the properties mutably
+    let (i, _) = path.match_indices('.').find(|&(i, _)| {
+        match properties
+            .get(&path[..i])
+            .and_then(|field| field.get("properties"))
+        {
+            Some(Value::Object(inner)) => property(inner, &path[i + 1..]).is_some(),
+            _ => false,
+        }
+    })?;
+
+    match properties.get_mut(&path[..i])?.get_mut("properties") {
+        Some(Value::Object(inner)) => property_mut(inner, &path[i + 1..]),
+        _ => None,
+    }
+}
+
+// Objects can be mapped without an explicit type
+fn data_type(field: &Map<String, Value>) -> String {
+    field
+        .get("type")
+        .and_then(Value::as_str)
+        .unwrap_or("object")
+        .to_owned()
+}
+
+impl Serialize for DocumentMapping {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.mapping.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DocumentMapping {
+    fn deserialize<D>(deserializer: D) -> Result<DocumentMapping, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mapping = Map::deserialize(deserializer)?;
+
+        Ok(DocumentMapping { mapping })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    use crate::types::{
+        error::MappingError,
+        prelude::*,
+    };
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct ResolvedType {
+        pub title: String,
+        pub count: i32,
+    }
+
+    #[test]
+    fn resolve_index_mapping() {
+        let mapping = ResolvedType::index_mapping().resolve().unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&ResolvedType::index_mapping()).unwrap(),
+            serde_json::to_value(&mapping).unwrap()
+        );
+
+        assert_eq!(
+            Some(&json!({ "type": "integer" })),
+            mapping.get_field("count")
+        );
+    }
+
+    #[test]
+    fn write_mapping_to_writer() {
+        let mapping = ResolvedType::index_mapping().resolve().unwrap();
+
+        let mut buf = Vec::new();
+        mapping.write_to(&mut buf).unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&mapping).unwrap(),
+            String::from_utf8(buf).unwrap()
+        );
+    }
+
+    #[test]
+    fn preview_mapping() {
+        let mapping = ResolvedType::index_mapping().resolve().unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&mapping).unwrap(),
+            mapping.preview().to_string()
+        );
+
+        let debug = format!("{:?}", mapping);
+        assert!(debug.starts_with("DocumentMapping"));
+    }
+
+    #[derive(Default)]
+    pub struct NormalizedKeywordMapping;
+    impl KeywordMapping for NormalizedKeywordMapping {
+        fn normalizer() -> Option<&'static str> {
+            Some("lowercase")
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct NormalizedType {
+        pub tag: Keyword<NormalizedKeywordMapping>,
+    }
+
+    #[test]
+    fn round_trip_keyword_mapping_with_normalizer() {
+        let ser = serde_json::to_string(&NormalizedType::index_mapping()).unwrap();
+
+        assert_eq!(
+            r#"{"properties":{"tag":{"type":"keyword","normalizer":"lowercase"}}}"#,
+            ser
+        );
+
+        let de: DocumentMapping = serde_json::from_str(&ser).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<Value>(&ser).unwrap(),
+            serde_json::to_value(&de).unwrap()
+        );
+        assert_eq!(
+            Some(&json!("lowercase")),
+            de.get_field("tag").and_then(|tag| tag.get("normalizer"))
+        );
+
+        let normalizers = de.normalizers();
+
+        assert_eq!(1, normalizers.len());
+        assert_eq!("lowercase", normalizers["tag"]);
+    }
+
+    #[test]
+    fn document_mapping_from_non_object_fails() {
+        assert_eq!(
+            MappingError::InvalidParameter {
+                name: "mapping",
+                reason: "a document mapping must be a json object".to_owned(),
+            },
+            DocumentMapping::from_value(json!([])).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn count_fields_with_objects_and_multi_fields() {
+        let mapping = DocumentMapping::from_value(json!({
+            "properties": {
+                "title": {
+                    "type": "text",
+                    "fields": {
+                        "keyword": { "type": "keyword" }
+                    }
+                },
+                "address": {
+                    "type": "object",
+                    "properties": {
+                        "city": { "type": "keyword" },
+                        "street": { "type": "keyword" }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(5, mapping.field_count());
+        assert!(mapping.validate_total_fields(None).is_ok());
+        assert!(mapping.validate_total_fields(Some(5)).is_ok());
+
+        assert_eq!(
+            MappingError::TooManyFields { count: 5, limit: 4 },
+            mapping.validate_total_fields(Some(4)).unwrap_err()
+        );
+    }
+
+    fn nested_mapping(depth: usize) -> DocumentMapping {
+        let mut field = json!({ "type": "keyword" });
+
+        for _ in 1..depth {
+            field = json!({
+                "type": "object",
+                "properties": {
+                    "inner": field
+                }
+            });
+        }
+
+        DocumentMapping::from_value(json!({
+            "properties": {
+                "inner": field
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn validate_depth_within_limit() {
+        let mapping = nested_mapping(20);
+
+        assert_eq!(20, mapping.max_depth().unwrap().1);
+        assert!(mapping.validate_depth(None).is_ok());
+    }
+
+    #[test]
+    fn max_depth_with_dotted_names() {
+        let mapping = DocumentMapping::from_value(json!({
+            "properties": {
+                "metrics": {
+                    "type": "object",
+                    "subobjects": false,
+                    "properties": {
+                        "a.b.c": {
+                            "type": "long"
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(Some(("metrics.a.b.c".to_owned(), 2)), mapping.max_depth());
+    }
+
+    #[test]
+    fn validate_depth_over_limit() {
+        let mapping = nested_mapping(21);
+
+        let field = vec!["inner"; 21].join(".");
+
+        assert_eq!(
+            MappingError::TooDeep {
+                field,
+                depth: 21,
+                limit: 20,
+            },
+            mapping.validate_depth(None).unwrap_err()
+        );
+
+        assert!(mapping.validate_depth(Some(21)).is_ok());
+    }
+
+    #[derive(Default)]
+    pub struct NoDocValuesMapping;
+    impl KeywordMapping for NoDocValuesMapping {
+        fn doc_values() -> Option<bool> {
+            Some(false)
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct SortedType {
+        pub title: Keyword<NoDocValuesMapping>,
+        pub published: Keyword<DefaultKeywordMapping>,
+    }
+
+    #[test]
+    fn validate_doc_values_for_sort_fields() {
+        let mapping = SortedType::index_mapping().resolve().unwrap();
+
+        assert!(mapping.validate_doc_values(&["published"]).is_ok());
+        assert!(mapping.validate_doc_values(&["missing"]).is_ok());
+
+        assert_eq!(
+            MappingError::DocValuesDisabled {
+                field: "title".to_owned()
+            },
+            mapping
+                .validate_doc_values(vec!["published", "title"])
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn merge_mappings() {
+        let base = DocumentMapping::from_value(json!({
+            "dynamic": "strict",
+            "properties": {
+                "title": { "type": "keyword" },
+                "address": {
+                    "properties": {
+                        "city": { "type": "keyword" }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let feature = DocumentMapping::from_value(json!({
+            "properties": {
+                "title": { "type": "keyword" },
+                "views": { "type": "long" },
+                "address": {
+                    "properties": {
+                        "street": { "type": "text" }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let merged = base.merge(feature).unwrap();
+
+        let expected = json!({
+            "dynamic": "strict",
+            "properties": {
+                "title": { "type": "keyword" },
+                "views": { "type": "long" },
+                "address": {
+                    "properties": {
+                        "city": { "type": "keyword" },
+                        "street": { "type": "text" }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, merged.into_value());
+    }
+
+    #[test]
+    fn merge_mappings_with_meta_and_dynamic_templates() {
+        let base = DocumentMapping::from_value(json!({
+            "_meta": { "version": 1 },
+            "dynamic_templates": [
+                { "strings": { "match_mapping_type": "string", "mapping": { "type": "keyword" } } }
+            ],
+            "properties": {
+                "title": { "type": "keyword" }
+            }
+        }))
+        .unwrap();
+
+        let feature = DocumentMapping::from_value(json!({
+            "_meta": { "version": 1, "owner": "search" },
+            "dynamic_templates": [
+                { "strings": { "match_mapping_type": "string", "mapping": { "type": "keyword" } } },
+                { "longs": { "match_mapping_type": "long", "mapping": { "type": "integer" } } }
+            ]
+        }))
+        .unwrap();
+
+        let merged = base.clone().merge(feature).unwrap();
+
+        let expected = json!({
+            "_meta": { "version": 1, "owner": "search" },
+            "dynamic_templates": [
+                { "strings": { "match_mapping_type": "string", "mapping": { "type": "keyword" } } },
+                { "longs": { "match_mapping_type": "long", "mapping": { "type": "integer" } } }
+            ],
+            "properties": {
+                "title": { "type": "keyword" }
+            }
+        });
+
+        assert_eq!(expected, merged.into_value());
+
+        let conflicting_meta = DocumentMapping::from_value(json!({
+            "_meta": { "version": 2 }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            MappingError::ConflictingFieldMapping {
+                field: "_meta.version".to_owned(),
+            },
+            base.clone().merge(conflicting_meta).unwrap_err()
+        );
+
+        let conflicting_template = DocumentMapping::from_value(json!({
+            "dynamic_templates": [
+                { "strings": { "match_mapping_type": "string", "mapping": { "type": "text" } } }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            MappingError::ConflictingFieldMapping {
+                field: "dynamic_templates.strings".to_owned(),
+            },
+            base.merge(conflicting_template).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn merge_mappings_with_conflicting_fields_fails() {
+        let base = DocumentMapping::from_value(json!({
+            "properties": {
+                "address": {
+                    "properties": {
+                        "city": { "type": "keyword" }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let conflicting_type = DocumentMapping::from_value(json!({
+            "properties": {
+                "address": {
+                    "properties": {
+                        "city": { "type": "text" }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            MappingError::ConflictingFieldType {
+                field: "address.city".to_owned(),
+                existing: "keyword".to_owned(),
+                requested: "text".to_owned(),
+            },
+            base.clone().merge(conflicting_type).unwrap_err()
+        );
+
+        let conflicting_params = DocumentMapping::from_value(json!({
+            "properties": {
+                "address": {
+                    "properties": {
+                        "city": { "type": "keyword", "ignore_above": 64 }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            MappingError::ConflictingFieldMapping {
+                field: "address.city".to_owned(),
+            },
+            base.merge(conflicting_params).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn copy_sources_to_full_text() {
+        let mapping = DocumentMapping::from_value(json!({
+            "properties": {
+                "title": { "type": "text" },
+                "summary": { "type": "text", "copy_to": "summaries" },
+                "summaries": { "type": "text" },
+                "author": {
+                    "properties": {
+                        "name": { "type": "keyword" }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let mapping = mapping
+            .copy_to(vec!["title", "summary", "author.name"], "full_text")
+            .unwrap();
+
+        let expected = json!({
+            "properties": {
+                "title": { "type": "text", "copy_to": ["full_text"] },
+                "summary": { "type": "text", "copy_to": ["summaries", "full_text"] },
+                "summaries": { "type": "text" },
+                "author": {
+                    "properties": {
+                        "name": { "type": "keyword", "copy_to": ["full_text"] }
+                    }
+                },
+                "full_text": { "type": "text" }
+            }
+        });
+
+        assert_eq!(expected, mapping.clone().into_value());
+
+        // Copying to the same target again doesn't duplicate it
+        assert_eq!(
+            mapping.clone(),
+            mapping.copy_to(&["title"], "full_text").unwrap()
+        );
+    }
+
+    #[test]
+    fn copy_sources_to_invalid_target_fails() {
+        let mapping = DocumentMapping::from_value(json!({
+            "properties": {
+                "title": { "type": "text" },
+                "tag": { "type": "keyword" }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            MappingError::UnknownField {
+                field: "missing".to_owned(),
+            },
+            mapping
+                .clone()
+                .copy_to(&["title", "missing"], "full_text")
+                .unwrap_err()
+        );
+
+        assert_eq!(
+            MappingError::ConflictingFieldType {
+                field: "tag".to_owned(),
+                existing: "keyword".to_owned(),
+                requested: "text".to_owned(),
+            },
+            mapping.copy_to(&["title"], "tag").unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,238 @@
+/*!
+Mapping for Elasticsearch runtime fields.
+
+Runtime fields are computed by a script when a document is queried, rather than indexed.
+They're declared in the `runtime` section of a document mapping, separately from its `properties`.
+
+# Examples
+
+Map a runtime `keyword` field on a document:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use std::collections::BTreeMap;
+# use elastic::types::prelude::*;
+#[derive(Default)]
+struct MyTypeMapping;
+impl ObjectMapping for MyTypeMapping {
+    type Properties = MyType;
+
+    fn runtime() -> Option<BTreeMap<&'static str, RuntimeFieldMapping>> {
+        let mut runtime = BTreeMap::new();
+
+        runtime.insert(
+            "day_of_week",
+            RuntimeFieldMapping {
+                runtime_type: RuntimeType::Keyword,
+                script: Some(Script::inline(
+                    "emit(doc['timestamp'].value.dayOfWeekEnum.toString())",
+                )),
+            },
+        );
+
+        Some(runtime)
+    }
+}
+
+#[derive(Serialize, ElasticType)]
+#[elastic(mapping = "MyTypeMapping")]
+struct MyType {
+    pub timestamp: Date<DefaultDateMapping>,
+}
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/runtime.html)
+*/
+
+use crate::types::script::Script;
+use serde::{
+    ser::SerializeStruct,
+    Serialize,
+    Serializer,
+};
+use std::fmt::{
+    Display,
+    Formatter,
+    Result as FmtResult,
+};
+
+/** A runtime field in the `runtime` section of a document mapping. */
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeFieldMapping {
+    /** The type of value the runtime field emits. */
+    pub runtime_type: RuntimeType,
+    /**
+    The script that computes the value of the field.
+    If there's no script, the value is read from a field in the `_source` with the same name.
+    */
+    pub script: Option<Script>,
+}
+
+impl Serialize for RuntimeFieldMapping {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("mapping", 2)?;
+
+        state.serialize_field("type", &self.runtime_type)?;
+        ser_field!(state, "script", &self.script);
+
+        state.end()
+    }
+}
+
+/** The type of value emitted by a runtime field. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuntimeType {
+    /** Emits `boolean` values. */
+    Boolean,
+    /** Emits `date` values. */
+    Date,
+    /** Emits `double` values. */
+    Double,
+    /** Emits `geo_point` values. */
+    GeoPoint,
+    /** Emits `ip` values. */
+    Ip,
+    /** Emits `keyword` values. */
+    Keyword,
+    /** Emits `long` values. */
+    Long,
+}
+
+impl RuntimeType {
+    /** Get the value of this type as it's sent to Elasticsearch. */
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            RuntimeType::Boolean => "boolean",
+            RuntimeType::Date => "date",
+            RuntimeType::Double => "double",
+            RuntimeType::GeoPoint => "geo_point",
+            RuntimeType::Ip => "ip",
+            RuntimeType::Keyword => "keyword",
+            RuntimeType::Long => "long",
+        }
+    }
+}
+
+impl Serialize for RuntimeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Display for RuntimeType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+impl_str_enum_deserialize!(RuntimeType {
+    Boolean,
+    Date,
+    Double,
+    GeoPoint,
+    Ip,
+    Keyword,
+    Long
+});
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::types::prelude::*;
+
+    #[derive(Default)]
+    pub struct RuntimeDocMapping;
+    impl ObjectMapping for RuntimeDocMapping {
+        type Properties = RuntimeDoc;
+
+        fn runtime() -> Option<BTreeMap<&'static str, RuntimeFieldMapping>> {
+            let mut runtime = BTreeMap::new();
+
+            runtime.insert(
+                "day_of_week",
+                RuntimeFieldMapping {
+                    runtime_type: RuntimeType::Keyword,
+                    script: Some(Script::inline(
+                        "emit(doc['timestamp'].value.dayOfWeekEnum.toString())",
+                    )),
+                },
+            );
+
+            Some(runtime)
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", mapping = "RuntimeDocMapping")]
+    pub struct RuntimeDoc {
+        pub timestamp: Date<DefaultDateMapping>,
+    }
+
+    #[test]
+    fn serialise_runtime_field() {
+        let mapping = RuntimeFieldMapping {
+            runtime_type: RuntimeType::Long,
+            script: Some(
+                Script::inline("emit(doc['count'].value * params.factor)")
+                    .lang("painless")
+                    .param("factor", 2),
+            ),
+        };
+
+        let ser = serde_json::to_value(&mapping).unwrap();
+
+        let expected = json!({
+            "type": "long",
+            "script": {
+                "source": "emit(doc['count'].value * params.factor)",
+                "lang": "painless",
+                "params": {
+                    "factor": 2
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_document_with_runtime_keyword_field() {
+        let ser = serde_json::to_value(&RuntimeDoc::index_mapping()).unwrap();
+
+        let expected = json!({
+            "runtime": {
+                "day_of_week": {
+                    "type": "keyword",
+                    "script": {
+                        "source": "emit(doc['timestamp'].value.dayOfWeekEnum.toString())"
+                    }
+                }
+            },
+            "properties": {
+                "timestamp": {
+                    "type": "date",
+                    "format": "basic_date_time"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+
+        // Inner objects don't get a `runtime` section
+        let ser = serde_json::to_value(&RuntimeDoc::field_mapping()).unwrap();
+
+        assert!(ser.get("runtime").is_none());
+    }
+}
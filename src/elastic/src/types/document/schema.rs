@@ -0,0 +1,385 @@
+/*!
+Translation of resolved document mappings into JSON Schema.
+
+The translation is best-effort.
+Elasticsearch doesn't distinguish between a single value and an array of values,
+so each field accepts either, and fields can be missing, so the schema only describes the shape of each field's values.
+*/
+
+use serde_json::{
+    Map,
+    Value,
+};
+
+const JSON_SCHEMA_DRAFT: &str = "http://json-schema.org/draft-07/schema#";
+
+/** Get the JSON Schema for the root of a document mapping. */
+pub(crate) fn document_schema(mapping: &Map<String, Value>) -> Value {
+    let mut schema = object_schema(mapping);
+
+    // Fields marked with `#[elastic(required)]` are recorded in the `_meta`
+    if let Some(required) = mapping.get("_meta").and_then(|meta| meta.get("required")) {
+        schema.insert("required".to_owned(), required.clone());
+    }
+
+    schema.insert(
+        "$schema".to_owned(),
+        Value::String(JSON_SCHEMA_DRAFT.to_owned()),
+    );
+
+    Value::Object(schema)
+}
+
+fn object_schema(mapping: &Map<String, Value>) -> Map<String, Value> {
+    let properties = mapping
+        .get("properties")
+        .and_then(Value::as_object)
+        .map(|properties| {
+            properties
+                .iter()
+                .map(|(name, field)| (name.clone(), field_schema(field)))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut schema = typed_schema("object");
+
+    schema.insert("properties".to_owned(), Value::Object(properties));
+
+    schema
+}
+
+fn field_schema(field: &Value) -> Value {
+    let field = match field.as_object() {
+        Some(field) => field,
+        None => return Value::Object(Map::new()),
+    };
+
+    // Objects can be mapped without an explicit type
+    let data_type = field
+        .get("type")
+        .and_then(Value::as_str)
+        .unwrap_or("object");
+
+    let schema = match data_type {
+        "object" | "nested" => object_schema(field),
+        "date" | "date_nanos" => date_schema(data_type, field),
+        // A dense vector is already an array, so it can't be an array of values
+        "dense_vector" => {
+            let mut schema = typed_schema("array");
+
+            schema.insert("items".to_owned(), Value::Object(typed_schema("number")));
+
+            return Value::Object(schema);
+        }
+        data_type => match value_type(data_type) {
+            Some(ty) => typed_schema(ty),
+            None => return Value::Object(Map::new()),
+        },
+    };
+
+    single_or_array(schema)
+}
+
+// Any field can be indexed with a single value or an array of values
+fn single_or_array(schema: Map<String, Value>) -> Value {
+    let mut array = typed_schema("array");
+    array.insert("items".to_owned(), Value::Object(schema.clone()));
+
+    let mut any_of = Map::new();
+    any_of.insert(
+        "anyOf".to_owned(),
+        Value::Array(vec![Value::Object(schema), Value::Object(array)]),
+    );
+
+    Value::Object(any_of)
+}
+
+// The schema for a date depends on its formats, which are separated by `||`
+fn date_schema(data_type: &str, field: &Map<String, Value>) -> Map<String, Value> {
+    let default_format = match data_type {
+        "date_nanos" => "strict_date_optional_time_nanos||epoch_millis",
+        _ => "strict_date_optional_time||epoch_millis",
+    };
+
+    let format = field
+        .get("format")
+        .and_then(Value::as_str)
+        .unwrap_or(default_format);
+
+    let mut schemas: Vec<Map<String, Value>> = Vec::new();
+    for schema in format
+        .split("||")
+        .map(|format| date_format_schema(format.trim()))
+    {
+        if !schemas.contains(&schema) {
+            schemas.push(schema);
+        }
+    }
+
+    if schemas.len() == 1 {
+        schemas.remove(0)
+    } else {
+        let mut any_of = Map::new();
+        any_of.insert(
+            "anyOf".to_owned(),
+            Value::Array(schemas.into_iter().map(Value::Object).collect()),
+        );
+
+        any_of
+    }
+}
+
+// Only ISO 8601 formats are valid JSON Schema `date-time`s
+fn date_format_schema(format: &str) -> Map<String, Value> {
+    let string_format = match format {
+        // Epoch dates are indexed as numbers, but they're serialised as strings by `Date`
+        "epoch_millis" | "epoch_second" => {
+            let mut schema = Map::new();
+
+            schema.insert(
+                "type".to_owned(),
+                Value::Array(vec![
+                    Value::String("integer".to_owned()),
+                    Value::String("string".to_owned()),
+                ]),
+            );
+            schema.insert("pattern".to_owned(), Value::String("^-?[0-9]+$".to_owned()));
+
+            return schema;
+        }
+        "strict_date_optional_time"
+        | "date_optional_time"
+        | "strict_date_optional_time_nanos"
+        | "strict_date_time"
+        | "date_time"
+        | "strict_date_time_no_millis"
+        | "date_time_no_millis" => Some("date-time"),
+        "strict_date" | "date" => Some("date"),
+        _ => None,
+    };
+
+    let mut schema = typed_schema("string");
+
+    if let Some(string_format) = string_format {
+        schema.insert("format".to_owned(), Value::String(string_format.to_owned()));
+    }
+
+    schema
+}
+
+fn typed_schema(ty: &str) -> Map<String, Value> {
+    let mut schema = Map::new();
+
+    schema.insert("type".to_owned(), Value::String(ty.to_owned()));
+
+    schema
+}
+
+// The JSON Schema type for a datatype, or `None` if its values can have any shape
+fn value_type(data_type: &str) -> Option<&'static str> {
+    match data_type {
+        "text" | "keyword" | "constant_keyword" | "wildcard" | "match_only_text"
+        | "search_as_you_type" | "completion" | "ip" | "binary" => Some("string"),
+        "long" | "integer" | "short" | "byte" | "unsigned_long" | "token_count" => Some("integer"),
+        "double" | "float" | "half_float" | "scaled_float" => Some("number"),
+        "boolean" => Some("boolean"),
+        "flattened" => Some("object"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::prelude::*;
+
+    #[test]
+    fn document_mapping_to_json_schema() {
+        let mapping = DocumentMapping::from_value(json!({
+            "_meta": {
+                "required": ["id"]
+            },
+            "properties": {
+                "id": {
+                    "type": "keyword"
+                },
+                "count": {
+                    "type": "long"
+                },
+                "created": {
+                    "type": "date",
+                    "format": "strict_date_optional_time"
+                },
+                "location": {
+                    "type": "geo_point"
+                },
+                "comments": {
+                    "type": "nested",
+                    "properties": {
+                        "score": {
+                            "type": "float"
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let expected = json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "required": ["id"],
+            "properties": {
+                "id": {
+                    "anyOf": [
+                        { "type": "string" },
+                        { "type": "array", "items": { "type": "string" } }
+                    ]
+                },
+                "count": {
+                    "anyOf": [
+                        { "type": "integer" },
+                        { "type": "array", "items": { "type": "integer" } }
+                    ]
+                },
+                "created": {
+                    "anyOf": [
+                        { "type": "string", "format": "date-time" },
+                        { "type": "array", "items": { "type": "string", "format": "date-time" } }
+                    ]
+                },
+                "location": {},
+                "comments": {
+                    "anyOf": [
+                        {
+                            "type": "object",
+                            "properties": {
+                                "score": {
+                                    "anyOf": [
+                                        { "type": "number" },
+                                        { "type": "array", "items": { "type": "number" } }
+                                    ]
+                                }
+                            }
+                        },
+                        {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "properties": {
+                                    "score": {
+                                        "anyOf": [
+                                            { "type": "number" },
+                                            { "type": "array", "items": { "type": "number" } }
+                                        ]
+                                    }
+                                }
+                            }
+                        }
+                    ]
+                }
+            }
+        });
+
+        assert_eq!(expected, mapping.to_json_schema());
+    }
+
+    fn date_field_schema(field: serde_json::Value) -> serde_json::Value {
+        let mapping = DocumentMapping::from_value(json!({
+            "properties": {
+                "date": field
+            }
+        }))
+        .unwrap();
+
+        mapping.to_json_schema()["properties"]["date"]["anyOf"][0].clone()
+    }
+
+    #[test]
+    fn date_schema_depends_on_format() {
+        assert_eq!(
+            json!({ "type": "string", "format": "date-time" }),
+            date_field_schema(json!({ "type": "date", "format": "strict_date_optional_time" }))
+        );
+        assert_eq!(
+            json!({ "type": "string", "format": "date" }),
+            date_field_schema(json!({ "type": "date", "format": "strict_date" }))
+        );
+        assert_eq!(
+            json!({ "type": ["integer", "string"], "pattern": "^-?[0-9]+$" }),
+            date_field_schema(json!({ "type": "date", "format": "epoch_millis" }))
+        );
+
+        // The default format for derived dates isn't RFC 3339
+        assert_eq!(
+            json!({ "type": "string" }),
+            date_field_schema(json!({ "type": "date", "format": "basic_date_time" }))
+        );
+        assert_eq!(
+            json!({ "type": "string" }),
+            date_field_schema(json!({ "type": "date", "format": "yyyy/MM/dd" }))
+        );
+    }
+
+    #[test]
+    fn date_schema_with_many_formats() {
+        let expected = json!({
+            "anyOf": [
+                { "type": "string", "format": "date-time" },
+                { "type": ["integer", "string"], "pattern": "^-?[0-9]+$" }
+            ]
+        });
+
+        assert_eq!(
+            expected,
+            date_field_schema(json!({ "type": "date", "format": "date_time||epoch_millis" }))
+        );
+
+        // Dates without a format use the Elasticsearch default
+        assert_eq!(expected, date_field_schema(json!({ "type": "date" })));
+        assert_eq!(expected, date_field_schema(json!({ "type": "date_nanos" })));
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct DatedType {
+        pub created: Date<DefaultDateMapping>,
+        pub updated: Date<DefaultDateMapping<EpochMillis>>,
+        pub tags: Vec<Keyword<DefaultKeywordMapping>>,
+    }
+
+    #[test]
+    fn schema_accepts_serialised_documents() {
+        let schema = DatedType::index_mapping()
+            .resolve()
+            .unwrap()
+            .to_json_schema();
+
+        let doc = serde_json::to_value(&DatedType {
+            created: Date::build(2015, 7, 3, 14, 55, 2, 478),
+            updated: Date::build(2015, 7, 3, 14, 55, 2, 478),
+            tags: vec![Keyword::new("a"), Keyword::new("b")],
+        })
+        .unwrap();
+
+        // Basic dates are strings without a `date-time` format, epoch dates are strings of digits
+        assert_eq!(json!("20150703T145502.478Z"), doc["created"]);
+        assert_eq!(
+            json!({ "type": "string" }),
+            schema["properties"]["created"]["anyOf"][0]
+        );
+
+        assert_eq!(json!("1435935302478"), doc["updated"]);
+        assert_eq!(
+            json!({ "type": ["integer", "string"], "pattern": "^-?[0-9]+$" }),
+            schema["properties"]["updated"]["anyOf"][0]
+        );
+
+        // Arrays of values are accepted
+        assert!(doc["tags"].is_array());
+        assert_eq!(
+            json!({ "type": "array", "items": { "type": "string" } }),
+            schema["properties"]["tags"]["anyOf"][1]
+        );
+    }
+}
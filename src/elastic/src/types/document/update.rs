@@ -0,0 +1,352 @@
+/*!
+Partial mappings for adding fields to an existing index.
+
+Elasticsearch lets new fields be added to the mapping of an existing index,
+but it won't change the type of a field that's already mapped.
+An `UpdateMappingBody` serialises just the `properties` of a document mapping,
+along with any `_meta` and `dynamic` changes, as the body for the
+[Put Mapping API](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-put-mapping.html).
+
+# Examples
+
+Add new fields to an index, checking them against the mapping that's already on the index:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+struct MyType {
+    pub title: String,
+    pub views: i64,
+}
+
+let existing = json!({
+    "properties": {
+        "title": { "type": "text" }
+    }
+});
+
+let body = MyType::update_mapping().against(existing);
+
+# #[cfg(not(feature = "string-as-keyword"))]
+assert!(body.validate().is_ok());
+```
+*/
+
+use super::{
+    mapping::{
+        self,
+        ObjectMapping,
+    },
+    validation,
+};
+use crate::types::error::MappingError;
+use serde::{
+    ser::{
+        Error as SerError,
+        SerializeStruct,
+    },
+    Serialize,
+    Serializer,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+use std::marker::PhantomData;
+
+/**
+A partial mapping that adds new fields to an existing index.
+
+Only the `properties` of the mapping are serialised, along with its `_meta` and `dynamic` parameters.
+Other root parameters, like `date_detection` or `subobjects`, can't be changed on an existing index.
+
+The new properties are validated the same way as an index mapping.
+If the mapping that's already on the index is given with `against`,
+then the new properties are checked for fields that would change the type of an existing field.
+*/
+pub struct UpdateMappingBody<TMapping>
+where
+    TMapping: ObjectMapping,
+{
+    existing: Option<Value>,
+    _m: PhantomData<TMapping>,
+}
+
+impl<TMapping> Default for UpdateMappingBody<TMapping>
+where
+    TMapping: ObjectMapping,
+{
+    fn default() -> Self {
+        UpdateMappingBody {
+            existing: None,
+            _m: PhantomData,
+        }
+    }
+}
+
+impl<TMapping> UpdateMappingBody<TMapping>
+where
+    TMapping: ObjectMapping,
+{
+    /**
+    Check the new properties against the mapping that's already on the index.
+
+    The existing mapping is the json object with the `properties` of the index,
+    like the one returned by the Get Mapping API.
+    */
+    pub fn against(mut self, existing: Value) -> Self {
+        self.existing = Some(existing);
+        self
+    }
+
+    /**
+    Validate the new properties, and check them against the existing mapping.
+
+    The properties are checked the same way as an index mapping, like for field names and `copy_to` targets.
+    A `copy_to` target can be a field that's already mapped on the index,
+    so targets are only checked when the existing mapping is given with `against`.
+    This is also checked when the body is serialised.
+    */
+    pub fn validate(&self) -> Result<(), MappingError> {
+        let properties = mapping::resolve_properties::<TMapping>().map_err(|e| {
+            MappingError::InvalidParameter {
+                name: "properties",
+                reason: e.to_string(),
+            }
+        })?;
+
+        let existing = match self.existing.as_ref().and_then(|e| e.get("properties")) {
+            Some(Value::Object(existing)) => Some(existing),
+            _ => None,
+        };
+
+        validation::validate_update_properties(
+            &properties,
+            existing,
+            TMapping::subobjects().unwrap_or(true),
+        )?;
+
+        match existing {
+            Some(existing) => check_types(&properties, existing, ""),
+            None => Ok(()),
+        }
+    }
+}
+
+fn data_type(field: &Value) -> Option<&str> {
+    match field.get("type") {
+        Some(Value::String(ty)) => Some(ty),
+        // Objects can be mapped without an explicit type
+        None if field.get("properties").is_some() => Some("object"),
+        _ => None,
+    }
+}
+
+fn check_types(
+    properties: &Map<String, Value>,
+    existing: &Map<String, Value>,
+    parent: &str,
+) -> Result<(), MappingError> {
+    for (name, field) in properties {
+        let existing_field = match existing.get(name) {
+            Some(existing_field) => existing_field,
+            None => continue,
+        };
+
+        let path = if parent.is_empty() {
+            name.to_owned()
+        } else {
+            format!("{}.{}", parent, name)
+        };
+
+        match (data_type(existing_field), data_type(field)) {
+            (Some(existing_ty), Some(ty)) if existing_ty != ty => {
+                return Err(MappingError::ConflictingFieldType {
+                    field: path,
+                    existing: existing_ty.to_owned(),
+                    requested: ty.to_owned(),
+                });
+            }
+            _ => (),
+        }
+
+        for inner in &["properties", "fields"] {
+            if let (Some(Value::Object(inner)), Some(Value::Object(existing_inner))) =
+                (field.get(inner), existing_field.get(inner))
+            {
+                check_types(inner, existing_inner, &path)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl<TMapping> Serialize for UpdateMappingBody<TMapping>
+where
+    TMapping: ObjectMapping,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.validate().map_err(S::Error::custom)?;
+
+        let properties = mapping::resolve_properties::<TMapping>().map_err(S::Error::custom)?;
+
+        let mut state = serializer.serialize_struct("mapping", 3)?;
+
+        ser_field!(state, "_meta", TMapping::meta());
+        ser_field!(state, "dynamic", TMapping::dynamic());
+        state.serialize_field("properties", &properties)?;
+
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::{
+        error::MappingError,
+        prelude::*,
+    };
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct NewFieldsType {
+        pub title: Keyword<DefaultKeywordMapping>,
+        pub views: i64,
+        pub published: Date<DefaultDateMapping<EpochMillis>>,
+    }
+
+    fn existing() -> serde_json::Value {
+        json!({
+            "properties": {
+                "title": {
+                    "type": "keyword"
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn serialise_update_adding_new_fields() {
+        let body = NewFieldsType::update_mapping().against(existing());
+
+        let ser = serde_json::to_value(&body).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "published": {
+                    "type": "date",
+                    "format": "epoch_millis"
+                },
+                "title": {
+                    "type": "keyword"
+                },
+                "views": {
+                    "type": "long"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_update_with_type_change_fails() {
+        let existing = json!({
+            "properties": {
+                "views": {
+                    "type": "integer"
+                }
+            }
+        });
+
+        let body = NewFieldsType::update_mapping().against(existing);
+
+        assert_eq!(
+            MappingError::ConflictingFieldType {
+                field: "views".to_owned(),
+                existing: "integer".to_owned(),
+                requested: "long".to_owned(),
+            },
+            body.validate().unwrap_err()
+        );
+
+        assert!(serde_json::to_value(&body).is_err());
+    }
+
+    #[derive(Default)]
+    pub struct MissingCopyToMapping;
+    impl KeywordMapping for MissingCopyToMapping {
+        fn copy_to() -> Option<&'static [&'static str]> {
+            Some(&["missing"])
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct MissingCopyToType {
+        pub title: Keyword<MissingCopyToMapping>,
+    }
+
+    #[test]
+    fn serialise_update_with_unknown_copy_to_target_fails() {
+        let body = MissingCopyToType::update_mapping().against(existing());
+
+        assert_eq!(
+            MappingError::UnknownCopyToTarget {
+                field: "title".to_owned(),
+                target: "missing".to_owned(),
+            },
+            body.validate().unwrap_err()
+        );
+
+        assert!(serde_json::to_value(&body).is_err());
+    }
+
+    #[test]
+    fn serialise_update_without_existing_skips_copy_to_targets() {
+        let body = MissingCopyToType::update_mapping();
+
+        assert!(body.validate().is_ok());
+    }
+
+    #[derive(Default)]
+    pub struct ExistingCopyToMapping;
+    impl KeywordMapping for ExistingCopyToMapping {
+        fn copy_to() -> Option<&'static [&'static str]> {
+            Some(&["title"])
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct ExistingCopyToType {
+        pub summary: Keyword<ExistingCopyToMapping>,
+    }
+
+    #[test]
+    fn serialise_update_with_copy_to_existing_field() {
+        let body = ExistingCopyToType::update_mapping().against(existing());
+
+        let ser = serde_json::to_value(&body).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "summary": {
+                    "type": "keyword",
+                    "copy_to": ["title"]
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+}
@@ -0,0 +1,390 @@
+/*!
+Validation for resolved document mappings.
+
+Some problems with a mapping can only be found once all of its properties are known,
+like a `copy_to` target that refers to a field that doesn't exist.
+These checks run against the serialised mapping for a document.
+*/
+
+use super::{
+    mapping::validate_field_name,
+    visitor::{
+        self,
+        MappingVisitor,
+    },
+};
+use crate::types::error::MappingError;
+use serde_json::{
+    Map,
+    Value,
+};
+use std::collections::BTreeSet;
+
+/**
+Validate the `properties` of a document mapping.
+
+The `subobjects` parameter is the value of `subobjects` for the root of the document.
+*/
+pub(crate) fn validate_properties(
+    properties: &Map<String, Value>,
+    subobjects: bool,
+) -> Result<(), MappingError> {
+    validate_names(properties, subobjects)?;
+    validate_copy_to(properties, None)?;
+    validate_null_values(properties)
+}
+
+/**
+Validate the `properties` of an update to an existing index mapping.
+
+The `copy_to` targets of the new properties can also be fields in the `existing` properties.
+If the existing properties aren't known then the targets aren't checked.
+*/
+pub(crate) fn validate_update_properties(
+    properties: &Map<String, Value>,
+    existing: Option<&Map<String, Value>>,
+    subobjects: bool,
+) -> Result<(), MappingError> {
+    validate_names(properties, subobjects)?;
+
+    if let Some(existing) = existing {
+        validate_copy_to(properties, Some(existing))?;
+    }
+
+    validate_null_values(properties)
+}
+
+fn validate_copy_to(
+    properties: &Map<String, Value>,
+    existing: Option<&Map<String, Value>>,
+) -> Result<(), MappingError> {
+    let mut copy_to = CopyToTargets::default();
+
+    visitor::walk_properties(properties, &mut copy_to);
+
+    if let Some(existing) = existing {
+        let mut existing_fields = CopyToTargets::default();
+        visitor::walk_properties(existing, &mut existing_fields);
+
+        copy_to.fields.extend(existing_fields.fields);
+    }
+
+    for (field, target) in copy_to.targets {
+        if !copy_to.fields.contains(&target) {
+            return Err(MappingError::UnknownCopyToTarget { field, target });
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_null_values(properties: &Map<String, Value>) -> Result<(), MappingError> {
+    let mut null_values = AnalyzedNullValues::default();
+
+    visitor::walk_properties(properties, &mut null_values);
+
+    if let Some(field) = null_values.fields.into_iter().next() {
+        return Err(MappingError::InvalidParameter {
+            name: "null_value",
+            reason: format!(
+                "the field `{}` is analysed, so it can't have a `null_value`",
+                field
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+// Analysed fields can't have a `null_value`
+fn is_analyzed(data_type: &str) -> bool {
+    match data_type {
+        "text" | "match_only_text" | "search_as_you_type" => true,
+        _ => false,
+    }
+}
+
+fn validate_names(properties: &Map<String, Value>, subobjects: bool) -> Result<(), MappingError> {
+    for (name, field) in properties {
+        validate_field_name(name)?;
+
+        if subobjects && name.contains('.') {
+            return Err(MappingError::InvalidFieldName {
+                name: name.clone(),
+                reason: "names with dots are only allowed in objects with `subobjects: false`"
+                    .to_owned(),
+            });
+        }
+
+        if let Some(Value::Object(fields)) = field.get("fields") {
+            for name in fields.keys() {
+                validate_field_name(name)?;
+            }
+        }
+
+        if let Some(Value::Object(inner)) = field.get("properties") {
+            let subobjects = field
+                .get("subobjects")
+                .and_then(Value::as_bool)
+                .unwrap_or(true);
+
+            validate_names(inner, subobjects)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Default)]
+struct CopyToTargets {
+    fields: BTreeSet<String>,
+    targets: Vec<(String, String)>,
+}
+
+impl MappingVisitor for CopyToTargets {
+    fn visit_field(&mut self, path: &str, mapping: &Value) {
+        match mapping.get("copy_to") {
+            Some(Value::String(target)) => self.targets.push((path.to_owned(), target.clone())),
+            Some(Value::Array(targets)) => {
+                for target in targets.iter().filter_map(Value::as_str) {
+                    self.targets.push((path.to_owned(), target.to_owned()));
+                }
+            }
+            _ => (),
+        }
+
+        self.fields.insert(path.to_owned());
+    }
+
+    // Multi-fields can't be the target of `copy_to`
+    fn visit_multi_field(&mut self, _: &str, _: &Value) {}
+}
+
+// Analysed fields don't support `null_value`, but Elasticsearch only rejects it when the mapping is created
+#[derive(Default)]
+struct AnalyzedNullValues {
+    fields: Vec<String>,
+}
+
+impl MappingVisitor for AnalyzedNullValues {
+    fn visit_field(&mut self, path: &str, mapping: &Value) {
+        let analyzed = mapping
+            .get("type")
+            .and_then(Value::as_str)
+            .map(is_analyzed)
+            .unwrap_or(false);
+
+        if analyzed && mapping.get("null_value").is_some() {
+            self.fields.push(path.to_owned());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::document::mapping::MAX_FIELD_NAME_LENGTH;
+
+    fn properties(value: Value) -> Map<String, Value> {
+        match value {
+            Value::Object(properties) => properties,
+            _ => panic!("expected an object"),
+        }
+    }
+
+    #[test]
+    fn valid_copy_to_target() {
+        let properties = properties(json!({
+            "first_name": {
+                "type": "text",
+                "copy_to": ["full_name"]
+            },
+            "full_name": {
+                "type": "text"
+            }
+        }));
+
+        assert!(validate_properties(&properties, true).is_ok());
+    }
+
+    #[test]
+    fn valid_nested_copy_to_target() {
+        let properties = properties(json!({
+            "name": {
+                "type": "text",
+                "copy_to": ["user.name"]
+            },
+            "user": {
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "text"
+                    }
+                }
+            }
+        }));
+
+        assert!(validate_properties(&properties, true).is_ok());
+    }
+
+    #[test]
+    fn dangling_copy_to_target() {
+        let properties = properties(json!({
+            "first_name": {
+                "type": "text",
+                "copy_to": ["full_name"]
+            }
+        }));
+
+        assert_eq!(
+            MappingError::UnknownCopyToTarget {
+                field: "first_name".to_owned(),
+                target: "full_name".to_owned(),
+            },
+            validate_properties(&properties, true).unwrap_err()
+        );
+    }
+
+    fn invalid_name(properties: Map<String, Value>) -> String {
+        match validate_properties(&properties, true) {
+            Err(MappingError::InvalidFieldName { name, .. }) => name,
+            result => panic!("expected an invalid field name, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn valid_field_name() {
+        assert!(validate_field_name("first_name").is_ok());
+        assert!(validate_field_name("metrics.cpu").is_ok());
+    }
+
+    #[test]
+    fn empty_field_name() {
+        let properties = properties(json!({
+            "": { "type": "text" }
+        }));
+
+        assert_eq!("", invalid_name(properties));
+    }
+
+    #[test]
+    fn field_name_with_leading_underscore() {
+        let properties = properties(json!({
+            "_id": { "type": "keyword" }
+        }));
+
+        assert_eq!("_id", invalid_name(properties));
+    }
+
+    #[test]
+    fn field_name_with_misplaced_dots() {
+        for name in &[".name", "name.", "first..name"] {
+            assert!(validate_field_name(name).is_err(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn field_name_over_length_cap() {
+        let name = "a".repeat(MAX_FIELD_NAME_LENGTH + 1);
+
+        let properties = properties(json!({
+            name.clone(): { "type": "text" }
+        }));
+
+        assert_eq!(name, invalid_name(properties));
+        assert!(validate_field_name(&"a".repeat(MAX_FIELD_NAME_LENGTH)).is_ok());
+
+        assert_eq!(
+            MappingError::InvalidFieldName {
+                name: name.clone(),
+                reason: "names can't be longer than 255 bytes".to_owned(),
+            },
+            validate_field_name(&name).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn dotted_field_name_with_subobjects() {
+        let properties = properties(json!({
+            "metrics.cpu": { "type": "float" }
+        }));
+
+        assert_eq!("metrics.cpu", invalid_name(properties.clone()));
+        assert!(validate_properties(&properties, false).is_ok());
+    }
+
+    #[test]
+    fn dotted_field_name_in_inner_object() {
+        let flat = properties(json!({
+            "metrics": {
+                "type": "object",
+                "subobjects": false,
+                "properties": {
+                    "cpu.total": { "type": "float" }
+                }
+            }
+        }));
+
+        assert!(validate_properties(&flat, true).is_ok());
+
+        let expanded = properties(json!({
+            "metrics": {
+                "type": "object",
+                "properties": {
+                    "cpu.total": { "type": "float" }
+                }
+            }
+        }));
+
+        assert_eq!("cpu.total", invalid_name(expanded));
+    }
+
+    #[test]
+    fn invalid_multi_field_name() {
+        let properties = properties(json!({
+            "title": {
+                "type": "text",
+                "fields": {
+                    "_raw": { "type": "keyword" }
+                }
+            }
+        }));
+
+        assert_eq!("_raw", invalid_name(properties));
+    }
+
+    #[test]
+    fn text_field_with_null_value() {
+        let properties = properties(json!({
+            "title": {
+                "type": "text",
+                "null_value": "n/a"
+            }
+        }));
+
+        assert_eq!(
+            MappingError::InvalidParameter {
+                name: "null_value",
+                reason: "the field `title` is analysed, so it can't have a `null_value`".to_owned(),
+            },
+            validate_properties(&properties, true).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn keyword_field_with_null_value() {
+        let properties = properties(json!({
+            "title": {
+                "type": "text",
+                "fields": {
+                    "raw": {
+                        "type": "keyword",
+                        "null_value": "n/a"
+                    }
+                }
+            }
+        }));
+
+        assert!(validate_properties(&properties, true).is_ok());
+    }
+}
@@ -0,0 +1,166 @@
+/*!
+Walk the fields of a resolved document mapping.
+
+Mappings are static types, so tools that need to inspect the fields of a document
+work with its resolved json representation instead.
+A `MappingVisitor` is called for each field in the mapping with the full dotted path to the field,
+including fields on inner objects and multi-fields.
+
+# Examples
+
+Collect the paths of all fields in a document mapping:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+# use elastic::types::document::visitor::MappingVisitor;
+# use serde_json::Value;
+#[derive(Serialize, ElasticType)]
+struct MyType {
+    pub title: String,
+}
+
+struct Paths(Vec<String>);
+
+impl MappingVisitor for Paths {
+    fn visit_field(&mut self, path: &str, _: &Value) {
+        self.0.push(path.to_owned());
+    }
+}
+
+let mut paths = Paths(Vec::new());
+MyType::index_mapping().visit(&mut paths).unwrap();
+
+# #[cfg(not(feature = "string-as-keyword"))]
+assert_eq!(vec!["title", "title.keyword"], paths.0);
+```
+*/
+
+use serde_json::{
+    Map,
+    Value,
+};
+
+/** A visitor for the fields of a resolved document mapping. */
+pub trait MappingVisitor {
+    /** Visit a field with the given dotted path, like `address.city`. */
+    fn visit_field(&mut self, path: &str, mapping: &Value);
+
+    /**
+    Visit a multi-field with the given dotted path, like `address.city.keyword`.
+
+    Multi-fields are visited as regular fields unless this method is overridden.
+    */
+    fn visit_multi_field(&mut self, path: &str, mapping: &Value) {
+        self.visit_field(path, mapping)
+    }
+}
+
+/**
+Walk the `properties` of a resolved mapping.
+
+Fields are visited before their inner `properties` and `fields`.
+*/
+pub fn walk_properties<V>(properties: &Map<String, Value>, visitor: &mut V)
+where
+    V: MappingVisitor + ?Sized,
+{
+    walk(properties, "", visitor)
+}
+
+/**
+Find the field at a dotted path in the `properties` of a resolved mapping.
+
+Each segment of the path is looked up in the inner `properties` of the previous field,
+then in its multi-`fields`, so both `address.city` and `address.city.keyword` can be found.
+Fields with dots in their names, like those in an object with `subobjects: false`, are also found.
+*/
+pub fn get_field<'a>(properties: &'a Map<String, Value>, path: &str) -> Option<&'a Value> {
+    if let Some(field) = properties.get(path) {
+        return Some(field);
+    }
+
+    path.match_indices('.').find_map(|(i, _)| {
+        let (name, rest) = (&path[..i], &path[i + 1..]);
+        let field = properties.get(name)?;
+
+        ["properties", "fields"]
+            .iter()
+            .find_map(|inner| match field.get(inner) {
+                Some(Value::Object(inner)) => get_field(inner, rest),
+                _ => None,
+            })
+    })
+}
+
+fn walk<V>(properties: &Map<String, Value>, prefix: &str, visitor: &mut V)
+where
+    V: MappingVisitor + ?Sized,
+{
+    for (name, mapping) in properties {
+        let path = format!("{}{}", prefix, name);
+
+        visitor.visit_field(&path, mapping);
+
+        if let Some(Value::Object(fields)) = mapping.get("fields") {
+            for (name, mapping) in fields {
+                visitor.visit_multi_field(&format!("{}.{}", path, name), mapping);
+            }
+        }
+
+        if let Some(Value::Object(properties)) = mapping.get("properties") {
+            walk(properties, &format!("{}.", path), visitor);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    struct Paths(BTreeSet<String>);
+
+    impl MappingVisitor for Paths {
+        fn visit_field(&mut self, path: &str, _: &Value) {
+            self.0.insert(path.to_owned());
+        }
+    }
+
+    #[test]
+    fn collect_nested_field_paths() {
+        let properties = match json!({
+            "name": {
+                "type": "text"
+            },
+            "address": {
+                "type": "object",
+                "properties": {
+                    "city": {
+                        "type": "text",
+                        "fields": {
+                            "keyword": {
+                                "type": "keyword"
+                            }
+                        }
+                    }
+                }
+            }
+        }) {
+            Value::Object(properties) => properties,
+            _ => panic!("expected an object"),
+        };
+
+        let mut paths = Paths(BTreeSet::new());
+        walk_properties(&properties, &mut paths);
+
+        let expected: BTreeSet<String> =
+            vec!["name", "address", "address.city", "address.city.keyword"]
+                .into_iter()
+                .map(String::from)
+                .collect();
+
+        assert_eq!(expected, paths.0);
+    }
+}
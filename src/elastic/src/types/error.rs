@@ -0,0 +1,183 @@
+/*!
+Errors produced while building or validating mappings.
+*/
+
+use std::{
+    error::Error,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult,
+    },
+};
+
+/**
+An error encountered while validating a mapping.
+
+Elasticsearch will silently accept some invalid mappings,
+so these errors are raised before a mapping is sent.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub enum MappingError {
+    /** A field copies its value to a target that isn't a declared field. */
+    UnknownCopyToTarget {
+        /** The path of the field with the `copy_to` parameter. */
+        field: String,
+        /** The path of the target that couldn't be found. */
+        target: String,
+    },
+    /** A field that requires an `analyzer` doesn't have one. */
+    MissingAnalyzer {
+        /** The datatype of the field, like `token_count`. */
+        data_type: &'static str,
+    },
+    /** A parameter has a value that Elasticsearch won't accept. */
+    InvalidParameter {
+        /** The name of the parameter, like `max_input_length`. */
+        name: &'static str,
+        /** Why the value isn't valid. */
+        reason: String,
+    },
+    /** A field has a name that Elasticsearch won't accept. */
+    InvalidFieldName {
+        /** The name of the field. */
+        name: String,
+        /** Why the name isn't valid. */
+        reason: String,
+    },
+    /** A field in a mapping update would change the type of a field that's already mapped. */
+    ConflictingFieldType {
+        /** The path of the field. */
+        field: String,
+        /** The type the field is already mapped as. */
+        existing: String,
+        /** The type the update tried to map the field as. */
+        requested: String,
+    },
+    /** Two mappings being merged have different definitions for the same field. */
+    ConflictingFieldMapping {
+        /** The path of the field, or a root parameter like `dynamic` or `_meta.version`. */
+        field: String,
+    },
+    /** A mapping has more fields than the index allows. */
+    TooManyFields {
+        /** The number of fields in the mapping, including objects and multi-fields. */
+        count: usize,
+        /** The maximum number of fields allowed by `index.mapping.total_fields.limit`. */
+        limit: u32,
+    },
+    /** A field is nested in more objects than the index allows. */
+    TooDeep {
+        /** The path of the deepest field. */
+        field: String,
+        /** The depth of the field, where a field in the root of the mapping has a depth of `1`. */
+        depth: usize,
+        /** The maximum depth allowed by `index.mapping.depth.limit`. */
+        limit: u32,
+    },
+    /** Two properties of an object have the same name, like properties from two flattened fields. */
+    DuplicateProperty {
+        /** The name of the property. */
+        name: &'static str,
+    },
+    /** A field that's used for sorting or aggregations has `doc_values` disabled. */
+    DocValuesDisabled {
+        /** The path of the field. */
+        field: String,
+    },
+    /** A field that's referred to by path isn't in the mapping. */
+    UnknownField {
+        /** The path of the field. */
+        field: String,
+    },
+}
+
+impl Display for MappingError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            MappingError::UnknownCopyToTarget {
+                ref field,
+                ref target,
+            } => write!(
+                f,
+                "the field `{}` copies to `{}`, which isn't a declared field",
+                field, target
+            ),
+            MappingError::MissingAnalyzer { data_type } => {
+                write!(f, "a `{}` field requires an `analyzer`", data_type)
+            }
+            MappingError::InvalidParameter { name, ref reason } => {
+                write!(f, "the `{}` parameter is invalid: {}", name, reason)
+            }
+            MappingError::InvalidFieldName {
+                ref name,
+                ref reason,
+            } => {
+                write!(f, "the field name `{}` is invalid: {}", name, reason)
+            }
+            MappingError::ConflictingFieldType {
+                ref field,
+                ref existing,
+                ref requested,
+            } => write!(
+                f,
+                "the field `{}` is already mapped as `{}`, so it can't be changed to `{}`",
+                field, existing, requested
+            ),
+            MappingError::ConflictingFieldMapping { ref field } => write!(
+                f,
+                "the field `{}` has different definitions in the mappings being merged",
+                field
+            ),
+            MappingError::TooManyFields { count, limit } => write!(
+                f,
+                "the mapping has {} fields, which is more than the limit of {}",
+                count, limit
+            ),
+            MappingError::TooDeep {
+                ref field,
+                depth,
+                limit,
+            } => write!(
+                f,
+                "the field `{}` has a depth of {}, which is more than the limit of {}",
+                field, depth, limit
+            ),
+            MappingError::DuplicateProperty { name } => {
+                write!(f, "the property `{}` is mapped more than once", name)
+            }
+            MappingError::DocValuesDisabled { ref field } => write!(
+                f,
+                "the field `{}` needs `doc_values` for sorting or aggregations, but they're disabled",
+                field
+            ),
+            MappingError::UnknownField { ref field } => {
+                write!(f, "the field `{}` isn't in the mapping", field)
+            }
+        }
+    }
+}
+
+impl Error for MappingError {
+    fn description(&self) -> &str {
+        match *self {
+            MappingError::UnknownCopyToTarget { .. } => "a `copy_to` target isn't a declared field",
+            MappingError::MissingAnalyzer { .. } => "a field requires an `analyzer`",
+            MappingError::InvalidParameter { .. } => "a mapping parameter is invalid",
+            MappingError::InvalidFieldName { .. } => "a field name is invalid",
+            MappingError::ConflictingFieldType { .. } => {
+                "a mapping update would change the type of an existing field"
+            }
+            MappingError::ConflictingFieldMapping { .. } => {
+                "a field has different definitions in the mappings being merged"
+            }
+            MappingError::TooManyFields { .. } => "a mapping has more fields than the index allows",
+            MappingError::TooDeep { .. } => "a field is nested deeper than the index allows",
+            MappingError::DuplicateProperty { .. } => "a property is mapped more than once",
+            MappingError::DocValuesDisabled { .. } => {
+                "a field that needs `doc_values` has them disabled"
+            }
+            MappingError::UnknownField { .. } => "a field isn't in the mapping",
+        }
+    }
+}
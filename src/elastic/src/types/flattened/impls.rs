@@ -0,0 +1,290 @@
+use super::mapping::{
+    FlattenedFieldType,
+    FlattenedMapping,
+};
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use std::{
+    borrow::Borrow,
+    marker::PhantomData,
+    ops::Deref,
+};
+
+/**
+A value mapped as an Elasticsearch `flattened` field.
+
+The wrapped value is usually a map, like `BTreeMap<String, String>`,
+and is serialised unchanged.
+
+# Examples
+
+Defining a `flattened` map with a mapping:
+
+```
+# use std::collections::BTreeMap;
+# use elastic::types::prelude::*;
+let mut labels = BTreeMap::new();
+labels.insert("release".to_owned(), "stable".to_owned());
+
+let flattened = Flattened::<_, DefaultFlattenedMapping>::new(labels);
+```
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Flattened<TValue, TMapping>
+where
+    TMapping: FlattenedMapping,
+{
+    value: TValue,
+    _m: PhantomData<TMapping>,
+}
+
+impl<TValue, TMapping> Flattened<TValue, TMapping>
+where
+    TMapping: FlattenedMapping,
+{
+    /** Creates a new `Flattened` with the given mapping. */
+    pub fn new(value: TValue) -> Flattened<TValue, TMapping> {
+        Flattened {
+            value,
+            _m: PhantomData,
+        }
+    }
+
+    /** Change the mapping of this flattened value. */
+    pub fn remap<TNewMapping>(
+        flattened: Flattened<TValue, TMapping>,
+    ) -> Flattened<TValue, TNewMapping>
+    where
+        TNewMapping: FlattenedMapping,
+    {
+        Flattened::new(flattened.value)
+    }
+
+    /** Get the wrapped value. */
+    pub fn into_inner(self) -> TValue {
+        self.value
+    }
+}
+
+impl<TValue, TMapping> FlattenedFieldType<TMapping> for Flattened<TValue, TMapping> where
+    TMapping: FlattenedMapping
+{
+}
+
+impl<TValue, TMapping> Deref for Flattened<TValue, TMapping>
+where
+    TMapping: FlattenedMapping,
+{
+    type Target = TValue;
+
+    fn deref(&self) -> &TValue {
+        &self.value
+    }
+}
+
+impl<TValue, TMapping> Borrow<TValue> for Flattened<TValue, TMapping>
+where
+    TMapping: FlattenedMapping,
+{
+    fn borrow(&self) -> &TValue {
+        &self.value
+    }
+}
+
+impl<TValue, TMapping> From<TValue> for Flattened<TValue, TMapping>
+where
+    TMapping: FlattenedMapping,
+{
+    fn from(value: TValue) -> Self {
+        Flattened::new(value)
+    }
+}
+
+impl<TValue, TMapping> Serialize for Flattened<TValue, TMapping>
+where
+    TValue: Serialize,
+    TMapping: FlattenedMapping,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de, TValue, TMapping> Deserialize<'de> for Flattened<TValue, TMapping>
+where
+    TValue: Deserialize<'de>,
+    TMapping: FlattenedMapping,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Flattened<TValue, TMapping>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        TValue::deserialize(deserializer).map(Flattened::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use std::collections::{
+        BTreeMap,
+        HashMap,
+    };
+
+    use crate::types::prelude::*;
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct MapType {
+        pub object: BTreeMap<String, i64>,
+        pub flattened: Flattened<BTreeMap<String, String>, DefaultFlattenedMapping>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct NestedMapType {
+        pub scores: HashMap<String, BTreeMap<String, f32>>,
+        pub inner: MapType,
+    }
+
+    #[derive(Serialize)]
+    pub struct Label {
+        pub name: String,
+    }
+
+    impl DefaultFieldType for Label {}
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct DefaultMapType {
+        pub labels: HashMap<String, Label>,
+    }
+
+    #[derive(Default)]
+    pub struct TooLongKeywordMapping;
+    impl KeywordMapping for TooLongKeywordMapping {
+        fn ignore_above() -> Option<u32> {
+            Some(MAX_IGNORE_ABOVE + 1)
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct InvalidMapType {
+        pub labels: BTreeMap<String, Keyword<TooLongKeywordMapping>>,
+    }
+
+    #[test]
+    fn serialise_flattened() {
+        let mut labels = BTreeMap::new();
+        labels.insert("release".to_owned(), "stable".to_owned());
+
+        let flattened: Flattened<_, DefaultFlattenedMapping> = Flattened::new(labels);
+
+        let ser = serde_json::to_value(&flattened).unwrap();
+
+        assert_eq!(json!({ "release": "stable" }), ser);
+    }
+
+    #[test]
+    fn deserialise_flattened() {
+        let flattened: Flattened<BTreeMap<String, String>, DefaultFlattenedMapping> =
+            serde_json::from_str(r#"{"release":"stable"}"#).unwrap();
+
+        assert_eq!(Some(&"stable".to_owned()), flattened.get("release"));
+    }
+
+    #[test]
+    fn maps_are_objects_unless_flattened() {
+        let mapping = MapType::index_mapping();
+
+        assert_eq!(
+            Some(json!({ "type": "object", "dynamic": true })),
+            mapping.get_field("object").unwrap()
+        );
+        assert_eq!(
+            Some(json!({ "type": "flattened" })),
+            mapping.get_field("flattened").unwrap()
+        );
+    }
+
+    #[test]
+    fn map_values_are_mapped_by_dynamic_templates() {
+        let ser = serde_json::to_value(&MapType::index_mapping()).unwrap();
+
+        let expected = json!([
+            {
+                "object": {
+                    "path_match": "object.*",
+                    "mapping": { "type": "long" }
+                }
+            }
+        ]);
+
+        assert_eq!(expected, ser["dynamic_templates"]);
+    }
+
+    #[test]
+    fn nested_map_values_are_mapped_by_dynamic_templates() {
+        let ser = serde_json::to_value(&NestedMapType::index_mapping()).unwrap();
+
+        // More specific templates for the values of nested maps come first
+        let expected = json!([
+            {
+                "inner.object": {
+                    "path_match": "inner.object.*",
+                    "mapping": { "type": "long" }
+                }
+            },
+            {
+                "scores.*": {
+                    "path_match": "scores.*.*",
+                    "mapping": { "type": "float" }
+                }
+            },
+            {
+                "scores": {
+                    "path_match": "scores.*",
+                    "mapping": { "type": "object", "dynamic": true }
+                }
+            }
+        ]);
+
+        assert_eq!(expected, ser["dynamic_templates"]);
+
+        // Inner objects don't get their own dynamic templates
+        let ser = serde_json::to_value(&MapType::field_mapping()).unwrap();
+
+        assert!(ser.get("dynamic_templates").is_none());
+    }
+
+    #[test]
+    fn maps_of_inferred_values_are_objects_without_templates() {
+        let ser = serde_json::to_value(&DefaultMapType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "labels": {
+                    "type": "object",
+                    "dynamic": true
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_map_with_invalid_value_mapping_fails() {
+        let err = serde_json::to_value(&InvalidMapType::index_mapping()).unwrap_err();
+
+        assert!(err.to_string().contains("ignore_above"));
+    }
+}
@@ -0,0 +1,223 @@
+/*! Mapping for the Elasticsearch `flattened` type. */
+
+use crate::types::boost::Boost;
+
+/** A field that will be mapped as `flattened`. */
+pub trait FlattenedFieldType<TMapping> {}
+
+/**
+The base requirements for mapping a `flattened` type.
+
+Custom mappings can be defined by implementing `FlattenedMapping`.
+
+# Examples
+
+Define a custom `FlattenedMapping`:
+
+```
+# #[macro_use] use elastic::types::prelude::*;
+#[derive(Default)]
+struct MyFlattenedMapping;
+impl FlattenedMapping for MyFlattenedMapping {
+    //Overload the mapping functions here
+    fn depth_limit() -> Option<u32> {
+        Some(5)
+    }
+}
+```
+
+This will produce the following mapping:
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# #[derive(Default)]
+# struct MyFlattenedMapping;
+# impl FlattenedMapping for MyFlattenedMapping {
+#     //Overload the mapping functions here
+#     fn depth_limit() -> Option<u32> {
+#         Some(5)
+#     }
+# }
+# let json = json!(
+{
+    "type": "flattened",
+    "depth_limit": 5
+}
+# );
+# let mapping = elastic::types::__derive::standalone_field_ser(MyFlattenedMapping).unwrap();
+# assert_eq!(json, mapping);
+```
+*/
+pub trait FlattenedMapping {
+    /** Field-level index time boosting. Accepts a positive floating point number, defaults to `1.0`. */
+    fn boost() -> Option<Boost> {
+        None
+    }
+
+    /**
+    The maximum allowed depth of the flattened object field, in terms of nested inner objects.
+    Defaults to `20`.
+    */
+    fn depth_limit() -> Option<u32> {
+        None
+    }
+
+    /**
+    Should the field be stored on disk in a column-stride fashion,
+    so that it can later be used for sorting, aggregations, or scripting?
+    Accepts `true` (default) or `false`.
+    */
+    fn doc_values() -> Option<bool> {
+        None
+    }
+
+    /**
+    Should global ordinals be loaded eagerly on refresh?
+    Accepts `true` or `false` (default).
+    */
+    fn eager_global_ordinals() -> Option<bool> {
+        None
+    }
+
+    /** Leaf values longer than this limit will not be indexed. */
+    fn ignore_above() -> Option<u32> {
+        None
+    }
+
+    /** Should the field be searchable? Accepts `true` (default) or `false`. */
+    fn index() -> Option<bool> {
+        None
+    }
+
+    /**
+    A string value which is substituted for any explicit null values within the flattened object field.
+    Defaults to `null`, which means null values are treated as missing.
+    */
+    fn null_value() -> Option<&'static str> {
+        None
+    }
+}
+
+/** Default mapping for `flattened`. */
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DefaultFlattenedMapping;
+impl FlattenedMapping for DefaultFlattenedMapping {}
+
+mod private {
+    use super::{
+        FlattenedFieldType,
+        FlattenedMapping,
+    };
+    use crate::types::private::field::{
+        FieldMapping,
+        FieldType,
+        SerializeFieldMapping,
+        StaticSerialize,
+    };
+    use serde::{
+        ser::SerializeStruct,
+        Serialize,
+        Serializer,
+    };
+
+    #[derive(Default)]
+    pub struct FlattenedPivot;
+
+    impl<TField, TMapping> FieldType<TMapping, FlattenedPivot> for TField
+    where
+        TMapping: FlattenedMapping,
+        TField: FlattenedFieldType<TMapping> + Serialize,
+    {
+    }
+
+    impl<TMapping> FieldMapping<FlattenedPivot> for TMapping
+    where
+        TMapping: FlattenedMapping,
+    {
+        type SerializeFieldMapping = SerializeFieldMapping<TMapping, FlattenedPivot>;
+
+        fn data_type() -> &'static str {
+            "flattened"
+        }
+    }
+
+    impl<TMapping> StaticSerialize for SerializeFieldMapping<TMapping, FlattenedPivot>
+    where
+        TMapping: FieldMapping<FlattenedPivot> + FlattenedMapping,
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("mapping", 8)?;
+
+            state.serialize_field("type", TMapping::data_type())?;
+
+            ser_field!(state, "boost", TMapping::boost());
+            ser_field!(state, "depth_limit", TMapping::depth_limit());
+            ser_field!(state, "doc_values", TMapping::doc_values());
+            ser_field!(
+                state,
+                "eager_global_ordinals",
+                TMapping::eager_global_ordinals()
+            );
+            ser_field!(state, "ignore_above", TMapping::ignore_above());
+            ser_field!(state, "index", TMapping::index());
+            ser_field!(state, "null_value", TMapping::null_value());
+
+            state.end()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::{
+        prelude::*,
+        private::field,
+    };
+
+    #[derive(Default, Clone)]
+    pub struct MyFlattenedMapping;
+    impl FlattenedMapping for MyFlattenedMapping {
+        fn depth_limit() -> Option<u32> {
+            Some(5)
+        }
+
+        fn ignore_above() -> Option<u32> {
+            Some(256)
+        }
+
+        fn null_value() -> Option<&'static str> {
+            Some("NULL")
+        }
+    }
+
+    #[test]
+    fn serialise_mapping_default() {
+        let ser = serde_json::to_value(&field::serialize(DefaultFlattenedMapping)).unwrap();
+
+        let expected = json!({
+            "type": "flattened"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_custom() {
+        let ser = serde_json::to_value(&field::serialize(MyFlattenedMapping)).unwrap();
+
+        let expected = json!({
+            "type": "flattened",
+            "depth_limit": 5,
+            "ignore_above": 256,
+            "null_value": "NULL"
+        });
+
+        assert_eq!(expected, ser);
+    }
+}
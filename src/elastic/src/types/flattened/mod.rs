@@ -0,0 +1,114 @@
+/*!
+Implementation of the Elasticsearch `flattened` type.
+
+Maps like `HashMap` and `BTreeMap` are mapped as dynamic `object`s by default,
+so each key in the map becomes a new field in the index mapping.
+The values of a `BTreeMap<String, V>` are mapped like `V` by a dynamic template on the root of the mapping,
+so `V` needs a mapping of its own, the same as any other field.
+Values that don't have a mapping can implement `DefaultFieldType` instead,
+and their maps don't get a dynamic template, so Elasticsearch infers the mapping of each value.
+Wrapping a map in `Flattened` maps the whole map as a single `flattened` field instead,
+which avoids a mapping explosion when keys are unbounded.
+
+# Examples
+
+Map with a dynamic `object` whose values are mapped as `long`s:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use std::collections::BTreeMap;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+struct MyType {
+    pub field: BTreeMap<String, i64>
+}
+```
+
+This will produce the following mapping:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use std::collections::BTreeMap;
+# use elastic::types::prelude::*;
+# #[derive(Serialize, ElasticType)]
+# struct MyType {
+#     pub field: BTreeMap<String, i64>
+# }
+# let mapping = serde_json::to_value(&MyType::index_mapping()).unwrap();
+# let expected = json!(
+{
+    "dynamic_templates": [
+        {
+            "field": {
+                "path_match": "field.*",
+                "mapping": {
+                    "type": "long"
+                }
+            }
+        }
+    ],
+    "properties": {
+        "field": {
+            "type": "object",
+            "dynamic": true
+        }
+    }
+}
+# );
+# assert_eq!(expected, mapping);
+```
+
+Map with values that are mapped by Elasticsearch:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use std::collections::HashMap;
+# use elastic::types::prelude::*;
+#[derive(Serialize)]
+struct Label {
+    pub name: String,
+}
+
+impl DefaultFieldType for Label {}
+
+#[derive(Serialize, ElasticType)]
+struct MyType {
+    pub labels: HashMap<String, Label>,
+}
+```
+
+Map with a default `flattened`:
+
+```
+# use std::collections::BTreeMap;
+# use elastic::types::prelude::*;
+struct MyType {
+    pub field: Flattened<BTreeMap<String, String>, DefaultFlattenedMapping>
+}
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/flattened.html)
+*/
+pub mod mapping;
+
+mod impls;
+pub use self::impls::*;
+
+pub mod prelude {
+    /*!
+    Includes all types for the `flattened` type.
+
+    This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+    */
+
+    pub use super::{
+        impls::*,
+        mapping::*,
+    };
+}
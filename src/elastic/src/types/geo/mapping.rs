@@ -55,3 +55,62 @@ impl Serialize for Distance {
         serializer.serialize_str(&self.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::{
+        prelude::*,
+        private::field,
+    };
+
+    #[derive(Default)]
+    struct StrictGeoPointMapping;
+    impl GeoPointMapping for StrictGeoPointMapping {
+        type Format = GeoPointArray;
+
+        fn ignore_z_value() -> Option<bool> {
+            Some(false)
+        }
+    }
+
+    #[derive(Default)]
+    struct StrictGeoShapeMapping;
+    impl GeoShapeMapping for StrictGeoShapeMapping {
+        fn ignore_z_value() -> Option<bool> {
+            Some(false)
+        }
+    }
+
+    #[derive(Default)]
+    struct StrictPointMapping;
+    impl PointMapping for StrictPointMapping {
+        fn ignore_z_value() -> Option<bool> {
+            Some(false)
+        }
+    }
+
+    #[test]
+    fn ignore_z_value_is_serialised_the_same_for_all_geo_types() {
+        let point = serde_json::to_value(&field::serialize(StrictGeoPointMapping)).unwrap();
+        let shape = serde_json::to_value(&field::serialize(StrictGeoShapeMapping)).unwrap();
+        let cartesian = serde_json::to_value(&field::serialize(StrictPointMapping)).unwrap();
+
+        assert_eq!(Some(&json!(false)), point.get("ignore_z_value"));
+        assert_eq!(point.get("ignore_z_value"), shape.get("ignore_z_value"));
+        assert_eq!(point.get("ignore_z_value"), cartesian.get("ignore_z_value"));
+    }
+
+    #[test]
+    fn ignore_z_value_is_omitted_by_default() {
+        let point = serde_json::to_value(&field::serialize(
+            DefaultGeoPointMapping::<GeoPointArray>::default(),
+        ))
+        .unwrap();
+        let shape = serde_json::to_value(&field::serialize(DefaultGeoShapeMapping)).unwrap();
+
+        assert!(point.get("ignore_z_value").is_none());
+        assert!(shape.get("ignore_z_value").is_none());
+    }
+}
@@ -0,0 +1,101 @@
+use super::{
+    impls::GeoPoint,
+    mapping::GeoPointMapping,
+};
+use serde::{
+    ser::SerializeStruct,
+    Serialize,
+    Serializer,
+};
+
+/**
+A bounding box made of two `geo_point`s.
+
+This is the shape expected for the bounds of a `geo_bounding_box` query.
+Each corner is serialised using the format of the point mapping.
+
+# Examples
+
+Define a bounding box:
+
+```
+# use elastic::types::prelude::*;
+let bounds: GeoBounds<DefaultGeoPointMapping<GeoPointObject>> = GeoBounds::new(
+    GeoPoint::build(-74.1, 40.73),
+    GeoPoint::build(-71.12, 40.01),
+);
+```
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl-geo-bounding-box-query.html)
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoBounds<TMapping>
+where
+    TMapping: GeoPointMapping,
+{
+    /** The top left corner of the bounding box. */
+    pub top_left: GeoPoint<TMapping>,
+    /** The bottom right corner of the bounding box. */
+    pub bottom_right: GeoPoint<TMapping>,
+}
+
+impl<TMapping> GeoBounds<TMapping>
+where
+    TMapping: GeoPointMapping,
+{
+    /** Creates a new `GeoBounds` from its top left and bottom right corners. */
+    pub fn new(top_left: GeoPoint<TMapping>, bottom_right: GeoPoint<TMapping>) -> Self {
+        GeoBounds {
+            top_left,
+            bottom_right,
+        }
+    }
+}
+
+impl<TMapping> Serialize for GeoBounds<TMapping>
+where
+    TMapping: GeoPointMapping,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("geo_bounds", 2)?;
+
+        state.serialize_field("top_left", &self.top_left)?;
+        state.serialize_field("bottom_right", &self.bottom_right)?;
+
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::prelude::*;
+
+    #[test]
+    fn serialise_geo_bounds() {
+        let bounds: GeoBounds<DefaultGeoPointMapping<GeoPointObject>> = GeoBounds::new(
+            GeoPoint::build(-74.1, 40.73),
+            GeoPoint::build(-71.12, 40.01),
+        );
+
+        let ser = serde_json::to_value(&bounds).unwrap();
+
+        let expected = json!({
+            "top_left": {
+                "lat": 40.73,
+                "lon": -74.1
+            },
+            "bottom_right": {
+                "lat": 40.01,
+                "lon": -71.12
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+}
@@ -12,6 +12,7 @@ use geo::{
     Geometry as GeoEnum,
     ToGeo,
 };
+use geohash;
 use serde::{
     Deserialize,
     Deserializer,
@@ -20,9 +21,18 @@ use serde::{
 };
 use std::{
     borrow::Borrow,
+    error::Error,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult,
+    },
     marker::PhantomData,
 };
 
+/** The longest geohash a point can be encoded as. */
+pub const MAX_GEOHASH_PRECISION: usize = 12;
+
 /**
 An Elasticsearch `geo_point` type with a format.
 
@@ -137,6 +147,84 @@ where
     {
         GeoPoint::new(point.value)
     }
+
+    /**
+    Encode this point as a geohash with the given number of characters.
+
+    The precision must be between `1` and `MAX_GEOHASH_PRECISION`.
+    This is independent of the format of the point, so it can be used to produce a geohash of any length
+    regardless of the `geohash_precision` on the mapping.
+
+    # Examples
+
+    ```
+    # use elastic::types::prelude::*;
+    let point: GeoPoint<DefaultGeoPointMapping> = GeoPoint::build(10.40744, 57.64911);
+
+    assert_eq!("u4pru", point.to_geohash(5)?);
+    # Ok::<(), GeohashError>(())
+    ```
+    */
+    pub fn to_geohash(&self, precision: usize) -> Result<String, GeohashError> {
+        if precision == 0 || precision > MAX_GEOHASH_PRECISION {
+            return Err(GeohashError::Precision { precision });
+        }
+
+        geohash::encode(
+            Coordinate {
+                x: self.value.x(),
+                y: self.value.y(),
+            },
+            precision,
+        )
+        .map_err(|_| GeohashError::Coordinate {
+            x: self.value.x(),
+            y: self.value.y(),
+        })
+    }
+}
+
+/** A geo point couldn't be encoded as a geohash. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GeohashError {
+    /** The precision isn't between `1` and `MAX_GEOHASH_PRECISION`. */
+    Precision {
+        /** The requested number of characters. */
+        precision: usize,
+    },
+    /** The point isn't a valid longitude and latitude. */
+    Coordinate {
+        /** The longitude. */
+        x: f64,
+        /** The latitude. */
+        y: f64,
+    },
+}
+
+impl Display for GeohashError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match *self {
+            GeohashError::Precision { precision } => write!(
+                f,
+                "the geohash precision {} must be between 1 and {}",
+                precision, MAX_GEOHASH_PRECISION
+            ),
+            GeohashError::Coordinate { x, y } => write!(
+                f,
+                "the point ({}, {}) isn't a valid longitude and latitude",
+                x, y
+            ),
+        }
+    }
+}
+
+impl Error for GeohashError {
+    fn description(&self) -> &str {
+        match *self {
+            GeohashError::Precision { .. } => "a geohash precision is out of range",
+            GeohashError::Coordinate { .. } => "a point can't be encoded as a geohash",
+        }
+    }
 }
 
 impl<TMapping> GeoPointFieldType<TMapping> for GeoPoint<TMapping> where TMapping: GeoPointMapping {}
@@ -234,4 +322,30 @@ mod tests {
             _ => panic!("expected point"),
         }
     }
+
+    #[test]
+    fn can_encode_point_as_geohash() {
+        let point: GeoPoint<DefaultGeoPointMapping> = GeoPoint::build(10.40744, 57.64911);
+
+        assert_eq!("u4pru", point.to_geohash(5).unwrap());
+        assert_eq!("u4pruydqq", point.to_geohash(9).unwrap());
+    }
+
+    #[test]
+    fn encode_point_as_geohash_with_invalid_precision_fails() {
+        let point: GeoPoint<DefaultGeoPointMapping> = GeoPoint::build(10.40744, 57.64911);
+
+        assert_eq!(
+            GeohashError::Precision { precision: 0 },
+            point.to_geohash(0).unwrap_err()
+        );
+        assert_eq!(
+            GeohashError::Precision { precision: 13 },
+            point.to_geohash(13).unwrap_err()
+        );
+
+        let point: GeoPoint<DefaultGeoPointMapping> = GeoPoint::build(200.0, 57.64911);
+
+        assert!(point.to_geohash(5).is_err());
+    }
 }
@@ -112,6 +112,15 @@ pub trait GeoPointMapping {
         None
     }
 
+    /**
+    If `true` (default), three dimension points are accepted but only latitude and longitude values are indexed,
+    and the third dimension is ignored.
+    If `false`, points with more than two dimensions throw an exception and reject the whole document.
+    */
+    fn ignore_z_value() -> Option<bool> {
+        None
+    }
+
     /**
     Should the `geo-point` also be indexed as `.lat` and `.lon` sub-fields?
     Accepts `true` and `false` (default).
@@ -143,6 +152,7 @@ mod private {
         GeoPointMapping,
     };
     use crate::types::private::field::{
+        ser_ignore_z_value,
         FieldMapping,
         FieldType,
         SerializeFieldMapping,
@@ -183,7 +193,7 @@ mod private {
         where
             S: Serializer,
         {
-            let mut state = serializer.serialize_struct("mapping", 6)?;
+            let mut state = serializer.serialize_struct("mapping", 7)?;
 
             state.serialize_field("type", TMapping::data_type())?;
 
@@ -191,6 +201,7 @@ mod private {
             ser_field!(state, "geohash_precision", TMapping::geohash_precision());
             ser_field!(state, "geohash_prefix", TMapping::geohash_prefix());
             ser_field!(state, "ignore_malformed", TMapping::ignore_malformed());
+            ser_ignore_z_value(&mut state, TMapping::ignore_z_value())?;
             ser_field!(state, "lat_lon", TMapping::lat_lon());
 
             state.end()
@@ -66,11 +66,13 @@ type Geometry = G<f64>;
 
 pub mod mapping;
 
+mod bounds;
 mod format;
 mod formats;
 mod impls;
 
 pub use self::{
+    bounds::*,
     format::*,
     formats::*,
     impls::*,
@@ -84,6 +86,7 @@ pub mod prelude {
     */
 
     pub use super::{
+        bounds::*,
         format::*,
         formats::*,
         impls::*,
@@ -2,7 +2,11 @@ use super::mapping::{
     GeoShapeFieldType,
     GeoShapeMapping,
 };
-use geojson::Geometry;
+use geojson::{
+    Geometry,
+    PolygonType,
+    Value,
+};
 use serde::{
     Deserialize,
     Deserializer,
@@ -11,6 +15,12 @@ use serde::{
 };
 use std::{
     borrow::Borrow,
+    error::Error,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult,
+    },
     marker::PhantomData,
 };
 
@@ -86,6 +96,114 @@ where
     }
 }
 
+impl<TMapping> GeoShape<TMapping>
+where
+    TMapping: GeoShapeMapping,
+{
+    /**
+    Check that the linear rings of polygons in this shape are closed.
+
+    A ring is closed if its first and last points are the same.
+    If the mapping has `coerce` set to `true` then open rings are closed by repeating their first point,
+    otherwise an open ring is an error.
+
+    # Examples
+
+    ```
+    # extern crate geojson;
+    use geojson::{ Geometry, Value };
+
+    # use elastic::types::prelude::*;
+    # fn main() {
+    #[derive(Default)]
+    struct MyGeoShapeMapping;
+    impl GeoShapeMapping for MyGeoShapeMapping {
+        fn coerce() -> Option<bool> {
+            Some(true)
+        }
+    }
+
+    let mut shape: GeoShape<MyGeoShapeMapping> = GeoShape::new(
+        Geometry::new(
+            Value::Polygon(vec![vec![
+                vec![ 0.0, 0.0 ],
+                vec![ 1.0, 0.0 ],
+                vec![ 1.0, 1.0 ],
+            ]])
+        )
+    );
+
+    shape.close_rings().unwrap();
+    # }
+    ```
+    */
+    pub fn close_rings(&mut self) -> Result<(), OpenRingError> {
+        let coerce = TMapping::coerce().unwrap_or(false);
+
+        close_geometry_rings(&mut self.value, coerce)
+    }
+}
+
+fn close_geometry_rings(geometry: &mut Geometry, coerce: bool) -> Result<(), OpenRingError> {
+    match geometry.value {
+        Value::Polygon(ref mut polygon) => close_polygon_rings(polygon, coerce),
+        Value::MultiPolygon(ref mut polygons) => {
+            for polygon in polygons {
+                close_polygon_rings(polygon, coerce)?;
+            }
+
+            Ok(())
+        }
+        Value::GeometryCollection(ref mut geometries) => {
+            for geometry in geometries {
+                close_geometry_rings(geometry, coerce)?;
+            }
+
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn close_polygon_rings(polygon: &mut PolygonType, coerce: bool) -> Result<(), OpenRingError> {
+    for ring in polygon {
+        let first = match ring.first() {
+            Some(first) if ring.last() != Some(first) => first.clone(),
+            _ => continue,
+        };
+
+        if !coerce {
+            return Err(OpenRingError { first });
+        }
+
+        ring.push(first);
+    }
+
+    Ok(())
+}
+
+/** A polygon has a linear ring whose first and last points aren't the same. */
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenRingError {
+    first: Vec<f64>,
+}
+
+impl Display for OpenRingError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f,
+            "the polygon ring starting at {:?} isn't closed, and the mapping doesn't coerce it",
+            self.first
+        )
+    }
+}
+
+impl Error for OpenRingError {
+    fn description(&self) -> &str {
+        "a polygon ring isn't closed"
+    }
+}
+
 impl<TMapping> GeoShapeFieldType<TMapping> for GeoShape<TMapping> where TMapping: GeoShapeMapping {}
 
 impl_mapping_type!(Geometry, GeoShape, GeoShapeMapping);
@@ -168,4 +286,71 @@ mod tests {
 
         assert_eq!(Geometry::new(Value::Point(vec![1.0, 1.0])), *shape);
     }
+
+    #[derive(Default)]
+    struct CoerceGeoShapeMapping;
+    impl GeoShapeMapping for CoerceGeoShapeMapping {
+        fn coerce() -> Option<bool> {
+            Some(true)
+        }
+    }
+
+    fn open_polygon() -> Geometry {
+        Geometry::new(Value::Polygon(vec![vec![
+            vec![0.0, 0.0],
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+        ]]))
+    }
+
+    fn closed_polygon() -> Geometry {
+        Geometry::new(Value::Polygon(vec![vec![
+            vec![0.0, 0.0],
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![0.0, 0.0],
+        ]]))
+    }
+
+    #[test]
+    fn close_rings_already_closed() {
+        let mut shape = GeoShape::<DefaultGeoShapeMapping>::new(closed_polygon());
+
+        shape.close_rings().unwrap();
+
+        assert_eq!(closed_polygon(), *shape);
+    }
+
+    #[test]
+    fn close_rings_open_with_coerce() {
+        let mut shape =
+            GeoShape::<CoerceGeoShapeMapping>::new(Geometry::new(Value::MultiPolygon(vec![vec![
+                vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![1.0, 1.0]],
+            ]])));
+
+        shape.close_rings().unwrap();
+
+        let expected = Geometry::new(Value::MultiPolygon(vec![vec![vec![
+            vec![0.0, 0.0],
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![0.0, 0.0],
+        ]]]));
+
+        assert_eq!(expected, *shape);
+
+        let mut shape = GeoShape::<CoerceGeoShapeMapping>::new(open_polygon());
+
+        shape.close_rings().unwrap();
+
+        assert_eq!(closed_polygon(), *shape);
+    }
+
+    #[test]
+    fn close_rings_open_without_coerce() {
+        let mut shape = GeoShape::<DefaultGeoShapeMapping>::new(open_polygon());
+
+        assert!(shape.close_rings().is_err());
+        assert_eq!(open_polygon(), *shape);
+    }
 }
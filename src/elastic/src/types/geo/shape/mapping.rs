@@ -126,6 +126,15 @@ pub trait GeoShapeMapping {
         None
     }
 
+    /**
+    If `true` (default), three dimension points are accepted but only latitude and longitude values are indexed,
+    and the third dimension is ignored.
+    If `false`, points with more than two dimensions throw an exception and reject the whole document.
+    */
+    fn ignore_z_value() -> Option<bool> {
+        None
+    }
+
     /**
     Setting this option to `true` (defaults to `false`) configures the `geo_shape` field
     type for point shapes only (NOTE: Multi-Points are not yet supported).
@@ -138,6 +147,15 @@ pub trait GeoShapeMapping {
     fn points_only() -> Option<bool> {
         None
     }
+
+    /**
+    If `true`, unclosed linear rings in polygons will be automatically closed.
+    Defaults to `false`.
+    See `GeoShape::close_rings` for closing rings before a document is indexed.
+    */
+    fn coerce() -> Option<bool> {
+        None
+    }
 }
 
 /** Default mapping for `geo_shape`. */
@@ -218,6 +236,7 @@ mod private {
         GeoShapeMapping,
     };
     use crate::types::private::field::{
+        ser_ignore_z_value,
         FieldMapping,
         FieldType,
         SerializeFieldMapping,
@@ -258,7 +277,7 @@ mod private {
         where
             S: Serializer,
         {
-            let mut state = serializer.serialize_struct("mapping", 8)?;
+            let mut state = serializer.serialize_struct("mapping", 10)?;
 
             state.serialize_field("type", TMapping::data_type())?;
 
@@ -268,7 +287,9 @@ mod private {
             ser_field!(state, "strategy", TMapping::strategy());
             ser_field!(state, "distance_error_pct", TMapping::distance_error_pct());
             ser_field!(state, "orientation", TMapping::orientation());
+            ser_ignore_z_value(&mut state, TMapping::ignore_z_value())?;
             ser_field!(state, "points_only", TMapping::points_only());
+            ser_field!(state, "coerce", TMapping::coerce());
 
             state.end()
         }
@@ -314,6 +335,10 @@ mod tests {
         fn points_only() -> Option<bool> {
             Some(false)
         }
+
+        fn coerce() -> Option<bool> {
+            Some(true)
+        }
     }
 
     #[test]
@@ -339,7 +364,8 @@ mod tests {
             "strategy": "recursive",
             "distance_error_pct": 0.5,
             "orientation": "cw",
-            "points_only": false
+            "points_only": false,
+            "coerce": true
         });
 
         assert_eq!(expected, ser);
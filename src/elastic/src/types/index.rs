@@ -0,0 +1,610 @@
+/*!
+Bodies for creating indices with the mappings and settings of document types.
+
+# Examples
+
+The body for creating an index with the mapping for a document type:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+# fn main() -> Result<(), serde_json::Error> {
+#[derive(Serialize, ElasticType)]
+struct MyType {
+    pub title: String,
+}
+
+let body = CreateIndexBody::new(MyType::index_mapping().resolve()?);
+# Ok(())
+# }
+```
+
+The body for creating an index with settings:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use std::time::Duration;
+# use elastic::types::prelude::*;
+# fn main() -> Result<(), serde_json::Error> {
+# #[derive(Serialize, ElasticType)]
+# struct MyType {
+#     pub title: String,
+# }
+let settings = IndexSettings {
+    number_of_shards: Some(3),
+    refresh_interval: Some(RefreshInterval::Every(Duration::from_secs(30))),
+    ..Default::default()
+};
+
+let body = CreateIndexBody::new(MyType::index_mapping().resolve()?).settings(settings);
+# Ok(())
+# }
+```
+
+The body for adding an alias to an index:
+
+```
+# use elastic::types::prelude::*;
+let body = AliasesBody::new(vec![
+    AliasAction::add("my-index-2", "my-index").filter(Query::term("live", true)),
+    AliasAction::remove("my-index-1", "my-index"),
+]);
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-create-index.html)
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/indices-aliases.html)
+*/
+
+use crate::types::{
+    document::resolved::DocumentMapping,
+    error::MappingError,
+};
+use serde::{
+    ser::{
+        SerializeMap,
+        SerializeStruct,
+    },
+    Serialize,
+    Serializer,
+};
+use serde_json::Value;
+use std::time::Duration;
+
+/** The body for the Create Index API, with the mapping for the documents in the index. */
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateIndexBody {
+    settings: Option<IndexSettings>,
+    mappings: DocumentMapping,
+}
+
+impl CreateIndexBody {
+    /** Create a body for an index with the given document mapping. */
+    pub fn new(mappings: DocumentMapping) -> Self {
+        CreateIndexBody {
+            settings: None,
+            mappings,
+        }
+    }
+
+    /** Set the settings for the index. */
+    pub fn settings(mut self, settings: IndexSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    /** Get the document mapping for the index. */
+    pub fn mappings(&self) -> &DocumentMapping {
+        &self.mappings
+    }
+
+    /** Check that the document mapping is within the mapping limits in the settings for the index. */
+    pub fn validate(&self) -> Result<(), MappingError> {
+        let settings = self.settings.as_ref();
+
+        self.mappings
+            .validate_total_fields(settings.and_then(|settings| settings.total_fields_limit))?;
+        self.mappings
+            .validate_depth(settings.and_then(|settings| settings.depth_limit))
+    }
+}
+
+impl Serialize for CreateIndexBody {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("create_index", 2)?;
+
+        ser_field!(state, "settings", self.settings.as_ref());
+        state.serialize_field("mappings", &self.mappings)?;
+
+        state.end()
+    }
+}
+
+/**
+Settings for an index.
+
+Settings are serialised with their full dotted names, like `index.refresh_interval`.
+Settings that aren't set use the Elasticsearch defaults.
+*/
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IndexSettings {
+    /** The number of primary shards. Defaults to `1`. This can't be changed after the index is created. */
+    pub number_of_shards: Option<u32>,
+    /** The number of replicas for each primary shard. Defaults to `1`. */
+    pub number_of_replicas: Option<u32>,
+    /** How often to make recent changes visible to search. Defaults to `1s`. */
+    pub refresh_interval: Option<RefreshInterval>,
+    /**
+    The maximum number of fields in the mapping, including objects and multi-fields.
+    Defaults to `1000`.
+    */
+    pub total_fields_limit: Option<u32>,
+    /**
+    The maximum depth of objects in the mapping.
+    A field in the root of the mapping has a depth of `1`.
+    Defaults to `20`.
+    */
+    pub depth_limit: Option<u32>,
+    /** The maximum number of distinct `nested` fields in the mapping. Defaults to `50`. */
+    pub nested_fields_limit: Option<u32>,
+    /** The maximum number of `nested` objects in a single document. Defaults to `10000`. */
+    pub nested_objects_limit: Option<u32>,
+    /**
+    How often the translog is written to disk and committed.
+    Only applies when translog durability is `async`.
+    Defaults to `5s`.
+    */
+    pub translog_sync_interval: Option<Duration>,
+    /** How long the version number of a deleted document is kept around for versioned operations. Defaults to `60s`. */
+    pub gc_deletes: Option<Duration>,
+    /** How long a shard can go without receiving a search before it's considered idle. Defaults to `30s`. */
+    pub search_idle_after: Option<Duration>,
+}
+
+impl Serialize for IndexSettings {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("settings", 10)?;
+
+        ser_field!(state, "index.number_of_shards", self.number_of_shards);
+        ser_field!(state, "index.number_of_replicas", self.number_of_replicas);
+        ser_field!(state, "index.refresh_interval", self.refresh_interval);
+        ser_field!(
+            state,
+            "index.mapping.total_fields.limit",
+            self.total_fields_limit
+        );
+        ser_field!(state, "index.mapping.depth.limit", self.depth_limit);
+        ser_field!(
+            state,
+            "index.mapping.nested_fields.limit",
+            self.nested_fields_limit
+        );
+        ser_field!(
+            state,
+            "index.mapping.nested_objects.limit",
+            self.nested_objects_limit
+        );
+        ser_field!(
+            state,
+            "index.translog.sync_interval",
+            self.translog_sync_interval.map(time_value)
+        );
+        ser_field!(state, "index.gc_deletes", self.gc_deletes.map(time_value));
+        ser_field!(
+            state,
+            "index.search.idle.after",
+            self.search_idle_after.map(time_value)
+        );
+
+        state.end()
+    }
+}
+
+/** How often an index makes recent changes visible to search. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RefreshInterval {
+    /** Don't refresh periodically. Serialised as `-1`. */
+    Disabled,
+    /** Refresh after the given interval, like `30s`. */
+    Every(Duration),
+}
+
+impl Serialize for RefreshInterval {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            RefreshInterval::Disabled => serializer.serialize_str("-1"),
+            RefreshInterval::Every(interval) => serializer.serialize_str(&time_value(interval)),
+        }
+    }
+}
+
+/** The body for the Aliases API, with the actions to apply to aliases. */
+#[derive(Debug, Clone, PartialEq)]
+pub struct AliasesBody {
+    actions: Vec<AliasAction>,
+}
+
+impl AliasesBody {
+    /** Create a body for the given alias actions. They're applied in order. */
+    pub fn new(actions: Vec<AliasAction>) -> Self {
+        AliasesBody { actions }
+    }
+
+    /** Add an alias action to the end of the body. */
+    pub fn action(mut self, action: AliasAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /** Get the alias actions in the body. */
+    pub fn actions(&self) -> &[AliasAction] {
+        &self.actions
+    }
+}
+
+impl Serialize for AliasesBody {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("aliases", 1)?;
+
+        state.serialize_field("actions", &self.actions)?;
+
+        state.end()
+    }
+}
+
+/**
+An action that adds or removes an alias for an index.
+
+Actions are serialised with their kind as the key, like `{"add":{"index":"my-index","alias":"my-alias"}}`.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct AliasAction {
+    kind: AliasActionKind,
+    index: String,
+    alias: String,
+    filter: Option<Value>,
+    routing: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AliasActionKind {
+    Add,
+    Remove,
+}
+
+impl AliasAction {
+    /** Add an alias for an index. */
+    pub fn add(index: impl Into<String>, alias: impl Into<String>) -> Self {
+        AliasAction::new(AliasActionKind::Add, index.into(), alias.into())
+    }
+
+    /** Remove an alias from an index. */
+    pub fn remove(index: impl Into<String>, alias: impl Into<String>) -> Self {
+        AliasAction::new(AliasActionKind::Remove, index.into(), alias.into())
+    }
+
+    fn new(kind: AliasActionKind, index: String, alias: String) -> Self {
+        AliasAction {
+            kind,
+            index,
+            alias,
+            filter: None,
+            routing: None,
+        }
+    }
+
+    /** Set a query, like a `Query`, that limits the documents the alias can access. */
+    pub fn filter(mut self, filter: impl Into<Value>) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /** Set the value used to route indexing and search operations through the alias to shards. */
+    pub fn routing(mut self, routing: impl Into<String>) -> Self {
+        self.routing = Some(routing.into());
+        self
+    }
+
+    /** Whether this action adds an alias. */
+    pub fn is_add(&self) -> bool {
+        self.kind == AliasActionKind::Add
+    }
+
+    /** Whether this action removes an alias. */
+    pub fn is_remove(&self) -> bool {
+        self.kind == AliasActionKind::Remove
+    }
+
+    /** Get the name of the index the alias belongs to. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** Get the name of the alias. */
+    pub fn alias(&self) -> &str {
+        &self.alias
+    }
+}
+
+impl Serialize for AliasAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        struct Params<'a>(&'a AliasAction);
+
+        impl<'a> Serialize for Params<'a> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut state = serializer.serialize_struct("alias", 4)?;
+
+                state.serialize_field("index", &self.0.index)?;
+                state.serialize_field("alias", &self.0.alias)?;
+                ser_field!(state, "filter", self.0.filter.as_ref());
+                ser_field!(state, "routing", self.0.routing.as_ref());
+
+                state.end()
+            }
+        }
+
+        let kind = match self.kind {
+            AliasActionKind::Add => "add",
+            AliasActionKind::Remove => "remove",
+        };
+
+        let mut state = serializer.serialize_map(Some(1))?;
+
+        state.serialize_entry(kind, &Params(self))?;
+
+        state.end()
+    }
+}
+
+// Format a duration as a time value in the largest unit that represents it exactly, like `30s`
+fn time_value(duration: Duration) -> String {
+    const UNITS: &[(&str, u128)] = &[
+        ("d", 86_400_000_000_000),
+        ("h", 3_600_000_000_000),
+        ("m", 60_000_000_000),
+        ("s", 1_000_000_000),
+        ("ms", 1_000_000),
+        ("micros", 1_000),
+    ];
+
+    let nanos = duration.as_nanos();
+
+    if nanos == 0 {
+        return "0s".to_owned();
+    }
+
+    UNITS
+        .iter()
+        .find(|&&(_, unit)| nanos % unit == 0)
+        .map(|&(suffix, unit)| format!("{}{}", nanos / unit, suffix))
+        .unwrap_or_else(|| format!("{}nanos", nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use std::time::Duration;
+
+    use crate::types::prelude::*;
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct CreatedType {
+        pub title: Keyword<DefaultKeywordMapping>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct CreatedTextType {
+        pub title: Text<DefaultStringMapping>,
+    }
+
+    #[test]
+    fn serialise_create_index_body() {
+        let body = CreateIndexBody::new(CreatedType::index_mapping().resolve().unwrap());
+
+        let ser = serde_json::to_value(&body).unwrap();
+
+        let expected = json!({
+            "mappings": {
+                "properties": {
+                    "title": {
+                        "type": "keyword"
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_create_index_body_with_settings() {
+        let settings = IndexSettings {
+            number_of_shards: Some(3),
+            number_of_replicas: Some(2),
+            ..Default::default()
+        };
+
+        let body = CreateIndexBody::new(CreatedType::index_mapping().resolve().unwrap())
+            .settings(settings);
+
+        let ser = serde_json::to_value(&body).unwrap();
+
+        let expected = json!({
+            "settings": {
+                "index.number_of_shards": 3,
+                "index.number_of_replicas": 2
+            },
+            "mappings": {
+                "properties": {
+                    "title": {
+                        "type": "keyword"
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_refresh_interval_disabled() {
+        let ser = serde_json::to_value(&RefreshInterval::Disabled).unwrap();
+
+        assert_eq!(json!("-1"), ser);
+    }
+
+    #[test]
+    fn serialise_refresh_interval_every() {
+        let ser = serde_json::to_value(&RefreshInterval::Every(Duration::from_secs(30))).unwrap();
+
+        assert_eq!(json!("30s"), ser);
+
+        let intervals: Vec<String> = vec![
+            Duration::from_millis(1500),
+            Duration::from_secs(120),
+            Duration::from_secs(7200),
+            Duration::from_secs(86400),
+            Duration::from_nanos(1),
+            Duration::from_secs(0),
+        ]
+        .into_iter()
+        .map(|interval| serde_json::to_string(&RefreshInterval::Every(interval)).unwrap())
+        .collect();
+
+        assert_eq!(
+            vec![
+                r#""1500ms""#,
+                r#""2m""#,
+                r#""2h""#,
+                r#""1d""#,
+                r#""1nanos""#,
+                r#""0s""#
+            ],
+            intervals
+        );
+    }
+
+    #[test]
+    fn serialise_index_settings_refresh_interval() {
+        let settings = IndexSettings {
+            refresh_interval: Some(RefreshInterval::Disabled),
+            ..Default::default()
+        };
+
+        let ser = serde_json::to_value(&settings).unwrap();
+
+        assert_eq!(json!({ "index.refresh_interval": "-1" }), ser);
+    }
+
+    #[test]
+    fn serialise_index_settings_time_values() {
+        let settings = IndexSettings {
+            translog_sync_interval: Some(Duration::from_secs(5)),
+            gc_deletes: Some(Duration::from_secs(120)),
+            search_idle_after: Some(Duration::from_millis(500)),
+            ..Default::default()
+        };
+
+        let ser = serde_json::to_value(&settings).unwrap();
+
+        let expected = json!({
+            "index.translog.sync_interval": "5s",
+            "index.gc_deletes": "2m",
+            "index.search.idle.after": "500ms"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_index_settings_mapping_limits() {
+        let settings = IndexSettings {
+            total_fields_limit: Some(2000),
+            depth_limit: Some(10),
+            nested_fields_limit: Some(20),
+            nested_objects_limit: Some(5000),
+            ..Default::default()
+        };
+
+        let ser = serde_json::to_value(&settings).unwrap();
+
+        let expected = json!({
+            "index.mapping.total_fields.limit": 2000,
+            "index.mapping.depth.limit": 10,
+            "index.mapping.nested_fields.limit": 20,
+            "index.mapping.nested_objects.limit": 5000
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_alias_add_with_filter() {
+        let action = AliasAction::add("my-index-2", "my-index")
+            .filter(json!({ "term": { "live": true } }))
+            .routing("1");
+
+        let ser = serde_json::to_string(&action).unwrap();
+
+        assert_eq!(
+            r#"{"add":{"index":"my-index-2","alias":"my-index","filter":{"term":{"live":true}},"routing":"1"}}"#,
+            ser
+        );
+    }
+
+    #[test]
+    fn serialise_aliases_body_with_remove() {
+        let body = AliasesBody::new(vec![AliasAction::add("my-index-2", "my-index")])
+            .action(AliasAction::remove("my-index-1", "my-index"));
+
+        let ser = serde_json::to_value(&body).unwrap();
+
+        let expected = json!({
+            "actions": [
+                { "add": { "index": "my-index-2", "alias": "my-index" } },
+                { "remove": { "index": "my-index-1", "alias": "my-index" } }
+            ]
+        });
+
+        assert_eq!(expected, ser);
+        assert!(body.actions()[1].is_remove());
+    }
+
+    #[test]
+    fn validate_create_index_body_total_fields_limit() {
+        let settings = IndexSettings {
+            total_fields_limit: Some(1),
+            ..Default::default()
+        };
+
+        let body = CreateIndexBody::new(CreatedType::index_mapping().resolve().unwrap());
+        assert!(body.validate().is_ok());
+
+        let body = CreatedTextType::index_mapping().resolve().unwrap();
+        let body = CreateIndexBody::new(body).settings(settings);
+        assert!(body.validate().is_err());
+    }
+}
@@ -1,5 +1,6 @@
 /*! Mapping for the Elasticsearch `ip` type. */
 
+use crate::types::boost::Boost;
 use std::net::Ipv4Addr;
 
 /** A field that will be mapped as an `ip`. */
@@ -20,8 +21,8 @@ Define a custom `IpMapping`:
 struct MyIpMapping;
 impl IpMapping for MyIpMapping {
     //Overload the mapping functions here
-    fn boost() -> Option<f32> {
-        Some(1.5)
+    fn boost() -> Option<Boost> {
+        Some(Boost::new(1.5).unwrap())
     }
 }
 ```
@@ -35,8 +36,8 @@ This will produce the following mapping:
 # struct MyIpMapping;
 # impl IpMapping for MyIpMapping {
 #     //Overload the mapping functions here
-#     fn boost() -> Option<f32> {
-#         Some(1.5)
+#     fn boost() -> Option<Boost> {
+#         Some(Boost::new(1.5).unwrap())
 #     }
 # }
 # let json = json!(
@@ -50,8 +51,8 @@ This will produce the following mapping:
 ```
 */
 pub trait IpMapping {
-    /** Field-level index time boosting. Accepts a floating point number, defaults to `1.0`. */
-    fn boost() -> Option<f32> {
+    /** Field-level index time boosting. Accepts a positive floating point number, defaults to `1.0`. */
+    fn boost() -> Option<Boost> {
         None
     }
 
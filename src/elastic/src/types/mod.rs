@@ -44,16 +44,27 @@ The following table illustrates the types provided by `elastic`:
  `byte`              | `i8`                        | `std`     | [`Byte<M>`][number-mod]                                  | -
  `float`             | `f32`                       | `std`     | [`Float<M>`][number-mod]                                 | -
  `double`            | `f64`                       | `std`     | [`Double<M>`][number-mod]                                | -
- `keyword`           | -                           | -         | [`Keyword<M>`][string-mod]                               | -
+ `keyword`[^uuid]    | `Uuid`                      | `uuid`    | [`Keyword<M>`][string-mod]                               | -
+ `keyword`           | `char`                      | `std`     | [`Keyword<M>`][string-mod]                               | -
  `text`              | `String`                    | `std`     | [`Text<M>`][string-mod]                                  | -
+ `search_as_you_type`| -                           | -         | [`SearchAsYouType<M>`][string-mod]                       | -
+ `match_only_text`   | -                           | -         | [`MatchOnlyText<M>`][string-mod]                         | -
  `boolean`           | `bool`                      | `std`     | [`Boolean<M>`][boolean-mod]                              | -
+ `flattened`         | -                           | -         | [`Flattened<T, M>`][flattened-mod]                       | -
  `ip`                | `Ipv4Addr`                  | `std`     | [`Ip<M>`][ip-mod]                                        | -
+ `dense_vector`      | -                           | -         | [`DenseVector<M>`][dense-vector-mod]                     | -
+ `sparse_vector`     | -                           | -         | [`SparseVector<M>`][sparse-vector-mod]                   | -
  `date`              | `DateTime<UTC>`             | `chrono`  | [`Date<M>`][date-mod]                                    | `DateFormat`
+ `date_nanos`        | -                           | `chrono`  | [`DateNanos<M>`][date-mod]                               | `DateFormat`
  `geo_point`[^geo]   | `Point`                     | `geo`     | [`GeoPoint<M>`][geopoint-mod]                            | `GeoPointFormat`
  `geo_shape`[^geo]   | -                           | `geojson` | [`GeoShape<M>`][geoshape-mod]                            | -
+ `point`             | `CartesianPoint`            | -         | type implementing [`PointFieldType<M>`][point-mod]       | -
+ `shape`[^geo]       | `CartesianShape`            | `geojson` | type implementing [`ShapeFieldType<M>`][shape-mod]       | -
 
 [^geo]: requires building with the `geo-types` feature (which is enabled by default)
 
+[^uuid]: the default mapping for `Uuid` requires building with the `uuid-types` feature
+
 ## Mapping
 
 Having the mapping available at compile-time captures the fact that a mapping is static and tied to the data type.
@@ -67,6 +78,13 @@ that wraps the `std` type but also takes an explicit mapping (like `Integer` whi
 Where there isn't a `std` type available (like `date`), an external crate is used and an implementation of
 that type is provided (like `Date`, which implements `Deref<Target = chrono::DateTime<UTC>>`).
 
+## Arrays
+
+Elasticsearch doesn't have a dedicated array type, any field can be indexed with zero or more values.
+So collections like `Vec<T>` and `HashSet<T>`, and optional values like `Option<T>`,
+are mapped the same way as the type they contain.
+A `Vec<bool>` is mapped as a `boolean`, and a `Vec<Date<M>>` is mapped as a `date` with the mapping `M`.
+
 ## Formats
 
 For some types (like `Date`), it's helpful to have an extra generic parameter that describes the way the data can be interpreted.
@@ -151,6 +169,7 @@ This will produce the following JSON:
     }
 }
 # );
+# #[cfg(not(feature = "string-as-keyword"))]
 # assert_eq!(expected, mapping);
 # Ok(())
 # }
@@ -229,23 +248,37 @@ Serialising `MyType`s mapping will produce the following json:
 [number-mod]: number/index.html
 [string-mod]: string/index.html
 [boolean-mod]: boolean/index.html
+[flattened-mod]: flattened/index.html
 [ip-mod]: ip/index.html
 [date-mod]: date/index.html
 [geopoint-mod]: geo/point/index.html
 [geoshape-mod]: geo/shape/index.html
+[point-mod]: cartesian/point/index.html
+[shape-mod]: cartesian/shape/index.html
+[dense-vector-mod]: vector/dense/index.html
+[sparse-vector-mod]: vector/sparse/index.html
 */
 
 #[macro_use]
 mod private;
 
 pub mod boolean;
+pub mod boost;
+pub mod cartesian;
 pub mod date;
 pub mod document;
-#[cfg(features = "geo-types")]
+pub mod error;
+pub mod flattened;
+#[cfg(feature = "geo-types")]
 pub mod geo;
+pub mod index;
 pub mod ip;
 pub mod number;
+pub mod query;
+pub mod raw;
+pub mod script;
 pub mod string;
+pub mod vector;
 
 #[doc(hidden)]
 pub mod __derive;
@@ -257,16 +290,44 @@ pub mod prelude {
     This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
     */
 
-    pub use super::document::prelude::*;
+    pub use super::{
+        boost::Boost,
+        document::prelude::*,
+        index::{
+            AliasAction,
+            AliasesBody,
+            CreateIndexBody,
+            IndexSettings,
+            RefreshInterval,
+        },
+        private::DefaultFieldType,
+        query::{
+            BoolQuery,
+            Query,
+            RangeQuery,
+        },
+        raw::{
+            RawFieldMapping,
+            RawFieldType,
+            RawMapping,
+        },
+        script::{
+            Script,
+            ScriptSource,
+        },
+    };
 
     pub use super::{
         boolean::prelude::*,
+        cartesian::prelude::*,
         date::prelude::*,
+        flattened::prelude::*,
         ip::prelude::*,
         number::prelude::*,
         string::prelude::*,
+        vector::prelude::*,
     };
 
-    #[cfg(features = "geo-types")]
-    pub use geo::prelude::*;
+    #[cfg(feature = "geo-types")]
+    pub use super::geo::prelude::*;
 }
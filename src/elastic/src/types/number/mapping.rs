@@ -2,7 +2,8 @@
 Mapping for the Elasticsearch `number` types.
 
 Custom mappings can be defined by implementing the right number mapping for some Rust primitive number type.
-The implementation is the same for all number types, the only difference is the return type of `null_value`.
+The implementation is the same for all number types, the only differences are the return type of `null_value`,
+and that only integer types have a `time_series_dimension`.
 
 # Examples
 
@@ -44,8 +45,57 @@ This will produce the following mapping:
 ```
 */
 
+use crate::types::boost::Boost;
+use serde::{
+    Serialize,
+    Serializer,
+};
+use std::{
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult,
+    },
+    num::{
+        NonZeroI16,
+        NonZeroI32,
+        NonZeroI64,
+        NonZeroI8,
+        NonZeroIsize,
+        NonZeroU16,
+        NonZeroU32,
+        NonZeroU8,
+    },
+};
+
+// Only integer types can be time series dimensions
+macro_rules! time_series_dimension {
+    (integer) => {
+        /**
+        Whether the field is a dimension that identifies a time series.
+        Accepts `true` or `false` (default).
+        This only applies to indices in `time_series` mode.
+        */
+        fn time_series_dimension() -> Option<bool> {
+            None
+        }
+    };
+    (float) => {};
+}
+
+macro_rules! ser_time_series_dimension {
+    (integer, $state:ident, $mapping:ty) => {
+        ser_field!(
+            $state,
+            "time_series_dimension",
+            <$mapping>::time_series_dimension()
+        );
+    };
+    (float, $state:ident, $mapping:ty) => {};
+}
+
 macro_rules! number_mapping {
-    ($mapping:ident, $pivot:ident, $field_trait:ident, $datatype_name:expr, $std_ty:ty, $private_mod:ident) => {
+    ($mapping:ident, $pivot:ident, $field_trait:ident, $datatype_name:expr, $std_ty:ty, $private_mod:ident, $kind:ident) => {
         /** A field that will be mapped as a number. */
         pub trait $field_trait<TMapping> {}
 
@@ -59,8 +109,17 @@ macro_rules! number_mapping {
                 None
             }
 
-            /** Field-level index time boosting. Accepts a floating point number, defaults to `1.0`. */
-            fn boost() -> Option<f32> {
+            /** Field-level index time boosting. Accepts a positive floating point number, defaults to `1.0`. */
+            fn boost() -> Option<Boost> {
+                None
+            }
+
+            /**
+            Copy the value of this field into one or more target fields,
+            which can then be queried as a single field.
+            Targets are the full dotted paths of fields declared on the same document.
+            */
+            fn copy_to() -> Option<&'static [&'static str]> {
                 None
             }
 
@@ -111,6 +170,16 @@ macro_rules! number_mapping {
             fn store() -> Option<bool> {
                 None
             }
+
+            time_series_dimension!($kind);
+
+            /**
+            The type of metric the field holds in a time series.
+            This only applies to indices in `time_series` mode.
+            */
+            fn time_series_metric() -> Option<TimeSeriesMetric> {
+                None
+            }
         }
 
         mod $private_mod {
@@ -148,18 +217,21 @@ macro_rules! number_mapping {
                 where
                     S: ::serde::Serializer,
                 {
-                    let mut state = serializer.serialize_struct("mapping", 9)?;
+                    let mut state = serializer.serialize_struct("mapping", 12)?;
 
                     state.serialize_field("type", TMapping::data_type())?;
 
                     ser_field!(state, "coerce", TMapping::coerce());
                     ser_field!(state, "boost", TMapping::boost());
+                    ser_field!(state, "copy_to", TMapping::copy_to());
                     ser_field!(state, "doc_values", TMapping::doc_values());
                     ser_field!(state, "ignore_malformed", TMapping::ignore_malformed());
                     ser_field!(state, "include_in_all", TMapping::include_in_all());
                     ser_field!(state, "index", TMapping::index());
                     ser_field!(state, "null_value", TMapping::null_value());
                     ser_field!(state, "store", TMapping::store());
+                    ser_time_series_dimension!($kind, state, TMapping);
+                    ser_field!(state, "time_series_metric", TMapping::time_series_metric());
 
                     state.end()
                 }
@@ -174,7 +246,8 @@ number_mapping!(
     IntegerFieldType,
     "integer",
     i32,
-    private_i32
+    private_i32,
+    integer
 );
 number_mapping!(
     LongMapping,
@@ -182,7 +255,8 @@ number_mapping!(
     LongFieldType,
     "long",
     i64,
-    private_i64
+    private_i64,
+    integer
 );
 number_mapping!(
     ShortMapping,
@@ -190,7 +264,8 @@ number_mapping!(
     ShortFieldType,
     "short",
     i16,
-    private_i16
+    private_i16,
+    integer
 );
 number_mapping!(
     ByteMapping,
@@ -198,7 +273,8 @@ number_mapping!(
     ByteFieldType,
     "byte",
     i8,
-    private_i8
+    private_i8,
+    integer
 );
 number_mapping!(
     FloatMapping,
@@ -206,7 +282,8 @@ number_mapping!(
     FloatFieldType,
     "float",
     f32,
-    private_f32
+    private_f32,
+    float
 );
 number_mapping!(
     DoubleMapping,
@@ -214,14 +291,229 @@ number_mapping!(
     DoubleFieldType,
     "double",
     f64,
-    private_f64
+    private_f64,
+    float
 );
 
+/** A field that will be mapped as a `scaled_float`. */
+pub trait ScaledFloatFieldType<TMapping> {}
+
+/**
+The base `scaled_float` mapping.
+
+A `scaled_float` is stored as a `long`, multiplied by the `scaling_factor` and rounded.
+Values are still sent and received as floats, so the `null_value` is the value before it's scaled.
+There's no default mapping for a `scaled_float`, because the `scaling_factor` has to be set.
+
+# Examples
+
+```
+# use elastic::types::prelude::*;
+#[derive(Default)]
+struct PriceMapping;
+impl ScaledFloatMapping for PriceMapping {
+    fn scaling_factor() -> f64 {
+        100.0
+    }
+
+    fn null_value() -> Option<f64> {
+        Some(0.0)
+    }
+}
+```
+*/
+pub trait ScaledFloatMapping
+where
+    Self: Default,
+{
+    /** The number that values are multiplied by before they're rounded and stored, like `100.0` for 2 decimal places. */
+    fn scaling_factor() -> f64;
+
+    /** Try to convert strings to numbers. Accepts `true` (default) and `false`. */
+    fn coerce() -> Option<bool> {
+        None
+    }
+
+    /** Field-level index time boosting. Accepts a positive floating point number, defaults to `1.0`. */
+    fn boost() -> Option<Boost> {
+        None
+    }
+
+    /**
+    Copy the value of this field into one or more target fields,
+    which can then be queried as a single field.
+    Targets are the full dotted paths of fields declared on the same document.
+    */
+    fn copy_to() -> Option<&'static [&'static str]> {
+        None
+    }
+
+    /**
+    Should the field be stored on disk in a column-stride fashion,
+    so that it can later be used for sorting, aggregations, or scripting?
+    Accepts `true` (default) or `false`.
+    */
+    fn doc_values() -> Option<bool> {
+        None
+    }
+
+    /**
+    If `true`, malformed numbers are ignored. If `false` (default),
+    malformed numbers throw an exception and reject the whole document.
+    */
+    fn ignore_malformed() -> Option<bool> {
+        None
+    }
+
+    /**
+    Whether or not the field value should be included in the `_all` field?
+    Accepts `true` or `false`. Defaults to false if index is set to no,
+    or if a parent object field sets `include_in_all` to false.
+    Otherwise defaults to `true`.
+    */
+    fn include_in_all() -> Option<bool> {
+        None
+    }
+
+    /** Should the field be searchable? Accepts `true` (default) and `false`. */
+    fn index() -> Option<bool> {
+        None
+    }
+
+    /**
+    A float, before it's scaled, which is substituted for any explicit null values.
+    Defaults to `null`, which means the field is treated as missing.
+    */
+    fn null_value() -> Option<f64> {
+        None
+    }
+
+    /**
+    Whether the field value should be stored and retrievable separately from the `_source` field.
+    Accepts true or false (default).
+    */
+    fn store() -> Option<bool> {
+        None
+    }
+
+    /**
+    The type of metric the field holds in a time series.
+    This only applies to indices in `time_series` mode.
+    */
+    fn time_series_metric() -> Option<TimeSeriesMetric> {
+        None
+    }
+}
+
+mod private_scaled_float {
+    use super::{
+        ScaledFloatFieldType,
+        ScaledFloatMapping,
+    };
+    use crate::types::private::field::{
+        FieldMapping,
+        FieldType,
+        SerializeFieldMapping,
+        StaticSerialize,
+    };
+    use serde::{
+        ser::SerializeStruct,
+        Serialize,
+    };
+
+    #[derive(Default)]
+    pub struct ScaledFloatFormat;
+
+    impl<TField, TMapping> FieldType<TMapping, ScaledFloatFormat> for TField
+    where
+        TField: ScaledFloatFieldType<TMapping> + Serialize,
+        TMapping: ScaledFloatMapping,
+    {
+    }
+
+    impl<TMapping> FieldMapping<ScaledFloatFormat> for TMapping
+    where
+        TMapping: ScaledFloatMapping,
+    {
+        type SerializeFieldMapping = SerializeFieldMapping<TMapping, ScaledFloatFormat>;
+
+        fn data_type() -> &'static str {
+            "scaled_float"
+        }
+    }
+
+    impl<TMapping> StaticSerialize for SerializeFieldMapping<TMapping, ScaledFloatFormat>
+    where
+        TMapping: FieldMapping<ScaledFloatFormat> + ScaledFloatMapping,
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ::serde::Serializer,
+        {
+            let mut state = serializer.serialize_struct("mapping", 12)?;
+
+            state.serialize_field("type", TMapping::data_type())?;
+            state.serialize_field("scaling_factor", &TMapping::scaling_factor())?;
+
+            ser_field!(state, "coerce", TMapping::coerce());
+            ser_field!(state, "boost", TMapping::boost());
+            ser_field!(state, "copy_to", TMapping::copy_to());
+            ser_field!(state, "doc_values", TMapping::doc_values());
+            ser_field!(state, "ignore_malformed", TMapping::ignore_malformed());
+            ser_field!(state, "include_in_all", TMapping::include_in_all());
+            ser_field!(state, "index", TMapping::index());
+            ser_field!(state, "null_value", TMapping::null_value());
+            ser_field!(state, "store", TMapping::store());
+            ser_field!(state, "time_series_metric", TMapping::time_series_metric());
+
+            state.end()
+        }
+    }
+}
+
+/** The type of metric a numeric field holds in a time series. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeSeriesMetric {
+    /** A value that can go up or down, like a temperature. */
+    Gauge,
+    /** A value that only increases, or resets to `0`, like a request count. */
+    Counter,
+}
+
+impl TimeSeriesMetric {
+    /** Get the value of this metric as it's sent to Elasticsearch. */
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            TimeSeriesMetric::Gauge => "gauge",
+            TimeSeriesMetric::Counter => "counter",
+        }
+    }
+}
+
+impl Serialize for TimeSeriesMetric {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Display for TimeSeriesMetric {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+impl_str_enum_deserialize!(TimeSeriesMetric { Gauge, Counter });
+
 /** Default mapping for an `integer` type. */
 #[derive(PartialEq, Debug, Default, Clone, Copy)]
 pub struct DefaultIntegerMapping;
 impl IntegerMapping for DefaultIntegerMapping {}
 impl IntegerFieldType<DefaultIntegerMapping> for i32 {}
+impl IntegerFieldType<DefaultIntegerMapping> for NonZeroI32 {}
+impl IntegerFieldType<DefaultIntegerMapping> for NonZeroU16 {}
 
 /** Default mapping for a `long` type. */
 #[derive(PartialEq, Debug, Default, Clone, Copy)]
@@ -229,18 +521,24 @@ pub struct DefaultLongMapping;
 impl LongMapping for DefaultLongMapping {}
 impl LongFieldType<DefaultLongMapping> for i64 {}
 impl LongFieldType<DefaultLongMapping> for isize {}
+impl LongFieldType<DefaultLongMapping> for NonZeroI64 {}
+impl LongFieldType<DefaultLongMapping> for NonZeroIsize {}
+impl LongFieldType<DefaultLongMapping> for NonZeroU32 {}
 
 /** Default mapping for a `short` type. */
 #[derive(PartialEq, Debug, Default, Clone, Copy)]
 pub struct DefaultShortMapping;
 impl ShortMapping for DefaultShortMapping {}
 impl ShortFieldType<DefaultShortMapping> for i16 {}
+impl ShortFieldType<DefaultShortMapping> for NonZeroI16 {}
+impl ShortFieldType<DefaultShortMapping> for NonZeroU8 {}
 
 /** Default mapping for a `byte` type. */
 #[derive(PartialEq, Debug, Default, Clone, Copy)]
 pub struct DefaultByteMapping;
 impl ByteMapping for DefaultByteMapping {}
 impl ByteFieldType<DefaultByteMapping> for i8 {}
+impl ByteFieldType<DefaultByteMapping> for NonZeroI8 {}
 
 /** Default mapping for a `float` type. */
 #[derive(PartialEq, Debug, Default, Clone, Copy)]
@@ -257,6 +555,16 @@ impl DoubleFieldType<DefaultDoubleMapping> for f64 {}
 #[cfg(test)]
 mod tests {
     use serde_json;
+    use std::num::{
+        NonZeroI16,
+        NonZeroI32,
+        NonZeroI64,
+        NonZeroI8,
+        NonZeroIsize,
+        NonZeroU16,
+        NonZeroU32,
+        NonZeroU8,
+    };
 
     use crate::types::{
         prelude::*,
@@ -451,6 +759,55 @@ mod tests {
         }
     }
 
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct BaseNumbers {
+        pub byte: i8,
+        pub short: i16,
+        pub integer: i32,
+        pub long: i64,
+        pub size: isize,
+        pub unsigned_byte: i16,
+        pub unsigned_short: i32,
+        pub unsigned_integer: i64,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct NonZeroNumbers {
+        pub byte: NonZeroI8,
+        pub short: NonZeroI16,
+        pub integer: NonZeroI32,
+        pub long: NonZeroI64,
+        pub size: NonZeroIsize,
+        pub unsigned_byte: NonZeroU8,
+        pub unsigned_short: NonZeroU16,
+        pub unsigned_integer: NonZeroU32,
+    }
+
+    #[test]
+    fn serialise_mapping_non_zero_same_as_base() {
+        let base = serde_json::to_value(&BaseNumbers::index_mapping()).unwrap();
+        let non_zero = serde_json::to_value(&NonZeroNumbers::index_mapping()).unwrap();
+
+        assert_eq!(base, non_zero);
+
+        let expected = json!({
+            "properties": {
+                "byte": { "type": "byte" },
+                "short": { "type": "short" },
+                "integer": { "type": "integer" },
+                "long": { "type": "long" },
+                "size": { "type": "long" },
+                "unsigned_byte": { "type": "short" },
+                "unsigned_short": { "type": "integer" },
+                "unsigned_integer": { "type": "long" }
+            }
+        });
+
+        assert_eq!(expected, non_zero);
+    }
+
     #[test]
     fn serialise_mapping_integer_default() {
         let ser = serde_json::to_value(&field::serialize(DefaultIntegerMapping)).unwrap();
@@ -623,4 +980,101 @@ mod tests {
 
         assert_eq!(expected, ser);
     }
+
+    #[derive(Default, Clone)]
+    pub struct MyGaugeMapping;
+    impl DoubleMapping for MyGaugeMapping {
+        fn time_series_metric() -> Option<TimeSeriesMetric> {
+            Some(TimeSeriesMetric::Gauge)
+        }
+    }
+
+    #[derive(Default, Clone)]
+    pub struct MyDimensionMapping;
+    impl IntegerMapping for MyDimensionMapping {
+        fn time_series_dimension() -> Option<bool> {
+            Some(true)
+        }
+    }
+
+    #[derive(Default, Clone)]
+    pub struct MyScaledFloatMapping;
+    impl ScaledFloatMapping for MyScaledFloatMapping {
+        fn scaling_factor() -> f64 {
+            100.0
+        }
+
+        fn coerce() -> Option<bool> {
+            Some(false)
+        }
+
+        fn ignore_malformed() -> Option<bool> {
+            Some(true)
+        }
+
+        fn null_value() -> Option<f64> {
+            Some(2.0)
+        }
+    }
+
+    #[derive(Default, Clone)]
+    pub struct MinimalScaledFloatMapping;
+    impl ScaledFloatMapping for MinimalScaledFloatMapping {
+        fn scaling_factor() -> f64 {
+            10.0
+        }
+    }
+
+    #[test]
+    fn serialise_mapping_scaled_float_minimal() {
+        let ser = serde_json::to_value(&field::serialize(MinimalScaledFloatMapping)).unwrap();
+
+        let expected = json!({
+            "type": "scaled_float",
+            "scaling_factor": 10.0
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_scaled_float_custom() {
+        let ser = serde_json::to_value(&field::serialize(MyScaledFloatMapping)).unwrap();
+
+        let expected = json!({
+            "type": "scaled_float",
+            "scaling_factor": 100.0,
+            "coerce": false,
+            "ignore_malformed": true,
+            "null_value": 2.0
+        });
+
+        assert_eq!(expected, ser);
+
+        assert!(ser["null_value"].is_f64());
+    }
+
+    #[test]
+    fn serialise_mapping_time_series_metric() {
+        let ser = serde_json::to_value(&field::serialize(MyGaugeMapping)).unwrap();
+
+        let expected = json!({
+            "type": "double",
+            "time_series_metric": "gauge"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_time_series_dimension() {
+        let ser = serde_json::to_value(&field::serialize(MyDimensionMapping)).unwrap();
+
+        let expected = json!({
+            "type": "integer",
+            "time_series_dimension": true
+        });
+
+        assert_eq!(expected, ser);
+    }
 }
@@ -12,8 +12,13 @@ Rust | Elasticsearch
 `f64` | `double`
 `f32` | `float`
 
+The `NonZero*` integers are mapped the same way as their base integer.
+Unsigned `NonZero*` integers are mapped as the smallest signed type that can hold all of their values,
+so `NonZeroU8` is a `short`, `NonZeroU16` is an `integer`, and `NonZeroU32` is a `long`.
+
 For mapping a number with the default mapping, you can use the Rust primitive.
 If you need to use a custom mapping, then there is an `Elastic*` type for each number.
+A `scaled_float` doesn't correspond to a primitive, so it's always mapped with a `ScaledFloat` and a `ScaledFloatMapping`.
 
 # Examples
 
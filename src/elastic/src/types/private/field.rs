@@ -7,8 +7,10 @@ The idea is to use a concrete type for `TPivot` so non-overlapping blanket imple
 
 use serde::ser::{
     Serialize,
+    SerializeStruct,
     Serializer,
 };
+use serde_json::Value;
 use std::{
     borrow::Borrow,
     marker::PhantomData,
@@ -34,6 +36,27 @@ pub trait FieldMapping<TPivot> {
     type SerializeFieldMapping: StaticSerialize;
 
     fn data_type() -> &'static str;
+
+    /**
+    Add the dynamic templates for values under this field that are mapped dynamically, like the values of a map.
+
+    Dynamic templates can only be set on the root of a mapping, so they're collected from each field and matched by its `path`.
+    */
+    fn dynamic_templates(
+        _path: &str,
+        _templates: &mut Vec<Value>,
+    ) -> Result<(), serde_json::Error> {
+        Ok(())
+    }
+
+    /**
+    Whether values with this mapping are mapped by Elasticsearch when they're indexed.
+
+    The values of maps with an inferred mapping don't get a dynamic template.
+    */
+    fn is_inferred() -> bool {
+        false
+    }
 }
 
 /** Captures traits required for conversion between a field with mapping and a default counterpart. */
@@ -81,6 +104,23 @@ where
     }
 }
 
+/**
+Serialise the `ignore_z_value` parameter shared by the geo and cartesian types.
+
+The parameter is omitted when it's `None`, so Elasticsearch uses its default of `true`.
+*/
+pub(crate) fn ser_ignore_z_value<S>(
+    state: &mut S,
+    ignore_z_value: Option<bool>,
+) -> Result<(), S::Error>
+where
+    S: SerializeStruct,
+{
+    ser_field!(state, "ignore_z_value", ignore_z_value);
+
+    Ok(())
+}
+
 #[cfg(test)]
 pub(crate) fn serialize<TMapping, TPivot>(_: TMapping) -> SerializeFieldMapping<TMapping, TPivot>
 where
@@ -0,0 +1,58 @@
+/*!
+Comparison helpers for floating point mapping parameters.
+
+Floats don't implement `Eq` or `Hash`, which mapping types need to be usable as cache keys.
+These helpers canonicalise a float into its bit representation so `0.0` and `-0.0` compare equal,
+and all `NaN`s compare equal to each other.
+*/
+
+use std::hash::{
+    Hash,
+    Hasher,
+};
+
+/** Get a canonical bit representation of an optional float. */
+pub fn canonical_bits(value: Option<f32>) -> Option<u32> {
+    value.map(|value| {
+        if value.is_nan() {
+            ::std::f32::NAN.to_bits()
+        } else if value == 0.0 {
+            0
+        } else {
+            value.to_bits()
+        }
+    })
+}
+
+/** Compare two optional floats using their canonical bit representations. */
+pub fn eq(a: Option<f32>, b: Option<f32>) -> bool {
+    canonical_bits(a) == canonical_bits(b)
+}
+
+/** Hash an optional float using its canonical bit representation. */
+pub fn hash<H>(value: Option<f32>, state: &mut H)
+where
+    H: Hasher,
+{
+    canonical_bits(value).hash(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signed_zeros_are_equal() {
+        assert!(eq(Some(0.0), Some(-0.0)));
+    }
+
+    #[test]
+    fn nans_are_equal() {
+        assert!(eq(Some(::std::f32::NAN), Some(-::std::f32::NAN)));
+    }
+
+    #[test]
+    fn none_is_not_zero() {
+        assert!(!eq(None, Some(0.0)));
+    }
+}
@@ -3,6 +3,11 @@ use serde::{
     Serialize,
     Serializer,
 };
+use serde_json::{
+    self,
+    Map,
+    Value,
+};
 use std::{
     collections::{
         BTreeMap,
@@ -20,6 +25,13 @@ use super::field::{
     StaticSerialize,
 };
 
+/**
+A value that's mapped by Elasticsearch when it's indexed, rather than by a mapping.
+
+Implement this for types that don't have a mapping of their own to use them as the values of a map.
+Maps of these values are mapped as a dynamic `object` without a dynamic template,
+so Elasticsearch infers the mapping for each of their values.
+*/
 pub trait DefaultFieldType {}
 
 /** A mapping implementation for a non-core type, or anywhere it's ok for Elasticsearch to infer the mapping at index-time. */
@@ -31,6 +43,10 @@ impl FieldMapping<()> for DefaultMapping {
     fn data_type() -> &'static str {
         "object"
     }
+
+    fn is_inferred() -> bool {
+        true
+    }
 }
 
 impl StaticSerialize for SerializeFieldMapping<DefaultMapping, ()> {
@@ -46,6 +62,77 @@ impl StaticSerialize for SerializeFieldMapping<DefaultMapping, ()> {
     }
 }
 
+/**
+Mapping for a map, like `BTreeMap<String, V>`, whose values are mapped like `V`.
+
+A map is a dynamic `object`, so each key becomes a new field in the index mapping.
+The values are mapped by a dynamic template on the root of the mapping that matches the path of the map.
+*/
+#[derive(Debug, Default, Clone)]
+pub struct MapMapping<TMapping, TPivot>
+where
+    TMapping: FieldMapping<TPivot>,
+{
+    _m: PhantomData<(TMapping, TPivot)>,
+}
+
+#[derive(Default)]
+pub struct MapPivot;
+
+impl<TMapping, TPivot> FieldMapping<MapPivot> for MapMapping<TMapping, TPivot>
+where
+    TMapping: FieldMapping<TPivot>,
+{
+    type SerializeFieldMapping = SerializeFieldMapping<MapMapping<TMapping, TPivot>, MapPivot>;
+
+    fn data_type() -> &'static str {
+        "object"
+    }
+
+    fn dynamic_templates(path: &str, templates: &mut Vec<Value>) -> Result<(), serde_json::Error> {
+        let path_match = format!("{}.*", path);
+
+        // Templates for nested maps are more specific, so they need to be matched first
+        TMapping::dynamic_templates(&path_match, templates)?;
+
+        if TMapping::is_inferred() {
+            return Ok(());
+        }
+
+        let mapping =
+            TMapping::SerializeFieldMapping::static_serialize(serde_json::value::Serializer)?;
+
+        let mut template = Map::new();
+        template.insert("path_match".to_owned(), Value::String(path_match));
+        template.insert("mapping".to_owned(), mapping);
+
+        let mut named = Map::new();
+        named.insert(path.to_owned(), Value::Object(template));
+
+        templates.push(Value::Object(named));
+
+        Ok(())
+    }
+}
+
+impl<TMapping, TPivot> StaticSerialize
+    for SerializeFieldMapping<MapMapping<TMapping, TPivot>, MapPivot>
+where
+    TMapping: FieldMapping<TPivot>,
+{
+    fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("mapping", 2)?;
+
+        state.serialize_field("type", MapMapping::<TMapping, TPivot>::data_type())?;
+        state.serialize_field("dynamic", &true)?;
+
+        state.end()
+    }
+}
+
 /**
 A type that inherits its mapping from an inner value.
 */
@@ -74,6 +161,14 @@ where
     fn data_type() -> &'static str {
         TMapping::data_type()
     }
+
+    fn dynamic_templates(path: &str, templates: &mut Vec<Value>) -> Result<(), serde_json::Error> {
+        TMapping::dynamic_templates(path, templates)
+    }
+
+    fn is_inferred() -> bool {
+        TMapping::is_inferred()
+    }
 }
 
 impl<TMapping, TPivot> StaticSerialize
@@ -89,24 +184,26 @@ where
     }
 }
 
-impl<TField> FieldType<DefaultMapping, ()> for TField where TField: DefaultFieldType {}
-
 /** Mapping implementation for a standard binary tree map. */
-impl<K, V> DefaultFieldType for BTreeMap<K, V>
+impl<K, V, TMapping, TPivot> FieldType<MapMapping<TMapping, TPivot>, MapPivot> for BTreeMap<K, V>
 where
     K: AsRef<str> + Ord + Serialize,
-    V: Serialize,
+    V: FieldType<TMapping, TPivot> + Serialize,
+    TMapping: FieldMapping<TPivot>,
 {
 }
 
 /** Mapping implementation for a standard hash map. */
-impl<K, V> DefaultFieldType for HashMap<K, V>
+impl<K, V, TMapping, TPivot> FieldType<MapMapping<TMapping, TPivot>, MapPivot> for HashMap<K, V>
 where
     K: AsRef<str> + Eq + Hash + Serialize,
-    V: Serialize,
+    V: FieldType<TMapping, TPivot> + Serialize,
+    TMapping: FieldMapping<TPivot>,
 {
 }
 
+impl<TField> FieldType<DefaultMapping, ()> for TField where TField: DefaultFieldType {}
+
 impl<TField, TMapping, TPivot> FieldType<WrappedMapping<TMapping, TPivot>, TPivot> for TField
 where
     TField: WrappedFieldType<TMapping, TPivot>,
@@ -79,3 +79,51 @@ macro_rules! impl_mapping_type {
         }
     };
 }
+
+// Deserialise a fieldless enum from the value of its `as_str` method
+// Matching is case-insensitive, because Elasticsearch versions don't always agree on casing
+macro_rules! impl_str_enum_deserialize {
+    ($enum_ty:ident { $($variant:ident),+ }) => {
+        impl<'de> ::serde::Deserialize<'de> for $enum_ty {
+            fn deserialize<D>(deserializer: D) -> Result<$enum_ty, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                const VARIANTS: &[$enum_ty] = &[$($enum_ty::$variant),+];
+
+                struct StrEnumVisitor;
+
+                impl<'de> ::serde::de::Visitor<'de> for StrEnumVisitor {
+                    type Value = $enum_ty;
+
+                    fn expecting(
+                        &self,
+                        formatter: &mut ::std::fmt::Formatter,
+                    ) -> ::std::fmt::Result {
+                        let variants: Vec<_> = VARIANTS
+                            .iter()
+                            .map(|variant| format!("`{}`", variant.as_str()))
+                            .collect();
+
+                        write!(formatter, "one of {}", variants.join(", "))
+                    }
+
+                    fn visit_str<E>(self, v: &str) -> Result<$enum_ty, E>
+                    where
+                        E: ::serde::de::Error,
+                    {
+                        VARIANTS
+                            .iter()
+                            .find(|variant| variant.as_str().eq_ignore_ascii_case(v))
+                            .cloned()
+                            .ok_or_else(|| {
+                                E::invalid_value(::serde::de::Unexpected::Str(v), &self)
+                            })
+                    }
+                }
+
+                deserializer.deserialize_str(StrEnumVisitor)
+            }
+        }
+    };
+}
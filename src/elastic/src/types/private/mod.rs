@@ -7,5 +7,11 @@ This module contains implementation details that support serialisation of mappab
 #[macro_use]
 pub mod macros;
 pub mod field;
+pub mod float;
 
 mod impls;
+
+pub use self::impls::{
+    DefaultFieldType,
+    WrappedFieldType,
+};
@@ -0,0 +1,290 @@
+/*!
+Queries used by index bodies, like the `filter` of an alias.
+
+This is a small subset of the query DSL, with just enough to filter the documents an alias can access.
+Use a raw json `Value` for anything else.
+
+# Examples
+
+A `term` query on a keyword field:
+
+```
+# use elastic::types::prelude::*;
+let query = Query::term("status", "published");
+```
+
+A `bool` query that combines other queries:
+
+```
+# use elastic::types::prelude::*;
+let query = Query::bool(BoolQuery {
+    filter: vec![
+        Query::term("status", "published"),
+        Query::range("likes", RangeQuery {
+            gte: Some(10.into()),
+            ..Default::default()
+        }),
+    ],
+    ..Default::default()
+});
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/query-dsl.html)
+*/
+
+use serde::{
+    ser::{
+        SerializeMap,
+        SerializeStruct,
+    },
+    Serialize,
+    Serializer,
+};
+use serde_json::{
+    self,
+    Value,
+};
+
+/** A query in the Elasticsearch query DSL. */
+#[derive(Debug, Clone, PartialEq)]
+pub enum Query {
+    /** Match documents where a field contains an exact term. */
+    Term {
+        /** The field to match. */
+        field: String,
+        /** The exact term to match. This isn't analysed. */
+        value: Value,
+    },
+    /** Match documents where a field is within a range. */
+    Range {
+        /** The field to match. */
+        field: String,
+        /** The bounds of the range. */
+        range: RangeQuery,
+    },
+    /** Match documents with a boolean combination of other queries. */
+    Bool(BoolQuery),
+}
+
+impl Query {
+    /** A `term` query on the given field. */
+    pub fn term(field: impl Into<String>, value: impl Into<Value>) -> Self {
+        Query::Term {
+            field: field.into(),
+            value: value.into(),
+        }
+    }
+
+    /** A `range` query on the given field. */
+    pub fn range(field: impl Into<String>, range: RangeQuery) -> Self {
+        Query::Range {
+            field: field.into(),
+            range,
+        }
+    }
+
+    /** A `bool` query. */
+    pub fn bool(query: BoolQuery) -> Self {
+        Query::Bool(query)
+    }
+}
+
+/**
+The bounds of a `range` query.
+
+Bounds that aren't given are left open.
+*/
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RangeQuery {
+    /** Greater than. */
+    pub gt: Option<Value>,
+    /** Greater than or equal to. */
+    pub gte: Option<Value>,
+    /** Less than. */
+    pub lt: Option<Value>,
+    /** Less than or equal to. */
+    pub lte: Option<Value>,
+}
+
+/**
+The clauses of a `bool` query.
+
+Clauses that are empty aren't serialised.
+*/
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BoolQuery {
+    /** Queries that must match, and contribute to the score. */
+    pub must: Vec<Query>,
+    /** Queries that must match, but don't contribute to the score. */
+    pub filter: Vec<Query>,
+    /** Queries that should match. */
+    pub should: Vec<Query>,
+    /** Queries that must not match. */
+    pub must_not: Vec<Query>,
+}
+
+impl Serialize for Query {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        struct Field<'a, T> {
+            field: &'a str,
+            value: &'a T,
+        }
+
+        impl<'a, T> Serialize for Field<'a, T>
+        where
+            T: Serialize,
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut state = serializer.serialize_map(Some(1))?;
+
+                state.serialize_entry(self.field, self.value)?;
+
+                state.end()
+            }
+        }
+
+        let mut state = serializer.serialize_map(Some(1))?;
+
+        match *self {
+            Query::Term {
+                ref field,
+                ref value,
+            } => state.serialize_entry("term", &Field { field, value })?,
+            Query::Range {
+                ref field,
+                ref range,
+            } => state.serialize_entry(
+                "range",
+                &Field {
+                    field,
+                    value: range,
+                },
+            )?,
+            Query::Bool(ref query) => state.serialize_entry("bool", query)?,
+        }
+
+        state.end()
+    }
+}
+
+impl Serialize for RangeQuery {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("range", 4)?;
+
+        ser_field!(state, "gt", self.gt.as_ref());
+        ser_field!(state, "gte", self.gte.as_ref());
+        ser_field!(state, "lt", self.lt.as_ref());
+        ser_field!(state, "lte", self.lte.as_ref());
+
+        state.end()
+    }
+}
+
+impl Serialize for BoolQuery {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("bool", 4)?;
+
+        for &(name, clause) in &[
+            ("must", &self.must),
+            ("filter", &self.filter),
+            ("should", &self.should),
+            ("must_not", &self.must_not),
+        ] {
+            if !clause.is_empty() {
+                state.serialize_field(name, clause)?;
+            }
+        }
+
+        state.end()
+    }
+}
+
+impl From<Query> for Value {
+    fn from(query: Query) -> Self {
+        // Queries only have string keys, so they can always be converted into json
+        serde_json::to_value(&query).expect("failed to convert a query into json")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::prelude::*;
+
+    #[test]
+    fn serialise_term_query() {
+        let ser = serde_json::to_value(&Query::term("status", "published")).unwrap();
+
+        assert_eq!(json!({ "term": { "status": "published" } }), ser);
+    }
+
+    #[test]
+    fn serialise_bool_query() {
+        let query = Query::bool(BoolQuery {
+            filter: vec![
+                Query::term("status", "published"),
+                Query::range(
+                    "likes",
+                    RangeQuery {
+                        gte: Some(10.into()),
+                        lt: Some(100.into()),
+                        ..Default::default()
+                    },
+                ),
+            ],
+            must_not: vec![Query::term("hidden", true)],
+            ..Default::default()
+        });
+
+        let ser = serde_json::to_value(&query).unwrap();
+
+        let expected = json!({
+            "bool": {
+                "filter": [
+                    { "term": { "status": "published" } },
+                    { "range": { "likes": { "gte": 10, "lt": 100 } } }
+                ],
+                "must_not": [
+                    { "term": { "hidden": true } }
+                ]
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_alias_with_term_filter() {
+        let action = AliasAction::add("my-index-2", "my-index")
+            .filter(Query::term("status.keyword", "published"));
+
+        let ser = serde_json::to_value(&action).unwrap();
+
+        let expected = json!({
+            "add": {
+                "index": "my-index-2",
+                "alias": "my-index",
+                "filter": {
+                    "term": { "status.keyword": "published" }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+}
@@ -0,0 +1,293 @@
+/*!
+Mappings for datatypes that don't have a dedicated implementation yet.
+
+New datatypes are added to Elasticsearch faster than they can be modelled here.
+A raw mapping serialises any `type`, along with arbitrary json parameters,
+so a field can use a datatype before it's properly supported.
+Raw mappings aren't checked, so prefer the dedicated mapping for a datatype when there is one.
+
+# Examples
+
+Define a `RawFieldMapping` for a datatype:
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+let mapping = RawFieldMapping::new("brand_new_type").param("fancy_option", true);
+```
+
+Use a `RawMapping` for a field on a document:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# use serde_json::Value;
+#[derive(Default)]
+struct BrandNewMapping;
+impl RawMapping for BrandNewMapping {
+    fn type_name() -> &'static str {
+        "brand_new_type"
+    }
+
+    fn params() -> Value {
+        json!({ "fancy_option": true })
+    }
+}
+
+#[derive(Serialize, ElasticType)]
+struct MyType {
+    #[elastic(mapping = "BrandNewMapping")]
+    pub field: String,
+}
+```
+*/
+
+use serde::{
+    ser::{
+        Error as SerError,
+        SerializeMap,
+    },
+    Serialize,
+    Serializer,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+
+/** A field that will be mapped with a raw mapping. */
+pub trait RawFieldType<TMapping> {}
+
+/**
+The base requirements for mapping a field with a raw `type` and parameters.
+
+Raw mappings can be used on a field with the `#[elastic(mapping)]` attribute.
+*/
+pub trait RawMapping {
+    /** The name of the datatype, like `keyword`. */
+    fn type_name() -> &'static str;
+
+    /** The parameters for the field, as a json object. */
+    fn params() -> Value {
+        Value::Object(Map::new())
+    }
+}
+
+/**
+A mapping with a raw `type` and parameters.
+
+The parameters are serialised after the `type`.
+They must be a json object.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawFieldMapping {
+    /** The name of the datatype, like `keyword`. */
+    pub type_name: &'static str,
+    /** The parameters for the field, as a json object. */
+    pub params: Value,
+}
+
+impl RawFieldMapping {
+    /** Create a raw mapping for the given datatype, with no parameters. */
+    pub fn new(type_name: &'static str) -> Self {
+        RawFieldMapping {
+            type_name,
+            params: Value::Object(Map::new()),
+        }
+    }
+
+    /** Set a parameter on the mapping. */
+    pub fn param<V>(mut self, name: &str, value: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        if let Value::Object(ref mut params) = self.params {
+            params.insert(name.to_owned(), value.into());
+        }
+
+        self
+    }
+}
+
+impl Serialize for RawFieldMapping {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let params = match self.params {
+            Value::Object(ref params) => params,
+            Value::Null => return serialize_type_only(self.type_name, serializer),
+            _ => {
+                return Err(S::Error::custom(format_args!(
+                    "the parameters for a `{}` field must be a json object",
+                    self.type_name
+                )))
+            }
+        };
+
+        if params.contains_key("type") {
+            return Err(S::Error::custom(format_args!(
+                "the parameters for a `{}` field can't include a `type`",
+                self.type_name
+            )));
+        }
+
+        let mut state = serializer.serialize_map(Some(params.len() + 1))?;
+
+        state.serialize_entry("type", self.type_name)?;
+
+        for (name, value) in params {
+            state.serialize_entry(name, value)?;
+        }
+
+        state.end()
+    }
+}
+
+fn serialize_type_only<S>(type_name: &'static str, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut state = serializer.serialize_map(Some(1))?;
+
+    state.serialize_entry("type", type_name)?;
+
+    state.end()
+}
+
+mod private {
+    use super::{
+        RawFieldMapping,
+        RawFieldType,
+        RawMapping,
+    };
+    use crate::types::private::field::{
+        FieldMapping,
+        FieldType,
+        SerializeFieldMapping,
+        StaticSerialize,
+    };
+    use serde::{
+        Serialize,
+        Serializer,
+    };
+
+    #[derive(Default)]
+    pub struct RawPivot;
+
+    impl<TField, TMapping> FieldType<TMapping, RawPivot> for TField
+    where
+        TField: RawFieldType<TMapping> + Serialize,
+        TMapping: RawMapping,
+    {
+    }
+
+    impl<TMapping> FieldMapping<RawPivot> for TMapping
+    where
+        TMapping: RawMapping,
+    {
+        type SerializeFieldMapping = SerializeFieldMapping<TMapping, RawPivot>;
+
+        fn data_type() -> &'static str {
+            TMapping::type_name()
+        }
+    }
+
+    impl<TMapping> StaticSerialize for SerializeFieldMapping<TMapping, RawPivot>
+    where
+        TMapping: FieldMapping<RawPivot> + RawMapping,
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            RawFieldMapping {
+                type_name: TMapping::type_name(),
+                params: TMapping::params(),
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    use crate::types::{
+        prelude::*,
+        private::field,
+    };
+
+    #[derive(Default)]
+    pub struct BrandNewMapping;
+    impl RawMapping for BrandNewMapping {
+        fn type_name() -> &'static str {
+            "brand_new_type"
+        }
+
+        fn params() -> Value {
+            json!({
+                "fancy_option": true,
+                "dims": 3
+            })
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct RawType {
+        #[elastic(mapping = "BrandNewMapping")]
+        pub field: String,
+    }
+
+    #[test]
+    fn serialise_raw_field_mapping() {
+        let mapping = RawFieldMapping::new("brand_new_type")
+            .param("fancy_option", true)
+            .param("dims", 3);
+
+        let ser = serde_json::to_string(&mapping).unwrap();
+
+        assert_eq!(
+            r#"{"type":"brand_new_type","dims":3,"fancy_option":true}"#,
+            ser
+        );
+    }
+
+    #[test]
+    fn serialise_raw_mapping() {
+        let ser = serde_json::to_value(&field::serialize(BrandNewMapping)).unwrap();
+
+        let expected = json!({
+            "type": "brand_new_type",
+            "fancy_option": true,
+            "dims": 3
+        });
+
+        assert_eq!(expected, ser);
+
+        let ser = serde_json::to_value(&RawType::index_mapping()).unwrap();
+
+        assert_eq!(json!({ "properties": { "field": expected } }), ser);
+    }
+
+    #[test]
+    fn serialise_raw_field_mapping_with_invalid_params_fails() {
+        let mapping = RawFieldMapping {
+            type_name: "brand_new_type",
+            params: json!([1, 2]),
+        };
+
+        assert!(serde_json::to_value(&mapping).is_err());
+
+        let mapping = RawFieldMapping::new("brand_new_type").param("type", "keyword");
+
+        assert!(serde_json::to_value(&mapping).is_err());
+    }
+}
@@ -0,0 +1,172 @@
+/*!
+Scripts used by mapping parameters, like the `script` of a runtime field.
+
+A script is either inline, where its source is sent with the mapping,
+or stored, where it's referenced by the id it was stored in the cluster state with.
+
+# Examples
+
+An inline script with a parameter:
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+let script = Script::inline("emit(doc['count'].value * params.factor)").param("factor", 2);
+```
+
+A stored script:
+
+```
+# use elastic::types::prelude::*;
+let script = Script::stored("calculate-score");
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/modules-scripting-using.html)
+*/
+
+use serde::{
+    ser::SerializeStruct,
+    Serialize,
+    Serializer,
+};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/** A script with its parameters. */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Script {
+    /** The source of the script, or the id it's stored with. */
+    pub source: ScriptSource,
+    /**
+    The language the script is written in.
+    Defaults to `painless`.
+    Stored scripts already have a language, so this is ignored for them.
+    */
+    pub lang: Option<&'static str>,
+    /** Parameters passed to the script as variables. */
+    pub params: BTreeMap<&'static str, Value>,
+}
+
+/** Where the source of a script comes from. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScriptSource {
+    /** The source of the script is sent with the request. */
+    Inline(&'static str),
+    /** The id of a script stored in the cluster state. */
+    Stored(&'static str),
+}
+
+impl Script {
+    /** Create a new inline script with the given source. */
+    pub fn inline(source: &'static str) -> Self {
+        Script::new(ScriptSource::Inline(source))
+    }
+
+    /** Create a reference to a stored script with the given id. */
+    pub fn stored(id: &'static str) -> Self {
+        Script::new(ScriptSource::Stored(id))
+    }
+
+    fn new(source: ScriptSource) -> Self {
+        Script {
+            source,
+            lang: None,
+            params: BTreeMap::new(),
+        }
+    }
+
+    /** Set the language of the script. */
+    pub fn lang(mut self, lang: &'static str) -> Self {
+        self.lang = Some(lang);
+        self
+    }
+
+    /** Add a parameter that's passed to the script as a variable. */
+    pub fn param<V>(mut self, name: &'static str, value: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        self.params.insert(name, value.into());
+        self
+    }
+}
+
+impl Serialize for Script {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("script", 3)?;
+
+        match self.source {
+            ScriptSource::Inline(source) => {
+                state.serialize_field("source", source)?;
+                ser_field!(state, "lang", self.lang);
+            }
+            ScriptSource::Stored(id) => state.serialize_field("id", id)?,
+        }
+
+        if !self.params.is_empty() {
+            state.serialize_field("params", &self.params)?;
+        }
+
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+
+    #[test]
+    fn serialise_inline_script() {
+        let script = Script::inline("emit(doc['count'].value)");
+
+        let ser = serde_json::to_value(&script).unwrap();
+
+        assert_eq!(json!({ "source": "emit(doc['count'].value)" }), ser);
+    }
+
+    #[test]
+    fn serialise_inline_script_with_lang_and_params() {
+        let script = Script::inline("emit(doc['count'].value * params.factor)")
+            .lang("expression")
+            .param("factor", 2)
+            .param("field", "count");
+
+        let ser = serde_json::to_value(&script).unwrap();
+
+        let expected = json!({
+            "source": "emit(doc['count'].value * params.factor)",
+            "lang": "expression",
+            "params": {
+                "factor": 2,
+                "field": "count"
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_stored_script() {
+        let script = Script::stored("calculate-score")
+            .lang("painless")
+            .param("factor", 2);
+
+        let ser = serde_json::to_value(&script).unwrap();
+
+        let expected = json!({
+            "id": "calculate-score",
+            "params": {
+                "factor": 2
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+}
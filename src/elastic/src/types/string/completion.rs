@@ -0,0 +1,229 @@
+/*!
+Values for the Elasticsearch `completion` type.
+
+A `completion` field is usually mapped as a sub-field using `ElasticCompletionFieldMapping`.
+The values in this module are used when indexing suggestions into a completion field directly.
+
+# Examples
+
+Index a single suggestion:
+
+```
+# use elastic::types::prelude::*;
+let input = CompletionInput::new("Nevermind");
+```
+
+Index multiple suggestions with a weight:
+
+```
+# use elastic::types::prelude::*;
+let input = CompletionInput::weighted(vec!["Nevermind", "Nirvana"], 34);
+```
+
+Weights must fit in a non-negative 32-bit integer.
+Use `try_weighted` to check a weight that comes from somewhere else:
+
+```
+# use elastic::types::prelude::*;
+assert!(CompletionInput::try_weighted(vec!["Nevermind"], 34).is_ok());
+assert!(CompletionInput::try_weighted(vec!["Nevermind"], -1).is_err());
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters-completion.html#indexing)
+*/
+
+use serde::{
+    ser::{
+        Error as SerError,
+        SerializeStruct,
+    },
+    Serialize,
+    Serializer,
+};
+use std::{
+    error::Error,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult,
+    },
+};
+
+/** The largest weight a completion suggestion can have. */
+pub const MAX_COMPLETION_WEIGHT: u32 = i32::MAX as u32;
+
+/** A suggestion to index into a `completion` field. */
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompletionInput {
+    /** A single input, serialised as a plain string. */
+    Simple(String),
+    /** Multiple inputs that share an optional weight. */
+    Weighted {
+        /** The inputs to index. */
+        input: Vec<String>,
+        /** A weight between `0` and `MAX_COMPLETION_WEIGHT` used to rank the suggestions. */
+        weight: Option<u32>,
+    },
+}
+
+impl CompletionInput {
+    /** Create a simple input from a single string. */
+    pub fn new<I>(input: I) -> Self
+    where
+        I: Into<String>,
+    {
+        CompletionInput::Simple(input.into())
+    }
+
+    /** Create an input from multiple strings with the given weight. */
+    pub fn weighted<I, S>(input: I, weight: u32) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        CompletionInput::Weighted {
+            input: input.into_iter().map(Into::into).collect(),
+            weight: Some(weight),
+        }
+    }
+
+    /**
+    Create an input from multiple strings with the given weight,
+    checking that the weight is between `0` and `MAX_COMPLETION_WEIGHT`.
+    */
+    pub fn try_weighted<I, S>(input: I, weight: i64) -> Result<Self, CompletionWeightError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        if weight < 0 || weight > i64::from(MAX_COMPLETION_WEIGHT) {
+            return Err(CompletionWeightError { weight });
+        }
+
+        Ok(CompletionInput::weighted(input, weight as u32))
+    }
+}
+
+/** A completion suggestion has a weight that isn't between `0` and `MAX_COMPLETION_WEIGHT`. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompletionWeightError {
+    weight: i64,
+}
+
+impl Display for CompletionWeightError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f,
+            "the completion weight {} must be between 0 and {}",
+            self.weight, MAX_COMPLETION_WEIGHT
+        )
+    }
+}
+
+impl Error for CompletionWeightError {
+    fn description(&self) -> &str {
+        "a completion weight is out of range"
+    }
+}
+
+impl Serialize for CompletionInput {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            CompletionInput::Simple(ref input) => serializer.serialize_str(input),
+            CompletionInput::Weighted { ref input, weight } => {
+                if let Some(weight) = weight.filter(|&weight| weight > MAX_COMPLETION_WEIGHT) {
+                    return Err(S::Error::custom(CompletionWeightError {
+                        weight: i64::from(weight),
+                    }));
+                }
+
+                let mut state = serializer.serialize_struct("completion", 2)?;
+
+                state.serialize_field("input", input)?;
+                ser_field!(state, "weight", weight);
+
+                state.end()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::prelude::*;
+
+    #[test]
+    fn serialise_simple_input() {
+        let ser = serde_json::to_value(&CompletionInput::new("Nevermind")).unwrap();
+
+        assert_eq!(json!("Nevermind"), ser);
+    }
+
+    #[test]
+    fn serialise_weighted_input() {
+        let ser =
+            serde_json::to_value(&CompletionInput::weighted(vec!["Nevermind", "Nirvana"], 34))
+                .unwrap();
+
+        let expected = json!({
+            "input": ["Nevermind", "Nirvana"],
+            "weight": 34
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn try_weighted_input_with_valid_weight() {
+        let input = CompletionInput::try_weighted(vec!["Nevermind"], 34).unwrap();
+
+        assert_eq!(CompletionInput::weighted(vec!["Nevermind"], 34), input);
+
+        let input =
+            CompletionInput::try_weighted(vec!["Nevermind"], i64::from(MAX_COMPLETION_WEIGHT))
+                .unwrap();
+
+        assert_eq!(
+            CompletionInput::weighted(vec!["Nevermind"], MAX_COMPLETION_WEIGHT),
+            input
+        );
+    }
+
+    #[test]
+    fn try_weighted_input_with_zero_weight() {
+        let input = CompletionInput::try_weighted(vec!["Nevermind"], 0).unwrap();
+
+        let ser = serde_json::to_value(&input).unwrap();
+
+        let expected = json!({
+            "input": ["Nevermind"],
+            "weight": 0
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn try_weighted_input_with_negative_weight_fails() {
+        assert!(CompletionInput::try_weighted(vec!["Nevermind"], -1).is_err());
+        assert!(CompletionInput::try_weighted(
+            vec!["Nevermind"],
+            i64::from(MAX_COMPLETION_WEIGHT) + 1
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn serialise_weighted_input_out_of_range_fails() {
+        let input = CompletionInput::weighted(vec!["Nevermind"], MAX_COMPLETION_WEIGHT + 1);
+
+        assert!(serde_json::to_value(&input).is_err());
+    }
+}
@@ -80,3 +80,17 @@ where
 }
 
 impl_string_type!(Keyword, KeywordMapping, KeywordFieldType);
+
+// Strings are mapped as `text` unless the `string-as-keyword` feature is enabled
+#[cfg(feature = "string-as-keyword")]
+impl KeywordFieldType<super::mapping::DefaultKeywordMapping> for String {}
+#[cfg(feature = "string-as-keyword")]
+impl<'a> KeywordFieldType<super::mapping::DefaultKeywordMapping> for &'a str {}
+#[cfg(feature = "string-as-keyword")]
+impl KeywordFieldType<super::mapping::DefaultKeywordMapping> for std::borrow::Cow<'static, str> {}
+
+impl KeywordFieldType<super::mapping::DefaultCharMapping> for char {}
+
+// Uuids are exact values, so they're mapped as a `keyword`
+#[cfg(feature = "uuid-types")]
+impl KeywordFieldType<super::mapping::DefaultKeywordMapping> for uuid::Uuid {}
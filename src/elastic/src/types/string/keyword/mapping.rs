@@ -1,6 +1,8 @@
 /*! Mapping for the Elasticsearch `keyword` type. */
 
 use crate::types::{
+    boost::Boost,
+    error::MappingError,
     private::field::FieldMapping,
     string::mapping::{
         IndexOptions,
@@ -8,12 +10,40 @@ use crate::types::{
     },
 };
 use serde::{
-    ser::SerializeStruct,
+    ser::{
+        Error as SerError,
+        SerializeStruct,
+    },
     Serialize,
     Serializer,
 };
 use std::collections::BTreeMap;
 
+/**
+The largest `ignore_above` that's guaranteed to fit in a single term.
+
+Lucene can't index a term longer than `32766` bytes.
+A character can take up to `4` bytes in UTF-8, so this is the most characters that always fit,
+however the characters are counted towards the `ignore_above`.
+*/
+pub const MAX_IGNORE_ABOVE: u32 = 32766 / 4;
+
+/** Check an `ignore_above` value doesn't exceed `MAX_IGNORE_ABOVE`. */
+pub(crate) fn validate_ignore_above(ignore_above: Option<u32>) -> Result<(), MappingError> {
+    match ignore_above {
+        Some(ignore_above) if ignore_above > MAX_IGNORE_ABOVE => {
+            Err(MappingError::InvalidParameter {
+                name: "ignore_above",
+                reason: format!(
+                    "must be at most {}, but was {}",
+                    MAX_IGNORE_ABOVE, ignore_above
+                ),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
 /** A field that will be mapped as a `keyword`. */
 pub trait KeywordFieldType<TMapping> {}
 
@@ -32,8 +62,8 @@ Define a custom `KeywordMapping`:
 struct MyStringMapping;
 impl KeywordMapping for MyStringMapping {
     //Overload the mapping functions here
-    fn boost() -> Option<f32> {
-        Some(1.5)
+    fn boost() -> Option<Boost> {
+        Some(Boost::new(1.5).unwrap())
     }
 }
 ```
@@ -47,8 +77,8 @@ This will produce the following mapping:
 # struct MyStringMapping;
 # impl KeywordMapping for MyStringMapping {
 #     //Overload the mapping functions here
-#     fn boost() -> Option<f32> {
-#         Some(1.5)
+#     fn boost() -> Option<Boost> {
+#         Some(Boost::new(1.5).unwrap())
 #     }
 # }
 # let json = json!(
@@ -71,8 +101,17 @@ pub trait KeywordMapping {
         None
     }
 
-    /** Field-level index time boosting. Accepts a floating point number, defaults to `1.0`. */
-    fn boost() -> Option<f32> {
+    /** Field-level index time boosting. Accepts a positive floating point number, defaults to `1.0`. */
+    fn boost() -> Option<Boost> {
+        None
+    }
+
+    /**
+    Copy the value of this field into one or more target fields,
+    which can then be queried as a single field.
+    Targets are the full dotted paths of fields declared on the same document.
+    */
+    fn copy_to() -> Option<&'static [&'static str]> {
         None
     }
 
@@ -115,7 +154,10 @@ pub trait KeywordMapping {
 
         //Add a `token_count` as a sub field
         fields.insert("count", StringField::TokenCount(
-            ElasticTokenCountFieldMapping::default())
+            ElasticTokenCountFieldMapping {
+                analyzer: Some("standard"),
+                ..Default::default()
+            })
         );
 
         //Add a `completion` suggester as a sub field
@@ -145,6 +187,7 @@ pub trait KeywordMapping {
     /**
     The maximum number of characters to index.
     Any characters over this length will be ignored.
+    This can't be more than `MAX_IGNORE_ABOVE`.
     */
     fn ignore_above() -> Option<u32> {
         None
@@ -162,6 +205,14 @@ pub trait KeywordMapping {
         None
     }
 
+    /**
+    The name of the normalizer to apply to values before they're indexed and queried.
+    The normalizer is defined in the analysis settings of the index.
+    */
+    fn normalizer() -> Option<&'static str> {
+        None
+    }
+
     /** Whether field-length should be taken into account when scoring queries. Accepts `true` (default) or `false`. */
     fn norms() -> Option<bool> {
         None
@@ -198,6 +249,15 @@ pub trait KeywordMapping {
     fn similarity() -> Option<&'static str> {
         None
     }
+
+    /**
+    Whether the field is a dimension that identifies a time series.
+    Accepts `true` or `false` (default).
+    This only applies to indices in `time_series` mode.
+    */
+    fn time_series_dimension() -> Option<bool> {
+        None
+    }
 }
 
 /** Default mapping for `bool`. */
@@ -205,8 +265,22 @@ pub trait KeywordMapping {
 pub struct DefaultKeywordMapping;
 impl KeywordMapping for DefaultKeywordMapping {}
 
+/**
+Default mapping for `char`.
+
+A `char` is a single value, so it's mapped as a `keyword` with an `ignore_above` of `1`.
+Characters outside the Basic Multilingual Plane take up two UTF-16 code units, so they're ignored.
+*/
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DefaultCharMapping;
+impl KeywordMapping for DefaultCharMapping {
+    fn ignore_above() -> Option<u32> {
+        Some(1)
+    }
+}
+
 /** A multi-field string mapping. */
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct KeywordFieldMapping {
     /**
     The analyzer which should be used for analyzed string fields,
@@ -215,6 +289,12 @@ pub struct KeywordFieldMapping {
     */
     pub analyzer: Option<&'static str>,
     /**
+    Copy the value of this field into one or more target fields,
+    which can then be queried as a single field.
+    Targets are the full dotted paths of fields declared on the same document.
+    */
+    pub copy_to: Option<&'static [&'static str]>,
+    /**
     Should the field be stored on disk in a column-stride fashion,
     so that it can later be used for sorting, aggregations, or scripting?
     Accepts `true` (default) or `false`.
@@ -236,12 +316,18 @@ pub struct KeywordFieldMapping {
     /**
     The maximum number of characters to index.
     Any characters over this length will be ignored.
+    This can't be more than `MAX_IGNORE_ABOVE`.
     */
     pub ignore_above: Option<u32>,
     /** Should the field be searchable? Accepts `true` (default) or `false`. */
     pub index: Option<bool>,
     /** What information should be stored in the index, for search and highlighting purposes. Defaults to `Positions`. */
     pub index_options: Option<IndexOptions>,
+    /**
+    The name of the normalizer to apply to values before they're indexed and queried.
+    The normalizer is defined in the analysis settings of the index.
+    */
+    pub normalizer: Option<&'static str>,
     /** Whether field-length should be taken into account when scoring queries. Accepts `true` (default) or `false`. */
     pub norms: Option<bool>,
     /**
@@ -261,22 +347,37 @@ pub struct KeywordFieldMapping {
     pub similarity: Option<&'static str>,
 }
 
+impl KeywordFieldMapping {
+    /**
+    Check this mapping can be used by Elasticsearch.
+
+    The `ignore_above` parameter can't be more than `MAX_IGNORE_ABOVE`.
+    */
+    pub fn validate(&self) -> Result<(), MappingError> {
+        validate_ignore_above(self.ignore_above)
+    }
+}
+
 impl Serialize for KeywordFieldMapping {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("mapping", 12)?;
+        self.validate().map_err(S::Error::custom)?;
+
+        let mut state = serializer.serialize_struct("mapping", 14)?;
 
         state.serialize_field("type", DefaultKeywordMapping::data_type())?;
 
         ser_field!(state, "analyzer", self.analyzer);
+        ser_field!(state, "copy_to", self.copy_to);
         ser_field!(state, "doc_values", self.doc_values);
         ser_field!(state, "eager_global_ordinals", self.eager_global_ordinals);
         ser_field!(state, "include_in_all", self.include_in_all);
         ser_field!(state, "ignore_above", self.ignore_above);
         ser_field!(state, "index", self.index);
         ser_field!(state, "index_options", self.index_options);
+        ser_field!(state, "normalizer", self.normalizer);
         ser_field!(state, "norms", self.norms);
         ser_field!(state, "store", self.store);
         ser_field!(state, "search_analyzer", self.search_analyzer);
@@ -288,6 +389,7 @@ impl Serialize for KeywordFieldMapping {
 
 mod private {
     use super::{
+        validate_ignore_above,
         KeywordFieldType,
         KeywordMapping,
     };
@@ -298,7 +400,10 @@ mod private {
         StaticSerialize,
     };
     use serde::{
-        ser::SerializeStruct,
+        ser::{
+            Error as SerError,
+            SerializeStruct,
+        },
         Serialize,
         Serializer,
     };
@@ -332,12 +437,15 @@ mod private {
         where
             S: Serializer,
         {
-            let mut state = serializer.serialize_struct("mapping", 15)?;
+            validate_ignore_above(TMapping::ignore_above()).map_err(S::Error::custom)?;
+
+            let mut state = serializer.serialize_struct("mapping", 18)?;
 
             state.serialize_field("type", TMapping::data_type())?;
 
             ser_field!(state, "boost", TMapping::boost());
             ser_field!(state, "analyzer", TMapping::analyzer());
+            ser_field!(state, "copy_to", TMapping::copy_to());
             ser_field!(state, "doc_values", TMapping::doc_values());
             ser_field!(
                 state,
@@ -349,11 +457,17 @@ mod private {
             ser_field!(state, "ignore_above", TMapping::ignore_above());
             ser_field!(state, "index", TMapping::index());
             ser_field!(state, "index_options", TMapping::index_options());
+            ser_field!(state, "normalizer", TMapping::normalizer());
             ser_field!(state, "norms", TMapping::norms());
             ser_field!(state, "null_value", TMapping::null_value());
             ser_field!(state, "store", TMapping::store());
             ser_field!(state, "search_analyzer", TMapping::search_analyzer());
             ser_field!(state, "similarity", TMapping::similarity());
+            ser_field!(
+                state,
+                "time_series_dimension",
+                TMapping::time_series_dimension()
+            );
 
             state.end()
         }
@@ -0,0 +1,234 @@
+/*!
+Migration from the legacy Elasticsearch `string` mapping.
+
+Before Elasticsearch `5.x`, strings were mapped with a single `string` type,
+and the `index` parameter decided whether they were analysed.
+A `not_analyzed` string is the equivalent of a `keyword`, and an `analyzed` string is the equivalent of `text`.
+
+A `LegacyStringMapping` describes an old `string` field so it can be converted into a modern mapping.
+Parameters that the target type doesn't support are dropped.
+
+# Examples
+
+Convert a `not_analyzed` string into a `keyword`:
+
+```
+# use elastic::types::prelude::*;
+let legacy = LegacyStringMapping {
+    index: Some(IndexAnalysis::NotAnalyzed),
+    ignore_above: Some(256),
+    ..Default::default()
+};
+
+let keyword = KeywordFieldMapping::from(legacy);
+
+assert_eq!(Some(256), keyword.ignore_above);
+```
+
+Convert a string into either a `keyword` or `text` depending on whether it's analysed:
+
+```
+# use elastic::types::prelude::*;
+# let legacy = LegacyStringMapping::default();
+match legacy.migrate() {
+    StringField::Keyword(keyword) => { /* a `not_analyzed` string */ }
+    StringField::Text(text) => { /* an `analyzed` string */ }
+    _ => unreachable!(),
+}
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/2.4/string.html)
+*/
+
+use super::{
+    keyword::mapping::KeywordFieldMapping,
+    mapping::{
+        IndexAnalysis,
+        IndexOptions,
+        StringField,
+    },
+    text::mapping::{
+        TermVector,
+        TextFieldMapping,
+    },
+};
+
+/** The parameters of a legacy `string` field. */
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LegacyStringMapping {
+    /** The analyzer used for `analyzed` strings at index-time and search-time. */
+    pub analyzer: Option<&'static str>,
+    /** Copy the value of this field into one or more target fields. */
+    pub copy_to: Option<&'static [&'static str]>,
+    /** Whether the field is stored on disk in a column-stride fashion. Only applies to `not_analyzed` strings. */
+    pub doc_values: Option<bool>,
+    /** Whether the field value should be included in the `_all` field. */
+    pub include_in_all: Option<bool>,
+    /** The maximum number of characters to index. */
+    pub ignore_above: Option<u32>,
+    /** Whether the string is analysed. Defaults to `Analyzed`. */
+    pub index: Option<IndexAnalysis>,
+    /** What information should be stored in the index. */
+    pub index_options: Option<IndexOptions>,
+    /** Whether field-length should be taken into account when scoring queries. */
+    pub norms: Option<bool>,
+    /** The number of fake term positions inserted between each element of an array of strings. */
+    pub position_increment_gap: Option<u32>,
+    /** Whether the field value should be stored and retrievable separately from the `_source` field. */
+    pub store: Option<bool>,
+    /** The analyzer used at search time on `analyzed` strings. */
+    pub search_analyzer: Option<&'static str>,
+    /** The analyzer used at search time when a phrase is encountered. */
+    pub search_quote_analyzer: Option<&'static str>,
+    /** Which scoring algorithm or similarity should be used. */
+    pub similarity: Option<&'static str>,
+    /** Whether term vectors should be stored for an `analyzed` field. */
+    pub term_vector: Option<TermVector>,
+}
+
+impl LegacyStringMapping {
+    /** Whether the string is analysed. Strings are analysed unless `index` is `not_analyzed` or `no`. */
+    pub fn is_analyzed(&self) -> bool {
+        !matches!(
+            self.index,
+            Some(IndexAnalysis::NotAnalyzed) | Some(IndexAnalysis::No)
+        )
+    }
+
+    /**
+    Convert this string into a `keyword` if it isn't analysed, or `text` if it is.
+
+    Strings that aren't indexed (`index: no`) are converted into a `keyword` with `index: false`.
+    */
+    pub fn migrate(self) -> StringField {
+        if self.is_analyzed() {
+            StringField::Text(self.into())
+        } else {
+            StringField::Keyword(self.into())
+        }
+    }
+
+    fn index(&self) -> Option<bool> {
+        match self.index {
+            Some(IndexAnalysis::No) => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl From<LegacyStringMapping> for KeywordFieldMapping {
+    fn from(legacy: LegacyStringMapping) -> Self {
+        KeywordFieldMapping {
+            copy_to: legacy.copy_to,
+            doc_values: legacy.doc_values,
+            include_in_all: legacy.include_in_all,
+            ignore_above: legacy.ignore_above,
+            index: legacy.index(),
+            index_options: legacy.index_options,
+            norms: legacy.norms,
+            store: legacy.store,
+            similarity: legacy.similarity,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<LegacyStringMapping> for TextFieldMapping {
+    fn from(legacy: LegacyStringMapping) -> Self {
+        TextFieldMapping {
+            analyzer: legacy.analyzer,
+            copy_to: legacy.copy_to,
+            include_in_all: legacy.include_in_all,
+            ignore_above: legacy.ignore_above,
+            index: legacy.index(),
+            index_options: legacy.index_options,
+            norms: legacy.norms,
+            position_increment_gap: legacy.position_increment_gap,
+            store: legacy.store,
+            search_analyzer: legacy.search_analyzer,
+            search_quote_analyzer: legacy.search_quote_analyzer,
+            similarity: legacy.similarity,
+            term_vector: legacy.term_vector,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::prelude::*;
+
+    #[test]
+    fn not_analyzed_string_to_keyword() {
+        let legacy = LegacyStringMapping {
+            index: Some(IndexAnalysis::NotAnalyzed),
+            ignore_above: Some(256),
+            store: Some(true),
+            analyzer: Some("standard"),
+            ..Default::default()
+        };
+
+        let keyword = KeywordFieldMapping::from(legacy);
+
+        let expected = KeywordFieldMapping {
+            ignore_above: Some(256),
+            store: Some(true),
+            ..Default::default()
+        };
+
+        assert_eq!(expected, keyword);
+
+        let ser = serde_json::to_value(&legacy.migrate()).unwrap();
+
+        let expected = json!({
+            "type": "keyword",
+            "ignore_above": 256,
+            "store": true
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn analyzed_string_to_text() {
+        let legacy = LegacyStringMapping {
+            analyzer: Some("english"),
+            store: Some(true),
+            doc_values: Some(false),
+            ..Default::default()
+        };
+
+        assert!(legacy.is_analyzed());
+
+        let ser = serde_json::to_value(&legacy.migrate()).unwrap();
+
+        let expected = json!({
+            "type": "text",
+            "analyzer": "english",
+            "store": true
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn unindexed_string_to_keyword() {
+        let legacy = LegacyStringMapping {
+            index: Some(IndexAnalysis::No),
+            ..Default::default()
+        };
+
+        let ser = serde_json::to_value(&legacy.migrate()).unwrap();
+
+        let expected = json!({
+            "type": "keyword",
+            "index": false
+        });
+
+        assert_eq!(expected, ser);
+    }
+}
@@ -117,3 +117,79 @@ macro_rules! impl_string_type {
         }
     };
 }
+
+/**
+Build the multi-`fields` map for a string mapping.
+
+Each entry is a name, followed by the kind of sub field and its parameters.
+The kind of sub field is one of `keyword`, `text`, `token_count` or `completion`.
+Parameters are the fields of the matching sub field mapping, like `KeywordFieldMapping`,
+and any parameters that aren't given are left as `None`.
+
+# Examples
+
+Add `keyword` and `text` sub fields to a `text` mapping:
+
+```
+# use std::collections::BTreeMap;
+# use elastic::multi_fields;
+# use elastic::types::prelude::*;
+# #[derive(Default)]
+# struct MyStringMapping;
+# impl TextMapping for MyStringMapping {
+fn fields() -> Option<BTreeMap<&'static str, StringField>> {
+    Some(multi_fields! {
+        "keyword" => keyword(ignore_above: 256),
+        "raw" => text(analyzer: "english"),
+    })
+}
+# }
+```
+*/
+#[macro_export]
+macro_rules! multi_fields {
+    ($($name:expr => $kind:ident($($param:ident: $value:expr),* $(,)*)),* $(,)*) => {{
+        let mut fields = ::std::collections::BTreeMap::new();
+
+        $(
+            fields.insert(
+                $name,
+                $crate::multi_fields!(@field $kind { $($param: ::std::option::Option::Some($value),)* }),
+            );
+        )*
+
+        fields
+    }};
+    (@field keyword { $($params:tt)* }) => {
+        $crate::types::string::mapping::StringField::Keyword(
+            $crate::types::string::keyword::mapping::KeywordFieldMapping {
+                $($params)*
+                ..::std::default::Default::default()
+            },
+        )
+    };
+    (@field text { $($params:tt)* }) => {
+        $crate::types::string::mapping::StringField::Text(
+            $crate::types::string::text::mapping::TextFieldMapping {
+                $($params)*
+                ..::std::default::Default::default()
+            },
+        )
+    };
+    (@field token_count { $($params:tt)* }) => {
+        $crate::types::string::mapping::StringField::TokenCount(
+            $crate::types::string::mapping::ElasticTokenCountFieldMapping {
+                $($params)*
+                ..::std::default::Default::default()
+            },
+        )
+    };
+    (@field completion { $($params:tt)* }) => {
+        $crate::types::string::mapping::StringField::Completion(
+            $crate::types::string::mapping::ElasticCompletionFieldMapping {
+                $($params)*
+                ..::std::default::Default::default()
+            },
+        )
+    };
+}
@@ -7,12 +7,33 @@ use super::{
         TextMapping,
     },
 };
+use crate::types::{
+    boost::Boost,
+    error::MappingError,
+};
 use serde::{
-    ser::SerializeStruct,
+    de::{
+        Error as DeError,
+        Unexpected,
+        Visitor,
+    },
+    ser::{
+        Error as SerError,
+        SerializeStruct,
+    },
+    Deserialize,
+    Deserializer,
     Serialize,
     Serializer,
 };
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult,
+    },
+};
 
 /** Default mapping for `String`. */
 #[derive(PartialEq, Debug, Default, Clone, Copy)]
@@ -33,7 +54,7 @@ impl TextMapping for DefaultStringMapping {
 }
 
 /** The `index_options` parameter controls what information is added to the inverted index, for search and highlighting purposes. */
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum IndexOptions {
     /** Only the doc number is indexed. Can answer the question Does this term exist in this field? */
     Docs,
@@ -55,26 +76,46 @@ pub enum IndexOptions {
     Offsets,
 }
 
+impl IndexOptions {
+    /** Get the value of this option as it's sent to Elasticsearch. */
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            IndexOptions::Docs => "docs",
+            IndexOptions::Freqs => "freqs",
+            IndexOptions::Positions => "positions",
+            IndexOptions::Offsets => "offsets",
+        }
+    }
+}
+
 impl Serialize for IndexOptions {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_str(match *self {
-            IndexOptions::Docs => "docs",
-            IndexOptions::Freqs => "freqs",
-            IndexOptions::Positions => "positions",
-            IndexOptions::Offsets => "offsets",
-        })
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Display for IndexOptions {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.as_str())
     }
 }
 
+impl_str_enum_deserialize!(IndexOptions {
+    Docs,
+    Freqs,
+    Positions,
+    Offsets
+});
+
 /**
 A string sub-field type.
 
 String types can have a number of alternative field representations for different purposes.
 */
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum StringField {
     /** A `token_count` sub field. */
     TokenCount(ElasticTokenCountFieldMapping),
@@ -101,16 +142,15 @@ impl Serialize for StringField {
 }
 
 /** A multi-field string mapping for a [token count](https://www.elastic.co/guide/en/elasticsearch/reference/current/token-count.html). */
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ElasticTokenCountFieldMapping {
     /**
-    The analyzer which should be used for analyzed string fields,
-    both at index-time and at search-time (unless overridden by the `search_analyzer`).
-    Defaults to the default index analyzer, or the `standard` analyzer.
+    The analyzer which should be used to count tokens.
+    This is required by Elasticsearch, so serialising a mapping without one fails, see `validate`.
     */
     pub analyzer: Option<&'static str>,
-    /** Field-level index time boosting. Accepts a floating point number, defaults to `1.0`. */
-    pub boost: Option<f32>,
+    /** Field-level index time boosting. Accepts a positive floating point number, defaults to `1.0`. */
+    pub boost: Option<Boost>,
     /**
     Should the field be stored on disk in a column-stride fashion,
     so that it can later be used for sorting, aggregations, or scripting?
@@ -129,6 +169,7 @@ pub struct ElasticTokenCountFieldMapping {
     /**
     Controls the number of extra terms that are indexed to make range queries faster.
     Defaults to `32`.
+    This parameter is only supported by Elasticsearch `5.x`, see `for_version`.
     */
     pub precision_step: Option<u32>,
     /**
@@ -138,11 +179,45 @@ pub struct ElasticTokenCountFieldMapping {
     pub store: Option<bool>,
 }
 
+impl ElasticTokenCountFieldMapping {
+    /**
+    Adjust this mapping for a given major version of Elasticsearch.
+
+    `precision_step` was removed in Elasticsearch `6.x`, so it's cleared when targeting `6` or later.
+    */
+    pub fn for_version(self, major: u32) -> Self {
+        if major >= 6 {
+            ElasticTokenCountFieldMapping {
+                precision_step: None,
+                ..self
+            }
+        } else {
+            self
+        }
+    }
+
+    /**
+    Check this mapping can be used by Elasticsearch.
+
+    A `token_count` field needs an `analyzer` to count tokens with.
+    */
+    pub fn validate(&self) -> Result<(), MappingError> {
+        match self.analyzer {
+            Some(_) => Ok(()),
+            None => Err(MappingError::MissingAnalyzer {
+                data_type: "token_count",
+            }),
+        }
+    }
+}
+
 impl Serialize for ElasticTokenCountFieldMapping {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
+        self.validate().map_err(S::Error::custom)?;
+
         let mut state = serializer.serialize_struct("mapping", 8)?;
 
         state.serialize_field("type", "token_count")?;
@@ -160,7 +235,7 @@ impl Serialize for ElasticTokenCountFieldMapping {
 }
 
 /** A multi-field string mapping for a [completion suggester](https://www.elastic.co/guide/en/elasticsearch/reference/current/search-suggesters-completion.html#search-suggesters-completion). */
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ElasticCompletionFieldMapping {
     /**
     The analyzer which should be used for analyzed string fields,
@@ -176,6 +251,7 @@ pub struct ElasticCompletionFieldMapping {
     Preserves the separators, defaults to `true`.
     If disabled, you could find a field starting with Foo Fighters,
     if you suggest for foof.
+    These defaults aren't serialised unless they're set, use `resolved` to get the effective values.
     */
     pub preserve_separators: Option<bool>,
     /**
@@ -184,6 +260,8 @@ pub struct ElasticCompletionFieldMapping {
     you could get a field starting with The Beatles, if you suggest for b.
     > Note: You could also achieve this by indexing two inputs, Beatles and The Beatles,
     no need to change a simple analyzer, if you are able to enrich your data.
+
+    Disabling this only has an effect with an analyzer that removes tokens, like a stop word filter.
     */
     pub preserve_position_increments: Option<bool>,
     /**
@@ -197,6 +275,91 @@ pub struct ElasticCompletionFieldMapping {
     pub max_input_length: Option<u32>,
 }
 
+/**
+A builder for an `ElasticCompletionFieldMapping`.
+
+The builder checks the mapping is valid before returning it.
+If no `search_analyzer` is given then it defaults to the `analyzer`.
+
+# Examples
+
+```
+# use elastic::types::prelude::*;
+let mapping = CompletionFieldMappingBuilder::new()
+    .analyzer("simple")
+    .max_input_length(20)
+    .build()
+    .unwrap();
+
+assert_eq!(Some("simple"), mapping.search_analyzer);
+```
+*/
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CompletionFieldMappingBuilder {
+    mapping: ElasticCompletionFieldMapping,
+}
+
+impl CompletionFieldMappingBuilder {
+    /** Create a new `CompletionFieldMappingBuilder` with no parameters set. */
+    pub fn new() -> Self {
+        CompletionFieldMappingBuilder::default()
+    }
+
+    /** Specify the analyzer used at index-time, and at search-time unless a `search_analyzer` is given. */
+    pub fn analyzer(mut self, analyzer: &'static str) -> Self {
+        self.mapping.analyzer = Some(analyzer);
+        self
+    }
+
+    /** Specify the analyzer used at search-time. */
+    pub fn search_analyzer(mut self, search_analyzer: &'static str) -> Self {
+        self.mapping.search_analyzer = Some(search_analyzer);
+        self
+    }
+
+    /** Specify whether payloads are stored. */
+    pub fn payloads(mut self, payloads: bool) -> Self {
+        self.mapping.payloads = Some(payloads);
+        self
+    }
+
+    /** Specify whether separators are preserved. */
+    pub fn preserve_separators(mut self, preserve_separators: bool) -> Self {
+        self.mapping.preserve_separators = Some(preserve_separators);
+        self
+    }
+
+    /** Specify whether position increments are enabled. */
+    pub fn preserve_position_increments(mut self, preserve_position_increments: bool) -> Self {
+        self.mapping.preserve_position_increments = Some(preserve_position_increments);
+        self
+    }
+
+    /** Specify the maximum length of a single input. Must be greater than `0`. */
+    pub fn max_input_length(mut self, max_input_length: u32) -> Self {
+        self.mapping.max_input_length = Some(max_input_length);
+        self
+    }
+
+    /** Validate the parameters and build a completion mapping. */
+    pub fn build(self) -> Result<ElasticCompletionFieldMapping, MappingError> {
+        let mut mapping = self.mapping;
+
+        if mapping.max_input_length == Some(0) {
+            return Err(MappingError::InvalidParameter {
+                name: "max_input_length",
+                reason: "must be greater than 0".to_owned(),
+            });
+        }
+
+        if mapping.search_analyzer.is_none() {
+            mapping.search_analyzer = mapping.analyzer;
+        }
+
+        Ok(mapping)
+    }
+}
+
 impl Serialize for ElasticCompletionFieldMapping {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -221,8 +384,55 @@ impl Serialize for ElasticCompletionFieldMapping {
     }
 }
 
-/** Should the field be searchable? Accepts `not_analyzed` (default) and `no`. */
-#[derive(Debug, Clone, Copy)]
+impl ElasticCompletionFieldMapping {
+    /**
+    Get the effective parameters of this mapping, with the defaults Elasticsearch applies filled in.
+
+    This is for validation and tooling, the mapping itself is still serialised with only the parameters that are set.
+
+    # Examples
+
+    ```
+    # use elastic::types::prelude::*;
+    let mapping = ElasticCompletionFieldMapping {
+        analyzer: Some("simple"),
+        ..Default::default()
+    };
+
+    assert_eq!(Some("simple"), mapping.resolved().search_analyzer);
+    assert!(mapping.resolved().preserve_separators);
+    ```
+    */
+    pub fn resolved(&self) -> ResolvedCompletionFieldMapping {
+        ResolvedCompletionFieldMapping {
+            analyzer: self.analyzer,
+            search_analyzer: self.search_analyzer.or(self.analyzer),
+            preserve_separators: self.preserve_separators.unwrap_or(true),
+            preserve_position_increments: self.preserve_position_increments.unwrap_or(true),
+        }
+    }
+}
+
+/** The effective parameters of an `ElasticCompletionFieldMapping`, produced by `ElasticCompletionFieldMapping::resolved`. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ResolvedCompletionFieldMapping {
+    /** The analyzer used at index-time. `None` means the default index analyzer is used. */
+    pub analyzer: Option<&'static str>,
+    /** The analyzer used at search-time. Defaults to the `analyzer`. */
+    pub search_analyzer: Option<&'static str>,
+    /** Whether separators are preserved. Defaults to `true`. */
+    pub preserve_separators: bool,
+    /** Whether position increments are preserved. Defaults to `true`. */
+    pub preserve_position_increments: bool,
+}
+
+/**
+Should the field be searchable? Accepts `not_analyzed` (default) and `no`.
+
+Newer versions of Elasticsearch use a boolean for `index` instead.
+When deserialising, `true` is read as `analyzed` and `false` as `no`.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum IndexAnalysis {
     /**
     This option applies only to string fields, for which it is the default.
@@ -243,25 +453,97 @@ pub enum IndexAnalysis {
     No,
 }
 
+impl IndexAnalysis {
+    /** Get the value of this option as it's sent to Elasticsearch. */
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            IndexAnalysis::Analyzed => "analyzed",
+            IndexAnalysis::NotAnalyzed => "not_analyzed",
+            IndexAnalysis::No => "no",
+        }
+    }
+}
+
 impl Serialize for IndexAnalysis {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_str(match *self {
-            IndexAnalysis::Analyzed => "analyzed",
-            IndexAnalysis::NotAnalyzed => "not_analyzed",
-            IndexAnalysis::No => "no",
-        })
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Display for IndexAnalysis {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for IndexAnalysis {
+    fn deserialize<D>(deserializer: D) -> Result<IndexAnalysis, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct IndexAnalysisVisitor;
+
+        impl<'de> Visitor<'de> for IndexAnalysisVisitor {
+            type Value = IndexAnalysis;
+
+            fn expecting(&self, formatter: &mut Formatter) -> FmtResult {
+                write!(
+                    formatter,
+                    "one of `analyzed`, `not_analyzed`, `no`, or a boolean"
+                )
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<IndexAnalysis, E>
+            where
+                E: DeError,
+            {
+                [
+                    IndexAnalysis::Analyzed,
+                    IndexAnalysis::NotAnalyzed,
+                    IndexAnalysis::No,
+                ]
+                .iter()
+                .find(|variant| variant.as_str().eq_ignore_ascii_case(v))
+                .cloned()
+                .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+            }
+
+            fn visit_bool<E>(self, v: bool) -> Result<IndexAnalysis, E>
+            where
+                E: DeError,
+            {
+                Ok(if v {
+                    IndexAnalysis::Analyzed
+                } else {
+                    IndexAnalysis::No
+                })
+            }
+        }
+
+        deserializer.deserialize_any(IndexAnalysisVisitor)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use serde_json;
-    use std::collections::BTreeMap;
+    use serde_test::{
+        assert_ser_tokens,
+        Token,
+    };
+    use std::{
+        borrow::Cow,
+        collections::{
+            BTreeMap,
+            HashSet,
+        },
+    };
 
     use crate::types::{
+        error::MappingError,
         prelude::*,
         private::field,
     };
@@ -282,7 +564,10 @@ mod tests {
 
             fields.insert(
                 "count",
-                StringField::TokenCount(ElasticTokenCountFieldMapping::default()),
+                StringField::TokenCount(ElasticTokenCountFieldMapping {
+                    analyzer: Some("standard"),
+                    ..Default::default()
+                }),
             );
 
             fields.insert(
@@ -373,7 +658,10 @@ mod tests {
 
             fields.insert(
                 "count",
-                StringField::TokenCount(ElasticTokenCountFieldMapping::default()),
+                StringField::TokenCount(ElasticTokenCountFieldMapping {
+                    analyzer: Some("standard"),
+                    ..Default::default()
+                }),
             );
 
             fields.insert(
@@ -478,7 +766,8 @@ mod tests {
                     "type":"completion"
                 },
                 "count":{
-                    "type":"token_count"
+                    "type":"token_count",
+                    "analyzer":"standard"
                 },
                 "raw":{
                     "type":"keyword",
@@ -526,7 +815,8 @@ mod tests {
                     "type": "completion"
                 },
                 "count": {
-                    "type": "token_count"
+                    "type": "token_count",
+                    "analyzer": "standard"
                 },
                 "text": {
                     "type": "text",
@@ -597,6 +887,8 @@ mod tests {
             TermVector::WithPositions,
             TermVector::WithOffsets,
             TermVector::WithPositionsOffsets,
+            TermVector::WithPositionsPayloads,
+            TermVector::WithPositionsOffsetsPayloads,
         ]
         .iter()
         .map(|i| serde_json::to_string(i).unwrap())
@@ -608,6 +900,8 @@ mod tests {
             r#""with_positions""#,
             r#""with_offsets""#,
             r#""with_positions_offsets""#,
+            r#""with_positions_payloads""#,
+            r#""with_positions_offsets_payloads""#,
         ];
 
         let mut success = true;
@@ -625,12 +919,14 @@ mod tests {
     fn serialise_mapping_keyword_field() {
         let mapping = StringField::Keyword(KeywordFieldMapping {
             analyzer: Some("my_analyzer"),
+            copy_to: Some(&["my_other_field"]),
             doc_values: Some(true),
             eager_global_ordinals: Some(false),
             include_in_all: Some(true),
             ignore_above: Some(256),
             index: Some(false),
             index_options: Some(IndexOptions::Docs),
+            normalizer: Some("my_normalizer"),
             norms: Some(true),
             store: Some(true),
             search_analyzer: Some("my_analyzer"),
@@ -641,12 +937,14 @@ mod tests {
         let expected = json!({
             "type":"keyword",
             "analyzer":"my_analyzer",
+            "copy_to":["my_other_field"],
             "doc_values":true,
             "eager_global_ordinals":false,
             "include_in_all":true,
             "ignore_above":256,
             "index":false,
             "index_options":"docs",
+            "normalizer":"my_normalizer",
             "norms":true,
             "store":true,
             "search_analyzer":"my_analyzer",
@@ -664,6 +962,7 @@ mod tests {
                 ..Default::default()
             }),
             analyzer: Some("my_analyzer"),
+            copy_to: None,
             eager_global_ordinals: Some(true),
             fielddata: Some(false),
             include_in_all: Some(false),
@@ -754,4 +1053,879 @@ mod tests {
 
         assert_eq!(expected, ser);
     }
+
+    #[test]
+    fn equal_field_mappings_dedup_in_hash_set() {
+        let mut set = HashSet::new();
+
+        set.insert(StringField::TokenCount(ElasticTokenCountFieldMapping {
+            analyzer: Some("my_analyzer"),
+            boost: Some(Boost::new(2.0).unwrap()),
+            ..Default::default()
+        }));
+        set.insert(StringField::TokenCount(ElasticTokenCountFieldMapping {
+            analyzer: Some("my_analyzer"),
+            boost: Some(Boost::new(2.0).unwrap()),
+            ..Default::default()
+        }));
+        set.insert(StringField::Text(TextFieldMapping {
+            fielddata_frequency_filter: Some(FieldDataFrequencyFilter {
+                min: Some(0.1),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }));
+        set.insert(StringField::Text(TextFieldMapping {
+            fielddata_frequency_filter: Some(FieldDataFrequencyFilter {
+                min: Some(0.1),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }));
+
+        // `0.0` and `-0.0` are equal, so they need to hash the same way
+        set.insert(StringField::Text(TextFieldMapping {
+            fielddata_frequency_filter: Some(FieldDataFrequencyFilter {
+                min: Some(0.0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }));
+        set.insert(StringField::Text(TextFieldMapping {
+            fielddata_frequency_filter: Some(FieldDataFrequencyFilter {
+                min: Some(-0.0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }));
+
+        assert_eq!(3, set.len());
+    }
+
+    #[test]
+    fn token_count_precision_step_is_suppressed_for_6() {
+        let mapping = ElasticTokenCountFieldMapping {
+            analyzer: Some("standard"),
+            precision_step: Some(15),
+            ..Default::default()
+        }
+        .for_version(6);
+        let ser = serde_json::to_value(&mapping).unwrap();
+
+        let expected = json!({
+            "type": "token_count",
+            "analyzer": "standard"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn token_count_precision_step_is_retained_for_5() {
+        let mapping = ElasticTokenCountFieldMapping {
+            analyzer: Some("standard"),
+            precision_step: Some(15),
+            ..Default::default()
+        }
+        .for_version(5);
+        let ser = serde_json::to_value(&mapping).unwrap();
+
+        let expected = json!({
+            "type": "token_count",
+            "analyzer": "standard",
+            "precision_step": 15
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn token_count_without_analyzer_is_invalid() {
+        let mapping = ElasticTokenCountFieldMapping::default();
+
+        assert_eq!(
+            MappingError::MissingAnalyzer {
+                data_type: "token_count"
+            },
+            mapping.validate().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn serialise_token_count_without_analyzer_fails() {
+        let err = serde_json::to_value(&ElasticTokenCountFieldMapping::default()).unwrap_err();
+
+        assert_eq!(
+            MappingError::MissingAnalyzer {
+                data_type: "token_count"
+            }
+            .to_string(),
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn token_count_with_analyzer_is_valid() {
+        let mapping = ElasticTokenCountFieldMapping {
+            analyzer: Some("standard"),
+            ..Default::default()
+        };
+
+        assert!(mapping.validate().is_ok());
+    }
+
+    #[test]
+    fn deserialise_index_options() {
+        let options: Vec<IndexOptions> =
+            serde_json::from_str(r#"["docs","freqs","positions","offsets"]"#).unwrap();
+
+        assert_eq!(
+            vec![
+                IndexOptions::Docs,
+                IndexOptions::Freqs,
+                IndexOptions::Positions,
+                IndexOptions::Offsets,
+            ],
+            options
+        );
+    }
+
+    #[test]
+    fn deserialise_index_options_ignores_case() {
+        let options: Vec<IndexOptions> = serde_json::from_str(r#"["Offsets","OFFSETS"]"#).unwrap();
+
+        assert_eq!(vec![IndexOptions::Offsets, IndexOptions::Offsets], options);
+        assert_eq!(r#""offsets""#, serde_json::to_string(&options[1]).unwrap());
+    }
+
+    #[test]
+    fn deserialise_index_options_unknown() {
+        let err = serde_json::from_str::<IndexOptions>(r#""bogus""#).unwrap_err();
+
+        assert!(err.to_string().contains("bogus"));
+    }
+
+    #[test]
+    fn display_index_options() {
+        assert_eq!("offsets", IndexOptions::Offsets.to_string());
+        assert_eq!(
+            serde_json::to_string(&IndexOptions::Docs).unwrap(),
+            format!("\"{}\"", IndexOptions::Docs)
+        );
+    }
+
+    #[test]
+    fn deserialise_index_analysis() {
+        for analysis in &[
+            IndexAnalysis::Analyzed,
+            IndexAnalysis::NotAnalyzed,
+            IndexAnalysis::No,
+        ] {
+            let ser = serde_json::to_string(analysis).unwrap();
+            let de: IndexAnalysis = serde_json::from_str(&ser).unwrap();
+
+            assert_eq!(*analysis, de);
+        }
+    }
+
+    #[test]
+    fn deserialise_index_analysis_from_bool() {
+        let analysis: Vec<IndexAnalysis> = serde_json::from_str("[true, false]").unwrap();
+
+        assert_eq!(vec![IndexAnalysis::Analyzed, IndexAnalysis::No], analysis);
+    }
+
+    #[test]
+    fn deserialise_index_analysis_unknown() {
+        let err = serde_json::from_str::<IndexAnalysis>(r#""bogus""#).unwrap_err();
+
+        assert!(err.to_string().contains("bogus"));
+        assert!(serde_json::from_str::<IndexAnalysis>("1").is_err());
+    }
+
+    #[test]
+    fn display_index_analysis() {
+        assert_eq!("not_analyzed", IndexAnalysis::NotAnalyzed.to_string());
+    }
+
+    #[test]
+    fn deserialise_term_vector() {
+        let term_vector: TermVector =
+            serde_json::from_str(r#""with_positions_offsets_payloads""#).unwrap();
+
+        assert_eq!(TermVector::WithPositionsOffsetsPayloads, term_vector);
+
+        for term_vector in &[
+            TermVector::No,
+            TermVector::Yes,
+            TermVector::WithPositions,
+            TermVector::WithOffsets,
+            TermVector::WithPositionsOffsets,
+            TermVector::WithPositionsPayloads,
+            TermVector::WithPositionsOffsetsPayloads,
+        ] {
+            let ser = serde_json::to_string(term_vector).unwrap();
+            let de: TermVector = serde_json::from_str(&ser).unwrap();
+
+            assert_eq!(*term_vector, de);
+        }
+    }
+
+    #[test]
+    fn deserialise_term_vector_unknown() {
+        let err = serde_json::from_str::<TermVector>(r#""with_payloads""#).unwrap_err();
+
+        assert!(err.to_string().contains("with_payloads"));
+    }
+
+    #[test]
+    fn display_term_vector() {
+        assert_eq!(
+            "with_positions_offsets",
+            TermVector::WithPositionsOffsets.to_string()
+        );
+    }
+
+    #[test]
+    fn completion_builder_search_analyzer_defaults_to_analyzer() {
+        let mapping = CompletionFieldMappingBuilder::new()
+            .analyzer("simple")
+            .build()
+            .unwrap();
+
+        assert_eq!(Some("simple"), mapping.search_analyzer);
+
+        let mapping = CompletionFieldMappingBuilder::new()
+            .analyzer("simple")
+            .search_analyzer("standard")
+            .build()
+            .unwrap();
+
+        assert_eq!(Some("standard"), mapping.search_analyzer);
+    }
+
+    #[test]
+    fn completion_resolved_search_analyzer_defaults_to_analyzer() {
+        let mapping = ElasticCompletionFieldMapping {
+            analyzer: Some("simple"),
+            ..Default::default()
+        };
+
+        let resolved = mapping.resolved();
+
+        assert_eq!(resolved.analyzer, resolved.search_analyzer);
+        assert_eq!(Some("simple"), resolved.search_analyzer);
+
+        // The serialised mapping still only has the parameters that are set
+        let ser = serde_json::to_value(&mapping).unwrap();
+
+        assert_eq!(json!({ "type": "completion", "analyzer": "simple" }), ser);
+
+        let mapping = ElasticCompletionFieldMapping {
+            analyzer: Some("simple"),
+            search_analyzer: Some("standard"),
+            ..Default::default()
+        };
+
+        assert_eq!(Some("standard"), mapping.resolved().search_analyzer);
+    }
+
+    #[test]
+    fn completion_resolved_preserve_defaults_to_true() {
+        let mapping = ElasticCompletionFieldMapping::default();
+
+        let resolved = mapping.resolved();
+
+        assert!(resolved.preserve_separators);
+        assert!(resolved.preserve_position_increments);
+
+        let ser = serde_json::to_value(&mapping).unwrap();
+
+        assert_eq!(json!({ "type": "completion" }), ser);
+
+        let mapping = ElasticCompletionFieldMapping {
+            preserve_separators: Some(false),
+            ..Default::default()
+        };
+
+        let resolved = mapping.resolved();
+
+        assert!(!resolved.preserve_separators);
+        assert!(resolved.preserve_position_increments);
+    }
+
+    #[test]
+    fn completion_builder_rejects_zero_max_input_length() {
+        let err = CompletionFieldMappingBuilder::new()
+            .max_input_length(0)
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            MappingError::InvalidParameter {
+                name: "max_input_length",
+                reason: "must be greater than 0".to_owned(),
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn text_mappings_never_serialise_doc_values() {
+        let ser = serde_json::to_value(&field::serialize(MyTextMapping)).unwrap();
+
+        assert!(ser.get("doc_values").is_none());
+
+        let ser = serde_json::to_value(&StringField::Text(TextFieldMapping {
+            fielddata: Some(true),
+            ..Default::default()
+        }))
+        .unwrap();
+
+        assert_eq!(json!({ "type": "text", "fielddata": true }), ser);
+    }
+
+    #[test]
+    fn text_mappings_never_serialise_null_value() {
+        let ser = serde_json::to_value(&field::serialize(MyTextMapping)).unwrap();
+
+        assert!(ser.get("null_value").is_none());
+
+        let ser = serde_json::to_value(&StringField::Text(TextFieldMapping {
+            analyzer: Some("my_analyzer"),
+            index: Some(false),
+            ..Default::default()
+        }))
+        .unwrap();
+
+        assert!(ser.get("null_value").is_none());
+    }
+
+    #[test]
+    fn serialise_text_field_with_fielddata() {
+        let mapping = StringField::Text(TextFieldMapping {
+            fielddata: Some(true),
+            fielddata_frequency_filter: Some(FieldDataFrequencyFilter {
+                min: Some(0.001),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        let ser = serde_json::to_value(&mapping).unwrap();
+
+        assert_eq!(Some(&json!(true)), ser.get("fielddata"));
+        assert!(ser.get("fielddata_frequency_filter").is_some());
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct CharType {
+        pub initial: char,
+    }
+
+    #[test]
+    fn serialise_char_as_keyword() {
+        let ser = serde_json::to_value(&CharType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "initial": {
+                    "type": "keyword",
+                    "ignore_above": 1
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+
+        let ser = serde_json::to_string(&CharType { initial: 'a' }).unwrap();
+
+        assert_eq!(r#"{"initial":"a"}"#, ser);
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct DefaultStringType {
+        pub title: String,
+    }
+
+    #[cfg(not(feature = "string-as-keyword"))]
+    #[test]
+    fn serialise_string_as_text() {
+        let ser = serde_json::to_value(&DefaultStringType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "title": {
+                    "type": "text",
+                    "fields": {
+                        "keyword": {
+                            "type": "keyword",
+                            "ignore_above": 256
+                        }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[cfg(feature = "string-as-keyword")]
+    #[test]
+    fn serialise_string_as_keyword() {
+        let ser = serde_json::to_value(&DefaultStringType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "title": {
+                    "type": "keyword"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct CowStringType {
+        pub title: Cow<'static, str>,
+    }
+
+    #[test]
+    fn serialise_cow_str_as_string() {
+        let ser = serde_json::to_value(&CowStringType::index_mapping()).unwrap();
+        let string_ser = serde_json::to_value(&DefaultStringType::index_mapping()).unwrap();
+
+        assert_eq!(string_ser, ser);
+
+        let doc = CowStringType {
+            title: Cow::Borrowed("borrowed"),
+        };
+
+        assert_eq!(
+            r#"{"title":"borrowed"}"#,
+            serde_json::to_string(&doc).unwrap()
+        );
+    }
+
+    #[cfg(feature = "uuid-types")]
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct UuidType {
+        pub id: uuid::Uuid,
+    }
+
+    #[cfg(feature = "uuid-types")]
+    #[test]
+    fn serialise_uuid_as_keyword() {
+        let ser = serde_json::to_value(&UuidType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "id": {
+                    "type": "keyword"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_text_field_with_fields() {
+        let mapping = TextFieldMapping {
+            analyzer: Some("english"),
+            ..Default::default()
+        }
+        .with_fields(multi_fields! {
+            "keyword" => keyword(ignore_above: 256),
+        });
+
+        let ser = serde_json::to_value(&mapping).unwrap();
+
+        let expected = json!({
+            "type": "text",
+            "analyzer": "english",
+            "fields": {
+                "keyword": {
+                    "type": "keyword",
+                    "ignore_above": 256
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[derive(Default, Clone)]
+    pub struct UnorderedFieldsMapping;
+    impl TextMapping for UnorderedFieldsMapping {
+        fn fields() -> Option<BTreeMap<&'static str, StringField>> {
+            let mut fields = BTreeMap::new();
+
+            fields.insert("raw", StringField::Keyword(KeywordFieldMapping::default()));
+            fields.insert(
+                "keyword",
+                StringField::Keyword(KeywordFieldMapping {
+                    ignore_above: Some(256),
+                    ..Default::default()
+                }),
+            );
+
+            Some(fields)
+        }
+    }
+
+    #[test]
+    fn serialise_fields_in_alphabetical_order() {
+        let expected = r#"{"type":"text","fields":{"keyword":{"type":"keyword","ignore_above":256},"raw":{"type":"keyword"}}}"#;
+
+        let ser = serde_json::to_string(&field::serialize(UnorderedFieldsMapping)).unwrap();
+
+        assert_eq!(expected, ser);
+
+        // The order the fields are inserted in doesn't change the output
+        let inserted_first = TextFieldMapping::default().with_fields(multi_fields! {
+            "keyword" => keyword(ignore_above: 256),
+            "raw" => keyword(),
+        });
+        let inserted_last = TextFieldMapping::default().with_fields(multi_fields! {
+            "raw" => keyword(),
+            "keyword" => keyword(ignore_above: 256),
+        });
+
+        assert_eq!(expected, serde_json::to_string(&inserted_first).unwrap());
+        assert_eq!(expected, serde_json::to_string(&inserted_last).unwrap());
+    }
+
+    #[derive(Default, Clone)]
+    pub struct OversizedKeywordMapping;
+    impl KeywordMapping for OversizedKeywordMapping {
+        fn ignore_above() -> Option<u32> {
+            Some(u32::max_value())
+        }
+    }
+
+    #[test]
+    fn keyword_ignore_above_within_limit_is_valid() {
+        let mapping = KeywordFieldMapping {
+            ignore_above: Some(MAX_IGNORE_ABOVE),
+            ..Default::default()
+        };
+
+        assert!(mapping.validate().is_ok());
+    }
+
+    #[test]
+    fn keyword_ignore_above_over_limit_is_invalid() {
+        let mapping = KeywordFieldMapping {
+            ignore_above: Some(1_000_000),
+            ..Default::default()
+        };
+
+        assert!(mapping.validate().is_err());
+        assert!(serde_json::to_value(&StringField::Keyword(mapping)).is_err());
+        assert!(serde_json::to_value(&field::serialize(OversizedKeywordMapping)).is_err());
+    }
+
+    #[derive(Default, Clone)]
+    pub struct MyDimensionKeywordMapping;
+    impl KeywordMapping for MyDimensionKeywordMapping {
+        fn time_series_dimension() -> Option<bool> {
+            Some(true)
+        }
+    }
+
+    #[test]
+    fn serialise_keyword_mapping_time_series_dimension() {
+        let ser = serde_json::to_value(&field::serialize(MyDimensionKeywordMapping)).unwrap();
+
+        let expected = json!({
+            "type": "keyword",
+            "time_series_dimension": true
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[derive(Default, Clone)]
+    pub struct MySearchAsYouTypeMapping;
+    impl SearchAsYouTypeMapping for MySearchAsYouTypeMapping {
+        fn analyzer() -> Option<&'static str> {
+            Some("my_analyzer")
+        }
+
+        fn max_shingle_size() -> Option<u32> {
+            Some(4)
+        }
+
+        fn term_vector() -> Option<TermVector> {
+            Some(TermVector::WithPositionsOffsets)
+        }
+    }
+
+    #[test]
+    fn serialise_search_as_you_type_mapping_default() {
+        let ser = serde_json::to_value(&field::serialize(DefaultSearchAsYouTypeMapping)).unwrap();
+
+        let expected = json!({
+            "type": "search_as_you_type"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_search_as_you_type_mapping_custom() {
+        let ser = serde_json::to_value(&field::serialize(MySearchAsYouTypeMapping)).unwrap();
+
+        // Sub-fields inherit these from the root field, so no sub-field entries are mapped
+        let expected = json!({
+            "type": "search_as_you_type",
+            "analyzer": "my_analyzer",
+            "max_shingle_size": 4,
+            "term_vector": "with_positions_offsets"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[derive(Default, Clone)]
+    pub struct MyAnalyzedSearchAsYouTypeMapping;
+    impl SearchAsYouTypeMapping for MyAnalyzedSearchAsYouTypeMapping {
+        fn analyzer() -> Option<&'static str> {
+            Some("my_analyzer")
+        }
+
+        fn search_analyzer() -> Option<&'static str> {
+            Some("my_search_analyzer")
+        }
+
+        fn position_increment_gap() -> Option<u32> {
+            Some(50)
+        }
+    }
+
+    #[test]
+    fn serialise_search_as_you_type_mapping_analyzers() {
+        let ser =
+            serde_json::to_value(&field::serialize(MyAnalyzedSearchAsYouTypeMapping)).unwrap();
+
+        let expected = json!({
+            "type": "search_as_you_type",
+            "analyzer": "my_analyzer",
+            "search_analyzer": "my_search_analyzer",
+            "position_increment_gap": 50
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn search_as_you_type_sub_fields_follow_max_shingle_size() {
+        assert_eq!(
+            vec!["_2gram", "_3gram", "_index_prefix"],
+            search_as_you_type_sub_fields::<DefaultSearchAsYouTypeMapping>()
+        );
+        assert_eq!(
+            vec!["_2gram", "_3gram", "_4gram", "_index_prefix"],
+            search_as_you_type_sub_fields::<MySearchAsYouTypeMapping>()
+        );
+    }
+
+    #[derive(Default, Clone)]
+    pub struct MyMatchOnlyTextMapping;
+    impl MatchOnlyTextMapping for MyMatchOnlyTextMapping {
+        fn fields() -> Option<BTreeMap<&'static str, StringField>> {
+            let mut fields = BTreeMap::new();
+
+            fields.insert(
+                "raw",
+                StringField::Keyword(KeywordFieldMapping {
+                    ignore_above: Some(256),
+                    ..Default::default()
+                }),
+            );
+
+            Some(fields)
+        }
+
+        fn meta() -> Option<BTreeMap<&'static str, &'static str>> {
+            let mut meta = BTreeMap::new();
+            meta.insert("source", "nginx");
+
+            Some(meta)
+        }
+    }
+
+    #[test]
+    fn serialise_match_only_text_mapping_default() {
+        let ser = serde_json::to_value(&field::serialize(DefaultMatchOnlyTextMapping)).unwrap();
+
+        let expected = json!({
+            "type": "match_only_text"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_match_only_text_mapping_custom() {
+        let ser = serde_json::to_value(&field::serialize(MyMatchOnlyTextMapping)).unwrap();
+
+        let expected = json!({
+            "type": "match_only_text",
+            "fields": {
+                "raw": {
+                    "type": "keyword",
+                    "ignore_above": 256
+                }
+            },
+            "meta": {
+                "source": "nginx"
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn multi_fields_macro() {
+        let fields = multi_fields! {
+            "keyword" => keyword(ignore_above: 256),
+            "raw" => text(analyzer: "english", norms: false),
+            "count" => token_count(analyzer: "standard"),
+            "comp" => completion(),
+        };
+
+        let mut expected = BTreeMap::new();
+        expected.insert(
+            "keyword",
+            StringField::Keyword(KeywordFieldMapping {
+                ignore_above: Some(256),
+                ..Default::default()
+            }),
+        );
+        expected.insert(
+            "raw",
+            StringField::Text(TextFieldMapping {
+                analyzer: Some("english"),
+                norms: Some(false),
+                ..Default::default()
+            }),
+        );
+        expected.insert(
+            "count",
+            StringField::TokenCount(ElasticTokenCountFieldMapping {
+                analyzer: Some("standard"),
+                ..Default::default()
+            }),
+        );
+        expected.insert(
+            "comp",
+            StringField::Completion(ElasticCompletionFieldMapping::default()),
+        );
+
+        assert_eq!(expected, fields);
+    }
+
+    #[test]
+    fn keyword_field_tokens() {
+        let mapping = KeywordFieldMapping {
+            doc_values: Some(true),
+            ignore_above: Some(256),
+            ..Default::default()
+        };
+
+        assert_ser_tokens(
+            &mapping,
+            &[
+                Token::Struct {
+                    name: "mapping",
+                    len: 14,
+                },
+                Token::Str("type"),
+                Token::Str("keyword"),
+                Token::Str("doc_values"),
+                Token::Bool(true),
+                Token::Str("ignore_above"),
+                Token::U32(256),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn text_field_tokens() {
+        let mapping = TextFieldMapping {
+            analyzer: Some("my_analyzer"),
+            term_vector: Some(TermVector::WithOffsets),
+            ..Default::default()
+        };
+
+        assert_ser_tokens(
+            &mapping,
+            &[
+                Token::Struct {
+                    name: "mapping",
+                    len: 17,
+                },
+                Token::Str("type"),
+                Token::Str("text"),
+                Token::Str("analyzer"),
+                Token::Str("my_analyzer"),
+                Token::Str("term_vector"),
+                Token::Str("with_offsets"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn token_count_field_tokens() {
+        let mapping = ElasticTokenCountFieldMapping {
+            analyzer: Some("standard"),
+            boost: Some(Boost::new(2.0).unwrap()),
+            store: Some(false),
+            ..Default::default()
+        };
+
+        assert_ser_tokens(
+            &mapping,
+            &[
+                Token::Struct {
+                    name: "mapping",
+                    len: 8,
+                },
+                Token::Str("type"),
+                Token::Str("token_count"),
+                Token::Str("analyzer"),
+                Token::Str("standard"),
+                Token::Str("boost"),
+                Token::U64(2),
+                Token::Str("store"),
+                Token::Bool(false),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn default_field_tokens_skip_none() {
+        assert_ser_tokens(
+            &KeywordFieldMapping::default(),
+            &[
+                Token::Struct {
+                    name: "mapping",
+                    len: 14,
+                },
+                Token::Str("type"),
+                Token::Str("keyword"),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -0,0 +1,80 @@
+use super::mapping::{
+    MatchOnlyTextFieldType,
+    MatchOnlyTextMapping,
+};
+use serde::{
+    de::{
+        Error,
+        Visitor,
+    },
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use std::{
+    borrow::Borrow,
+    marker::PhantomData,
+};
+
+/**
+An Elasticsearch `match_only_text` field with a mapping.
+
+# Examples
+
+Defining a `match_only_text` field with a mapping:
+
+```
+use elastic::types::string::match_only_text::mapping::DefaultMatchOnlyTextMapping;
+use elastic::types::string::match_only_text::MatchOnlyText;
+
+let string = MatchOnlyText::<DefaultMatchOnlyTextMapping>::new("my string value");
+```
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MatchOnlyText<TMapping>
+where
+    TMapping: MatchOnlyTextMapping,
+{
+    value: String,
+    _m: PhantomData<TMapping>,
+}
+
+impl<TMapping> MatchOnlyText<TMapping>
+where
+    TMapping: MatchOnlyTextMapping,
+{
+    /**
+    Creates a new `MatchOnlyText` with the given mapping.
+
+    # Examples
+
+    Create a new `MatchOnlyText` from a `String`:
+
+    ```
+    use elastic::types::string::match_only_text::mapping::DefaultMatchOnlyTextMapping;
+    use elastic::types::string::match_only_text::MatchOnlyText;
+
+    let string = MatchOnlyText::<DefaultMatchOnlyTextMapping>::new("my string");
+    ```
+    */
+    pub fn new<I>(string: I) -> MatchOnlyText<TMapping>
+    where
+        I: Into<String>,
+    {
+        MatchOnlyText {
+            value: string.into(),
+            _m: PhantomData,
+        }
+    }
+
+    /** Change the mapping of this string. */
+    pub fn remap<TNewMapping>(string: MatchOnlyText<TMapping>) -> MatchOnlyText<TNewMapping>
+    where
+        TNewMapping: MatchOnlyTextMapping,
+    {
+        MatchOnlyText::new(string.value)
+    }
+}
+
+impl_string_type!(MatchOnlyText, MatchOnlyTextMapping, MatchOnlyTextFieldType);
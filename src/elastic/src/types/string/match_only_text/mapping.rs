@@ -0,0 +1,140 @@
+/*! Mapping for the Elasticsearch `match_only_text` type. */
+
+use crate::types::string::mapping::StringField;
+use std::collections::BTreeMap;
+
+/** A field that will be mapped as `match_only_text`. */
+pub trait MatchOnlyTextFieldType<TMapping> {}
+
+/**
+The base requirements for mapping a `match_only_text` type.
+
+Custom mappings can be defined by implementing `MatchOnlyTextMapping`.
+
+`match_only_text` doesn't support the analysis or scoring parameters of `text`,
+so only `fields` and `meta` can be mapped.
+
+# Examples
+
+Define a custom `MatchOnlyTextMapping`:
+
+```
+# #[macro_use] use std::collections::BTreeMap;
+# use elastic::types::prelude::*;
+#[derive(Default)]
+struct MyStringMapping;
+impl MatchOnlyTextMapping for MyStringMapping {
+    //Overload the mapping functions here
+    fn meta() -> Option<BTreeMap<&'static str, &'static str>> {
+        let mut meta = BTreeMap::new();
+        meta.insert("source", "nginx");
+
+        Some(meta)
+    }
+}
+```
+
+This will produce the following mapping:
+
+```
+# #[macro_use] extern crate serde_json;
+# use std::collections::BTreeMap;
+# use elastic::types::prelude::*;
+# #[derive(Default)]
+# struct MyStringMapping;
+# impl MatchOnlyTextMapping for MyStringMapping {
+#     fn meta() -> Option<BTreeMap<&'static str, &'static str>> {
+#         let mut meta = BTreeMap::new();
+#         meta.insert("source", "nginx");
+#         Some(meta)
+#     }
+# }
+# let json = json!(
+{
+    "type": "match_only_text",
+    "meta": {
+        "source": "nginx"
+    }
+}
+# );
+# let mapping = elastic::types::__derive::standalone_field_ser(MyStringMapping).unwrap();
+# assert_eq!(json, mapping);
+```
+*/
+pub trait MatchOnlyTextMapping {
+    /**
+    Multi-fields allow the same string value to be indexed in multiple ways for different purposes,
+    such as a `keyword` multi-field for sorting and aggregations.
+    */
+    fn fields() -> Option<BTreeMap<&'static str, StringField>> {
+        None
+    }
+
+    /** Metadata about the field, like its unit or where its values come from. */
+    fn meta() -> Option<BTreeMap<&'static str, &'static str>> {
+        None
+    }
+}
+
+/** Default mapping for `match_only_text`. */
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DefaultMatchOnlyTextMapping;
+impl MatchOnlyTextMapping for DefaultMatchOnlyTextMapping {}
+
+mod private {
+    use super::{
+        MatchOnlyTextFieldType,
+        MatchOnlyTextMapping,
+    };
+    use crate::types::private::field::{
+        FieldMapping,
+        FieldType,
+        SerializeFieldMapping,
+        StaticSerialize,
+    };
+    use serde::{
+        ser::SerializeStruct,
+        Serialize,
+        Serializer,
+    };
+
+    #[derive(Default)]
+    pub struct MatchOnlyTextPivot;
+
+    impl<TField, TMapping> FieldType<TMapping, MatchOnlyTextPivot> for TField
+    where
+        TField: MatchOnlyTextFieldType<TMapping> + Serialize,
+        TMapping: MatchOnlyTextMapping,
+    {
+    }
+
+    impl<TMapping> FieldMapping<MatchOnlyTextPivot> for TMapping
+    where
+        TMapping: MatchOnlyTextMapping,
+    {
+        type SerializeFieldMapping = SerializeFieldMapping<TMapping, MatchOnlyTextPivot>;
+
+        fn data_type() -> &'static str {
+            "match_only_text"
+        }
+    }
+
+    impl<TMapping> StaticSerialize for SerializeFieldMapping<TMapping, MatchOnlyTextPivot>
+    where
+        TMapping: FieldMapping<MatchOnlyTextPivot> + MatchOnlyTextMapping,
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("mapping", 3)?;
+
+            state.serialize_field("type", TMapping::data_type())?;
+
+            ser_field!(state, "fields", TMapping::fields());
+            ser_field!(state, "meta", TMapping::meta());
+
+            state.end()
+        }
+    }
+}
@@ -0,0 +1,25 @@
+/*!
+Implementation of the Elasticsearch `match_only_text` type.
+
+Match only text fields are analysed like `text`, but don't index positions, frequencies or norms.
+They use less space than `text`, but every match gets the same score and phrase queries are slower.
+They're useful for data like log messages, that's searched but rarely scored.
+*/
+
+pub mod mapping;
+
+mod impls;
+pub use self::impls::*;
+
+pub mod prelude {
+    /*!
+    Includes all types for the `match_only_text` type.
+
+    This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+    */
+
+    pub use super::{
+        impls::*,
+        mapping::*,
+    };
+}
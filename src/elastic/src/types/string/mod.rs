@@ -25,6 +25,14 @@ struct MyType {
 }
 ```
 
+By default, a `String` is mapped as `text` with a `keyword` sub-field,
+so it can be used for both full-text search and exact matching, sorting and aggregations.
+That flexibility costs index space, because every value is indexed twice.
+Enabling the `string-as-keyword` feature maps a `String` as a plain `keyword` instead,
+which is smaller but can't be searched as full-text.
+Either way, use `Text` or `Keyword` for fields that need the other behaviour.
+String slices and `Cow<'static, str>` are mapped the same way as a `String`.
+
 Map a `keyword`:
 
 ```
@@ -70,13 +78,19 @@ impl KeywordFieldType<DefaultKeywordMapping> for MyKeywordField {}
 #[macro_use]
 mod macros;
 
+pub mod completion;
 pub mod keyword;
+pub mod legacy;
+pub mod match_only_text;
+pub mod search_as_you_type;
 pub mod text;
 
 pub mod mapping;
 
 pub use self::{
     keyword::Keyword,
+    match_only_text::MatchOnlyText,
+    search_as_you_type::SearchAsYouType,
     text::Text,
 };
 
@@ -88,8 +102,12 @@ pub mod prelude {
     */
 
     pub use super::{
+        completion::*,
         keyword::prelude::*,
+        legacy::*,
         mapping::*,
+        match_only_text::prelude::*,
+        search_as_you_type::prelude::*,
         text::prelude::*,
     };
 }
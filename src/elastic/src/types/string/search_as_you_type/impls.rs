@@ -0,0 +1,84 @@
+use super::mapping::{
+    SearchAsYouTypeFieldType,
+    SearchAsYouTypeMapping,
+};
+use serde::{
+    de::{
+        Error,
+        Visitor,
+    },
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use std::{
+    borrow::Borrow,
+    marker::PhantomData,
+};
+
+/**
+An Elasticsearch `search_as_you_type` field with a mapping.
+
+# Examples
+
+Defining a `search_as_you_type` field with a mapping:
+
+```
+use elastic::types::string::search_as_you_type::mapping::DefaultSearchAsYouTypeMapping;
+use elastic::types::string::search_as_you_type::SearchAsYouType;
+
+let string = SearchAsYouType::<DefaultSearchAsYouTypeMapping>::new("my string value");
+```
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchAsYouType<TMapping>
+where
+    TMapping: SearchAsYouTypeMapping,
+{
+    value: String,
+    _m: PhantomData<TMapping>,
+}
+
+impl<TMapping> SearchAsYouType<TMapping>
+where
+    TMapping: SearchAsYouTypeMapping,
+{
+    /**
+    Creates a new `SearchAsYouType` with the given mapping.
+
+    # Examples
+
+    Create a new `SearchAsYouType` from a `String`:
+
+    ```
+    use elastic::types::string::search_as_you_type::mapping::DefaultSearchAsYouTypeMapping;
+    use elastic::types::string::search_as_you_type::SearchAsYouType;
+
+    let string = SearchAsYouType::<DefaultSearchAsYouTypeMapping>::new("my string");
+    ```
+    */
+    pub fn new<I>(string: I) -> SearchAsYouType<TMapping>
+    where
+        I: Into<String>,
+    {
+        SearchAsYouType {
+            value: string.into(),
+            _m: PhantomData,
+        }
+    }
+
+    /** Change the mapping of this string. */
+    pub fn remap<TNewMapping>(string: SearchAsYouType<TMapping>) -> SearchAsYouType<TNewMapping>
+    where
+        TNewMapping: SearchAsYouTypeMapping,
+    {
+        SearchAsYouType::new(string.value)
+    }
+}
+
+impl_string_type!(
+    SearchAsYouType,
+    SearchAsYouTypeMapping,
+    SearchAsYouTypeFieldType
+);
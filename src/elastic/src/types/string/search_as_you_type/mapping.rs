@@ -0,0 +1,238 @@
+/*! Mapping for the Elasticsearch `search_as_you_type` type. */
+
+use crate::types::string::{
+    mapping::IndexOptions,
+    text::mapping::TermVector,
+};
+
+/** A field that will be mapped as `search_as_you_type`. */
+pub trait SearchAsYouTypeFieldType<TMapping> {}
+
+/**
+The base requirements for mapping a `search_as_you_type` type.
+
+Custom mappings can be defined by implementing `SearchAsYouTypeMapping`.
+
+Elasticsearch creates a shingle sub-field for each shingle size up to `max_shingle_size`,
+like `my_field._2gram` and `my_field._3gram`, and an edge ngram sub-field, `my_field._index_prefix`.
+These sub-fields are implicit and can't be mapped individually.
+They inherit the `analyzer`, `search_analyzer`, `index_options`, `norms`, `store`, `similarity` and `term_vector` of the root field,
+so to highlight on a sub-field, set the `term_vector` on the root field.
+Use [`search_as_you_type_sub_fields`](fn.search_as_you_type_sub_fields.html) to get the names of the implicit sub-fields for a mapping.
+
+# Examples
+
+Define a custom `SearchAsYouTypeMapping`:
+
+```
+# #[macro_use] use elastic::types::prelude::*;
+#[derive(Default)]
+struct MyStringMapping;
+impl SearchAsYouTypeMapping for MyStringMapping {
+    //Overload the mapping functions here
+    fn term_vector() -> Option<TermVector> {
+        Some(TermVector::WithPositionsOffsets)
+    }
+}
+```
+
+This will produce the following mapping:
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# #[derive(Default)]
+# struct MyStringMapping;
+# impl SearchAsYouTypeMapping for MyStringMapping {
+#     //Overload the mapping functions here
+#     fn term_vector() -> Option<TermVector> {
+#         Some(TermVector::WithPositionsOffsets)
+#     }
+# }
+# let json = json!(
+{
+    "type": "search_as_you_type",
+    "term_vector": "with_positions_offsets"
+}
+# );
+# let mapping = elastic::types::__derive::standalone_field_ser(MyStringMapping).unwrap();
+# assert_eq!(json, mapping);
+```
+*/
+pub trait SearchAsYouTypeMapping {
+    /**
+    The analyzer which should be used for the root field and its sub-fields,
+    both at index-time and at search-time (unless overridden by the `search_analyzer`).
+    Defaults to the default index analyzer, or the `standard` analyzer.
+    */
+    fn analyzer() -> Option<&'static str> {
+        None
+    }
+
+    /** Should the field be searchable? Accepts `true` (default) or `false`. */
+    fn index() -> Option<bool> {
+        None
+    }
+
+    /** What information should be stored in the index, for search and highlighting purposes. Defaults to `Positions`. */
+    fn index_options() -> Option<IndexOptions> {
+        None
+    }
+
+    /**
+    The largest shingle size to index the input with.
+    Accepts an integer from `2` to `4`, defaults to `3`.
+    */
+    fn max_shingle_size() -> Option<u32> {
+        None
+    }
+
+    /** Whether field-length should be taken into account when scoring queries. Accepts `true` (default) or `false`. */
+    fn norms() -> Option<bool> {
+        None
+    }
+
+    /**
+    The number of fake term position which should be inserted between each element of an array of strings.
+    Defaults to the `position_increment_gap` configured on the analyzer which defaults to `100`.
+    */
+    fn position_increment_gap() -> Option<u32> {
+        None
+    }
+
+    /**
+    Whether the field value should be stored and retrievable separately from the `_source` field.
+    Accepts `true` or `false` (default).
+    */
+    fn store() -> Option<bool> {
+        None
+    }
+
+    /**
+    The analyzer that should be used at search time on the root field and its sub-fields.
+    Defaults to the `analyzer` setting.
+    */
+    fn search_analyzer() -> Option<&'static str> {
+        None
+    }
+
+    /**
+    Which scoring algorithm or similarity should be used.
+    Defaults to `"BM25"`.
+    */
+    fn similarity() -> Option<&'static str> {
+        None
+    }
+
+    /**
+    Whether term vectors should be stored for the root field and its sub-fields.
+    Defaults to `No`.
+    */
+    fn term_vector() -> Option<TermVector> {
+        None
+    }
+}
+
+/** Default mapping for `search_as_you_type`. */
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DefaultSearchAsYouTypeMapping;
+impl SearchAsYouTypeMapping for DefaultSearchAsYouTypeMapping {}
+
+/** The `max_shingle_size` Elasticsearch uses when it isn't mapped. */
+pub const DEFAULT_MAX_SHINGLE_SIZE: u32 = 3;
+
+/**
+Get the names of the implicit sub-fields Elasticsearch creates for a `search_as_you_type` mapping.
+
+The names are relative to the root field, so a field called `title` will have a `title._2gram` sub-field.
+
+# Examples
+
+```
+# use elastic::types::prelude::*;
+let fields = search_as_you_type_sub_fields::<DefaultSearchAsYouTypeMapping>();
+
+assert_eq!(vec!["_2gram", "_3gram", "_index_prefix"], fields);
+```
+*/
+pub fn search_as_you_type_sub_fields<TMapping>() -> Vec<String>
+where
+    TMapping: SearchAsYouTypeMapping,
+{
+    let max_shingle_size = TMapping::max_shingle_size().unwrap_or(DEFAULT_MAX_SHINGLE_SIZE);
+
+    (2..=max_shingle_size)
+        .map(|size| format!("_{}gram", size))
+        .chain(Some("_index_prefix".to_owned()))
+        .collect()
+}
+
+mod private {
+    use super::{
+        SearchAsYouTypeFieldType,
+        SearchAsYouTypeMapping,
+    };
+    use crate::types::private::field::{
+        FieldMapping,
+        FieldType,
+        SerializeFieldMapping,
+        StaticSerialize,
+    };
+    use serde::{
+        ser::SerializeStruct,
+        Serialize,
+        Serializer,
+    };
+
+    #[derive(Default)]
+    pub struct SearchAsYouTypePivot;
+
+    impl<TField, TMapping> FieldType<TMapping, SearchAsYouTypePivot> for TField
+    where
+        TField: SearchAsYouTypeFieldType<TMapping> + Serialize,
+        TMapping: SearchAsYouTypeMapping,
+    {
+    }
+
+    impl<TMapping> FieldMapping<SearchAsYouTypePivot> for TMapping
+    where
+        TMapping: SearchAsYouTypeMapping,
+    {
+        type SerializeFieldMapping = SerializeFieldMapping<TMapping, SearchAsYouTypePivot>;
+
+        fn data_type() -> &'static str {
+            "search_as_you_type"
+        }
+    }
+
+    impl<TMapping> StaticSerialize for SerializeFieldMapping<TMapping, SearchAsYouTypePivot>
+    where
+        TMapping: FieldMapping<SearchAsYouTypePivot> + SearchAsYouTypeMapping,
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("mapping", 11)?;
+
+            state.serialize_field("type", TMapping::data_type())?;
+
+            ser_field!(state, "analyzer", TMapping::analyzer());
+            ser_field!(state, "index", TMapping::index());
+            ser_field!(state, "index_options", TMapping::index_options());
+            ser_field!(state, "max_shingle_size", TMapping::max_shingle_size());
+            ser_field!(state, "norms", TMapping::norms());
+            ser_field!(
+                state,
+                "position_increment_gap",
+                TMapping::position_increment_gap()
+            );
+            ser_field!(state, "store", TMapping::store());
+            ser_field!(state, "search_analyzer", TMapping::search_analyzer());
+            ser_field!(state, "similarity", TMapping::similarity());
+            ser_field!(state, "term_vector", TMapping::term_vector());
+
+            state.end()
+        }
+    }
+}
@@ -0,0 +1,24 @@
+/*!
+Implementation of the Elasticsearch `search_as_you_type` type.
+
+Search as you type fields are analysed like `text`, but also index shingles and edge ngrams
+into implicit sub-fields so they can be efficiently matched as a user types.
+*/
+
+pub mod mapping;
+
+mod impls;
+pub use self::impls::*;
+
+pub mod prelude {
+    /*!
+    Includes all types for the `search_as_you_type` type.
+
+    This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+    */
+
+    pub use super::{
+        impls::*,
+        mapping::*,
+    };
+}
@@ -2,7 +2,6 @@ use super::mapping::{
     TextFieldType,
     TextMapping,
 };
-use crate::types::string::mapping::DefaultStringMapping;
 use serde::{
     de::{
         Error,
@@ -18,8 +17,16 @@ use std::{
     marker::PhantomData,
 };
 
-impl TextFieldType<DefaultStringMapping> for String {}
-impl<'a> TextFieldType<DefaultStringMapping> for &'a str {}
+// Strings are mapped as `keyword` instead when the `string-as-keyword` feature is enabled
+#[cfg(not(feature = "string-as-keyword"))]
+impl TextFieldType<crate::types::string::mapping::DefaultStringMapping> for String {}
+#[cfg(not(feature = "string-as-keyword"))]
+impl<'a> TextFieldType<crate::types::string::mapping::DefaultStringMapping> for &'a str {}
+#[cfg(not(feature = "string-as-keyword"))]
+impl TextFieldType<crate::types::string::mapping::DefaultStringMapping>
+    for std::borrow::Cow<'static, str>
+{
+}
 
 /**
 An Elasticsearch `text` field with a mapping.
@@ -1,7 +1,11 @@
 /*! Mapping for the Elasticsearch `text` type. */
 
 use crate::types::{
-    private::field::FieldMapping,
+    boost::Boost,
+    private::{
+        field::FieldMapping,
+        float,
+    },
     string::mapping::{
         IndexOptions,
         StringField,
@@ -12,7 +16,18 @@ use serde::{
     Serialize,
     Serializer,
 };
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult,
+    },
+    hash::{
+        Hash,
+        Hasher,
+    },
+};
 
 /** A field that will be mapped as `text`. */
 pub trait TextFieldType<TMapping> {}
@@ -22,6 +37,11 @@ The base requirements for mapping a `string` type.
 
 Custom mappings can be defined by implementing `TextMapping`.
 
+`text` fields don't support `doc_values`, so there's no way to map them.
+Use `fielddata` to sort or aggregate on a `text` field instead.
+They don't support `null_value` either, which is rejected when a document mapping is serialised.
+Use a `keyword` multi-field with a `null_value` to index a placeholder for missing values.
+
 # Examples
 
 Define a custom `TextMapping`:
@@ -32,8 +52,8 @@ Define a custom `TextMapping`:
 struct MyStringMapping;
 impl TextMapping for MyStringMapping {
     //Overload the mapping functions here
-    fn boost() -> Option<f32> {
-        Some(1.5)
+    fn boost() -> Option<Boost> {
+        Some(Boost::new(1.5).unwrap())
     }
 }
 ```
@@ -47,8 +67,8 @@ This will produce the following mapping:
 # struct MyStringMapping;
 # impl TextMapping for MyStringMapping {
 #     //Overload the mapping functions here
-#     fn boost() -> Option<f32> {
-#         Some(1.5)
+#     fn boost() -> Option<Boost> {
+#         Some(Boost::new(1.5).unwrap())
 #     }
 # }
 # let json = json!(
@@ -71,8 +91,17 @@ pub trait TextMapping {
         None
     }
 
-    /** Field-level index time boosting. Accepts a floating point number, defaults to `1.0`. */
-    fn boost() -> Option<f32> {
+    /** Field-level index time boosting. Accepts a positive floating point number, defaults to `1.0`. */
+    fn boost() -> Option<Boost> {
+        None
+    }
+
+    /**
+    Copy the value of this field into one or more target fields,
+    which can then be queried as a single field.
+    Targets are the full dotted paths of fields declared on the same document.
+    */
+    fn copy_to() -> Option<&'static [&'static str]> {
         None
     }
 
@@ -96,6 +125,7 @@ pub trait TextMapping {
     /**
     Expert settings which allow to decide which values to load in memory when `fielddata` is enabled.
     By default all values are loaded.
+    This has no effect unless `fielddata` is `true`.
     */
     fn fielddata_frequency_filter() -> Option<FieldDataFrequencyFilter> {
         None
@@ -122,7 +152,10 @@ pub trait TextMapping {
 
         //Add a `token_count` as a sub field
         fields.insert("count", StringField::TokenCount(
-            ElasticTokenCountFieldMapping::default())
+            ElasticTokenCountFieldMapping {
+                analyzer: Some("standard"),
+                ..Default::default()
+            })
         );
 
         //Add a `completion` suggester as a sub field
@@ -134,6 +167,11 @@ pub trait TextMapping {
     }
     # }
     ```
+
+    The `multi_fields!` macro can build the same map with less boilerplate.
+
+    Sub fields are always serialised in alphabetical order by name, regardless of the order they're inserted in,
+    so the mapping serialises to the same json every time.
     */
     fn fields() -> Option<BTreeMap<&'static str, StringField>> {
         None
@@ -229,7 +267,7 @@ pub struct DefaultTextMapping;
 impl TextMapping for DefaultTextMapping {}
 
 /** Term vectors contain information about the terms produced by the analysis process. */
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TermVector {
     /** No term vectors are stored. (default) */
     No,
@@ -241,25 +279,54 @@ pub enum TermVector {
     WithOffsets,
     /** Terms, positions, and character offsets are stored. */
     WithPositionsOffsets,
+    /** Terms, positions, and payloads are stored. */
+    WithPositionsPayloads,
+    /** Terms, positions, character offsets, and payloads are stored. */
+    WithPositionsOffsetsPayloads,
 }
 
-impl Serialize for TermVector {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_str(match *self {
+impl TermVector {
+    /** Get the value of this option as it's sent to Elasticsearch. */
+    pub fn as_str(&self) -> &'static str {
+        match *self {
             TermVector::No => "no",
             TermVector::Yes => "yes",
             TermVector::WithPositions => "with_positions",
             TermVector::WithOffsets => "with_offsets",
             TermVector::WithPositionsOffsets => "with_positions_offsets",
-        })
+            TermVector::WithPositionsPayloads => "with_positions_payloads",
+            TermVector::WithPositionsOffsetsPayloads => "with_positions_offsets_payloads",
+        }
+    }
+}
+
+impl Serialize for TermVector {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
     }
 }
 
+impl Display for TermVector {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+impl_str_enum_deserialize!(TermVector {
+    No,
+    Yes,
+    WithPositions,
+    WithOffsets,
+    WithPositionsOffsets,
+    WithPositionsPayloads,
+    WithPositionsOffsetsPayloads
+});
+
 /** Fielddata for term frequency as a percentage range. */
-#[derive(Debug, Default, PartialEq, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct FieldDataFrequencyFilter {
     /** The min frequency percentage. */
     pub min: Option<f32>,
@@ -269,6 +336,27 @@ pub struct FieldDataFrequencyFilter {
     pub min_segment_size: Option<i32>,
 }
 
+impl PartialEq for FieldDataFrequencyFilter {
+    fn eq(&self, other: &Self) -> bool {
+        float::eq(self.min, other.min)
+            && float::eq(self.max, other.max)
+            && self.min_segment_size == other.min_segment_size
+    }
+}
+
+impl Eq for FieldDataFrequencyFilter {}
+
+impl Hash for FieldDataFrequencyFilter {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        float::hash(self.min, state);
+        float::hash(self.max, state);
+        self.min_segment_size.hash(state);
+    }
+}
+
 impl Serialize for FieldDataFrequencyFilter {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -284,8 +372,12 @@ impl Serialize for FieldDataFrequencyFilter {
     }
 }
 
-/** A multi-field string mapping. */
-#[derive(Debug, Default, Clone, Copy)]
+/**
+A multi-field string mapping.
+
+`text` fields don't support `doc_values`, use `fielddata` instead.
+*/
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct TextFieldMapping {
     /**
     The analyzer which should be used for analyzed string fields,
@@ -294,6 +386,12 @@ pub struct TextFieldMapping {
     */
     pub analyzer: Option<&'static str>,
     /**
+    Copy the value of this field into one or more target fields,
+    which can then be queried as a single field.
+    Targets are the full dotted paths of fields declared on the same document.
+    */
+    pub copy_to: Option<&'static [&'static str]>,
+    /**
     Should global ordinals be loaded eagerly on refresh?
     Accepts `true` or `false` (default).
     Enabling this is a good idea on fields that are frequently used for (significant) terms aggregations.
@@ -307,6 +405,7 @@ pub struct TextFieldMapping {
     /**
     Expert settings which allow to decide which values to load in memory when `fielddata` is enabled.
     By default all values are loaded.
+    This has no effect unless `fielddata` is `true`.
     */
     pub fielddata_frequency_filter: Option<FieldDataFrequencyFilter>,
     /**
@@ -361,16 +460,39 @@ pub struct TextFieldMapping {
     pub term_vector: Option<TermVector>,
 }
 
-impl Serialize for TextFieldMapping {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+impl TextFieldMapping {
+    /**
+    Attach a map of multi-fields to this mapping.
+
+    The result serialises the same as this mapping, with an additional `fields` parameter.
+    The fields are serialised in alphabetical order by name.
+    Elasticsearch doesn't support multi-fields on a sub field, so the result can't be used in a `StringField`.
+    */
+    pub fn with_fields(
+        self,
+        fields: BTreeMap<&'static str, StringField>,
+    ) -> TextFieldMappingWithFields {
+        TextFieldMappingWithFields {
+            mapping: self,
+            fields,
+        }
+    }
+
+    fn serialize_with_fields<S>(
+        &self,
+        fields: Option<&BTreeMap<&'static str, StringField>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("mapping", 16)?;
+        let len = if fields.is_some() { 18 } else { 17 };
+        let mut state = serializer.serialize_struct("mapping", len)?;
 
         state.serialize_field("type", DefaultTextMapping::data_type())?;
 
         ser_field!(state, "analyzer", self.analyzer);
+        ser_field!(state, "copy_to", self.copy_to);
         ser_field!(state, "eager_global_ordinals", self.eager_global_ordinals);
         ser_field!(state, "fielddata", self.fielddata);
         ser_field!(
@@ -378,6 +500,7 @@ impl Serialize for TextFieldMapping {
             "fielddata_frequency_filter",
             self.fielddata_frequency_filter
         );
+        ser_field!(state, "fields", fields);
         ser_field!(state, "include_in_all", self.include_in_all);
         ser_field!(state, "ignore_above", self.ignore_above);
         ser_field!(state, "index", self.index);
@@ -394,6 +517,38 @@ impl Serialize for TextFieldMapping {
     }
 }
 
+impl Serialize for TextFieldMapping {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize_with_fields(None, serializer)
+    }
+}
+
+/**
+A `text` mapping with its own multi-fields.
+
+This is produced by `TextFieldMapping::with_fields`.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextFieldMappingWithFields {
+    /** The mapping for the `text` field. */
+    pub mapping: TextFieldMapping,
+    /** The multi-fields of the `text` field. */
+    pub fields: BTreeMap<&'static str, StringField>,
+}
+
+impl Serialize for TextFieldMappingWithFields {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.mapping
+            .serialize_with_fields(Some(&self.fields), serializer)
+    }
+}
+
 mod private {
     use super::{
         TextFieldType,
@@ -440,12 +595,13 @@ mod private {
         where
             S: Serializer,
         {
-            let mut state = serializer.serialize_struct("mapping", 18)?;
+            let mut state = serializer.serialize_struct("mapping", 19)?;
 
             state.serialize_field("type", TMapping::data_type())?;
 
             ser_field!(state, "boost", TMapping::boost());
             ser_field!(state, "analyzer", TMapping::analyzer());
+            ser_field!(state, "copy_to", TMapping::copy_to());
             ser_field!(
                 state,
                 "eager_global_ordinals",
@@ -0,0 +1,113 @@
+/*!
+Serialisation for fixed-size arrays of floats, like `[f32; 128]`.
+
+A `[f32; N]` is mapped as a `dense_vector` with `N` dimensions,
+but `serde` only implements its traits for arrays with up to 32 elements.
+Use this module with `#[serde(with)]` to serialise larger arrays as a json array of floats.
+
+# Examples
+
+Map a `[f32; 128]` field as a `dense_vector`:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+#[derive(Serialize, Deserialize, ElasticType)]
+struct MyType {
+    #[serde(with = "elastic::types::vector::dense::array")]
+    pub embedding: [f32; 128],
+}
+```
+*/
+
+use serde::{
+    de::Error,
+    Deserialize,
+    Deserializer,
+    Serializer,
+};
+use std::convert::TryFrom;
+
+/** Serialise a fixed-size array of floats as a json array. */
+pub fn serialize<S, const N: usize>(vector: &[f32; N], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(vector.iter())
+}
+
+/** Deserialise a fixed-size array of floats from a json array with exactly `N` elements. */
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[f32; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let vector = Vec::<f32>::deserialize(deserializer)?;
+    let len = vector.len();
+
+    <[f32; N]>::try_from(vector)
+        .map_err(|_| D::Error::invalid_length(len, &format!("{} dimensions", N).as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::prelude::*;
+
+    #[derive(Debug, Serialize, Deserialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct EmbeddingType {
+        #[serde(with = "crate::types::vector::dense::array")]
+        pub embedding: [f32; 64],
+        pub small: [f32; 3],
+    }
+
+    #[test]
+    fn serialise_mapping_fixed_array() {
+        let ser = serde_json::to_value(&EmbeddingType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "embedding": {
+                    "type": "dense_vector",
+                    "dims": 64
+                },
+                "small": {
+                    "type": "dense_vector",
+                    "dims": 3
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_fixed_array() {
+        let doc = EmbeddingType {
+            embedding: [0.5; 64],
+            small: [1.0, 2.0, 3.0],
+        };
+
+        let ser = serde_json::to_value(&doc).unwrap();
+
+        assert_eq!(64, ser["embedding"].as_array().unwrap().len());
+        assert_eq!(json!([1.0, 2.0, 3.0]), ser["small"]);
+
+        let de: EmbeddingType = serde_json::from_value(ser).unwrap();
+
+        assert_eq!(doc.embedding[..], de.embedding[..]);
+    }
+
+    #[test]
+    fn deserialise_fixed_array_wrong_length() {
+        let err = serde_json::from_value::<EmbeddingType>(json!({
+            "embedding": [0.5, 0.5],
+            "small": [1.0, 2.0, 3.0]
+        }))
+        .unwrap_err();
+
+        assert!(err.to_string().contains("64 dimensions"));
+    }
+}
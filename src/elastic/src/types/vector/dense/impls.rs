@@ -0,0 +1,149 @@
+use super::mapping::{
+    DenseVectorFieldType,
+    DenseVectorMapping,
+};
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use std::{
+    borrow::Borrow,
+    marker::PhantomData,
+    ops::Deref,
+};
+
+/**
+An Elasticsearch `dense_vector` with a mapping.
+
+The vector is serialised as a json array of floats.
+
+# Examples
+
+Defining a `dense_vector` with a mapping:
+
+```
+# use elastic::types::prelude::*;
+let vector = DenseVector::<DefaultDenseVectorMapping>::new(vec![0.5, 10.0, 6.0]);
+```
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DenseVector<TMapping>
+where
+    TMapping: DenseVectorMapping,
+{
+    value: Vec<f32>,
+    _m: PhantomData<TMapping>,
+}
+
+impl<TMapping> DenseVector<TMapping>
+where
+    TMapping: DenseVectorMapping,
+{
+    /** Creates a new `DenseVector` with the given mapping. */
+    pub fn new<I>(vector: I) -> DenseVector<TMapping>
+    where
+        I: Into<Vec<f32>>,
+    {
+        DenseVector {
+            value: vector.into(),
+            _m: PhantomData,
+        }
+    }
+
+    /** Change the mapping of this vector. */
+    pub fn remap<TNewMapping>(vector: DenseVector<TMapping>) -> DenseVector<TNewMapping>
+    where
+        TNewMapping: DenseVectorMapping,
+    {
+        DenseVector::new(vector.value)
+    }
+
+    /** Get the wrapped vector. */
+    pub fn into_inner(self) -> Vec<f32> {
+        self.value
+    }
+}
+
+impl<TMapping> DenseVectorFieldType<TMapping> for DenseVector<TMapping> where
+    TMapping: DenseVectorMapping
+{
+}
+
+impl<TMapping> Deref for DenseVector<TMapping>
+where
+    TMapping: DenseVectorMapping,
+{
+    type Target = [f32];
+
+    fn deref(&self) -> &[f32] {
+        &self.value
+    }
+}
+
+impl<TMapping> Borrow<[f32]> for DenseVector<TMapping>
+where
+    TMapping: DenseVectorMapping,
+{
+    fn borrow(&self) -> &[f32] {
+        &self.value
+    }
+}
+
+impl<TMapping> From<Vec<f32>> for DenseVector<TMapping>
+where
+    TMapping: DenseVectorMapping,
+{
+    fn from(vector: Vec<f32>) -> Self {
+        DenseVector::new(vector)
+    }
+}
+
+impl<TMapping> Serialize for DenseVector<TMapping>
+where
+    TMapping: DenseVectorMapping,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de, TMapping> Deserialize<'de> for DenseVector<TMapping>
+where
+    TMapping: DenseVectorMapping,
+{
+    fn deserialize<D>(deserializer: D) -> Result<DenseVector<TMapping>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::deserialize(deserializer).map(DenseVector::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::prelude::*;
+
+    #[test]
+    fn serialise_dense_vector() {
+        let vector: DenseVector<DefaultDenseVectorMapping> = DenseVector::new(vec![0.5, 10.0]);
+
+        let ser = serde_json::to_value(&vector).unwrap();
+
+        assert_eq!(json!([0.5, 10.0]), ser);
+    }
+
+    #[test]
+    fn deserialise_dense_vector() {
+        let vector: DenseVector<DefaultDenseVectorMapping> =
+            serde_json::from_str("[0.5,10.0]").unwrap();
+
+        assert_eq!(&[0.5, 10.0], &*vector);
+    }
+}
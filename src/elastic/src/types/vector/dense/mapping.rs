@@ -0,0 +1,434 @@
+/*! Mapping for the Elasticsearch `dense_vector` type. */
+
+use serde::{
+    ser::SerializeStruct,
+    Serialize,
+    Serializer,
+};
+use std::fmt::{
+    Display,
+    Formatter,
+    Result as FmtResult,
+};
+
+/** A field that will be mapped as a `dense_vector`. */
+pub trait DenseVectorFieldType<TMapping> {}
+
+/**
+The base requirements for mapping a `dense_vector` type.
+
+Custom mappings can be defined by implementing `DenseVectorMapping`.
+
+# Examples
+
+Define a custom `DenseVectorMapping`:
+
+```
+# #[macro_use] use elastic::types::prelude::*;
+#[derive(Default)]
+struct MyDenseVectorMapping;
+impl DenseVectorMapping for MyDenseVectorMapping {
+    //Overload the mapping functions here
+    fn dims() -> Option<u32> {
+        Some(3)
+    }
+
+    fn similarity() -> Option<VectorSimilarity> {
+        Some(VectorSimilarity::Cosine)
+    }
+
+    fn index_options() -> Option<DenseVectorIndexOptions> {
+        Some(DenseVectorIndexOptions::Hnsw {
+            m: Some(16),
+            ef_construction: Some(100),
+        })
+    }
+}
+```
+
+This will produce the following mapping:
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# #[derive(Default)]
+# struct MyDenseVectorMapping;
+# impl DenseVectorMapping for MyDenseVectorMapping {
+#     fn dims() -> Option<u32> {
+#         Some(3)
+#     }
+#     fn similarity() -> Option<VectorSimilarity> {
+#         Some(VectorSimilarity::Cosine)
+#     }
+#     fn index_options() -> Option<DenseVectorIndexOptions> {
+#         Some(DenseVectorIndexOptions::Hnsw {
+#             m: Some(16),
+#             ef_construction: Some(100),
+#         })
+#     }
+# }
+# let json = json!(
+{
+    "type": "dense_vector",
+    "dims": 3,
+    "similarity": "cosine",
+    "index_options": {
+        "type": "hnsw",
+        "m": 16,
+        "ef_construction": 100
+    }
+}
+# );
+# let mapping = elastic::types::__derive::standalone_field_ser(MyDenseVectorMapping).unwrap();
+# assert_eq!(json, mapping);
+```
+*/
+pub trait DenseVectorMapping {
+    /**
+    The number of dimensions in the vector.
+    Defaults to the length of the first vector indexed into the field.
+    */
+    fn dims() -> Option<u32> {
+        None
+    }
+
+    /** Should the field be searchable with kNN queries? Accepts `true` (default) or `false`. */
+    fn index() -> Option<bool> {
+        None
+    }
+
+    /**
+    The similarity metric used for kNN search.
+    Defaults to `Cosine`.
+    */
+    fn similarity() -> Option<VectorSimilarity> {
+        None
+    }
+
+    /**
+    The kNN algorithm used to index the vectors, with its tuning parameters.
+    This has no effect unless `index` is `true`.
+    */
+    fn index_options() -> Option<DenseVectorIndexOptions> {
+        None
+    }
+}
+
+/** Default mapping for a `dense_vector`. */
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DefaultDenseVectorMapping;
+impl DenseVectorMapping for DefaultDenseVectorMapping {}
+
+/**
+Default mapping for a fixed-size array of floats, like `[f32; 128]`.
+
+The number of dimensions is the length of the array.
+*/
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct FixedDenseVectorMapping<const N: usize>;
+impl<const N: usize> DenseVectorMapping for FixedDenseVectorMapping<N> {
+    fn dims() -> Option<u32> {
+        Some(N as u32)
+    }
+}
+
+/** The similarity metric used to compare vectors in kNN search. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VectorSimilarity {
+    /** Similarity based on the L2 distance between vectors. */
+    L2Norm,
+    /** The dot product of vectors, which must be normalised to unit length. */
+    DotProduct,
+    /** The cosine of the angle between vectors. (default) */
+    Cosine,
+    /** The dot product of vectors, which don't need to be normalised. */
+    MaxInnerProduct,
+}
+
+impl VectorSimilarity {
+    /** Get the value of this similarity as it's sent to Elasticsearch. */
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            VectorSimilarity::L2Norm => "l2_norm",
+            VectorSimilarity::DotProduct => "dot_product",
+            VectorSimilarity::Cosine => "cosine",
+            VectorSimilarity::MaxInnerProduct => "max_inner_product",
+        }
+    }
+}
+
+impl Serialize for VectorSimilarity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Display for VectorSimilarity {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+impl_str_enum_deserialize!(VectorSimilarity {
+    L2Norm,
+    DotProduct,
+    Cosine,
+    MaxInnerProduct
+});
+
+/** The kNN algorithm used to index a `dense_vector`. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DenseVectorIndexOptions {
+    /** Index vectors in an HNSW graph for approximate kNN search. */
+    Hnsw {
+        /** The number of neighbours each node is connected to in the graph. Defaults to `16`. */
+        m: Option<u32>,
+        /** The number of candidates tracked while building the graph. Defaults to `100`. */
+        ef_construction: Option<u32>,
+    },
+    /** Like `Hnsw`, but quantises each dimension to a single byte to reduce memory use. */
+    Int8Hnsw {
+        /** The number of neighbours each node is connected to in the graph. Defaults to `16`. */
+        m: Option<u32>,
+        /** The number of candidates tracked while building the graph. Defaults to `100`. */
+        ef_construction: Option<u32>,
+    },
+    /** Search vectors by brute force for exact kNN search. */
+    Flat,
+}
+
+impl DenseVectorIndexOptions {
+    /** Get the name of this algorithm as it's sent to Elasticsearch. */
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            DenseVectorIndexOptions::Hnsw { .. } => "hnsw",
+            DenseVectorIndexOptions::Int8Hnsw { .. } => "int8_hnsw",
+            DenseVectorIndexOptions::Flat => "flat",
+        }
+    }
+}
+
+impl Serialize for DenseVectorIndexOptions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("index_options", 3)?;
+
+        state.serialize_field("type", self.as_str())?;
+
+        match *self {
+            DenseVectorIndexOptions::Hnsw { m, ef_construction }
+            | DenseVectorIndexOptions::Int8Hnsw { m, ef_construction } => {
+                ser_field!(state, "m", m);
+                ser_field!(state, "ef_construction", ef_construction);
+            }
+            DenseVectorIndexOptions::Flat => (),
+        }
+
+        state.end()
+    }
+}
+
+mod private {
+    use super::{
+        DenseVectorFieldType,
+        DenseVectorMapping,
+        FixedDenseVectorMapping,
+    };
+    use crate::types::private::field::{
+        FieldMapping,
+        FieldType,
+        SerializeFieldMapping,
+        StaticSerialize,
+    };
+    use serde::{
+        ser::SerializeStruct,
+        Serialize,
+        Serializer,
+    };
+
+    #[derive(Default)]
+    pub struct DenseVectorPivot;
+
+    // Fixed-size arrays have their own pivot, because `serde` doesn't implement `Serialize` for all of them
+    #[derive(Default)]
+    pub struct FixedDenseVectorPivot;
+
+    impl<const N: usize> FieldType<FixedDenseVectorMapping<N>, FixedDenseVectorPivot> for [f32; N] {}
+
+    impl<const N: usize> FieldMapping<FixedDenseVectorPivot> for FixedDenseVectorMapping<N> {
+        type SerializeFieldMapping = SerializeFieldMapping<Self, FixedDenseVectorPivot>;
+
+        fn data_type() -> &'static str {
+            "dense_vector"
+        }
+    }
+
+    impl<const N: usize> StaticSerialize
+        for SerializeFieldMapping<FixedDenseVectorMapping<N>, FixedDenseVectorPivot>
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            SerializeFieldMapping::<FixedDenseVectorMapping<N>, DenseVectorPivot>::static_serialize(
+                serializer,
+            )
+        }
+    }
+
+    impl<TField, TMapping> FieldType<TMapping, DenseVectorPivot> for TField
+    where
+        TField: DenseVectorFieldType<TMapping> + Serialize,
+        TMapping: DenseVectorMapping,
+    {
+    }
+
+    impl<TMapping> FieldMapping<DenseVectorPivot> for TMapping
+    where
+        TMapping: DenseVectorMapping,
+    {
+        type SerializeFieldMapping = SerializeFieldMapping<TMapping, DenseVectorPivot>;
+
+        fn data_type() -> &'static str {
+            "dense_vector"
+        }
+    }
+
+    impl<TMapping> StaticSerialize for SerializeFieldMapping<TMapping, DenseVectorPivot>
+    where
+        TMapping: FieldMapping<DenseVectorPivot> + DenseVectorMapping,
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("mapping", 5)?;
+
+            state.serialize_field("type", TMapping::data_type())?;
+
+            ser_field!(state, "dims", TMapping::dims());
+            ser_field!(state, "index", TMapping::index());
+            ser_field!(state, "similarity", TMapping::similarity());
+            ser_field!(state, "index_options", TMapping::index_options());
+
+            state.end()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::{
+        prelude::*,
+        private::field,
+    };
+
+    #[derive(Default, Clone)]
+    pub struct MyHnswMapping;
+    impl DenseVectorMapping for MyHnswMapping {
+        fn dims() -> Option<u32> {
+            Some(384)
+        }
+
+        fn index() -> Option<bool> {
+            Some(true)
+        }
+
+        fn similarity() -> Option<VectorSimilarity> {
+            Some(VectorSimilarity::DotProduct)
+        }
+
+        fn index_options() -> Option<DenseVectorIndexOptions> {
+            Some(DenseVectorIndexOptions::Hnsw {
+                m: Some(16),
+                ef_construction: Some(100),
+            })
+        }
+    }
+
+    #[derive(Default, Clone)]
+    pub struct MyFlatMapping;
+    impl DenseVectorMapping for MyFlatMapping {
+        fn index_options() -> Option<DenseVectorIndexOptions> {
+            Some(DenseVectorIndexOptions::Flat)
+        }
+    }
+
+    #[test]
+    fn serialise_mapping_default() {
+        let ser = serde_json::to_value(&field::serialize(DefaultDenseVectorMapping)).unwrap();
+
+        assert_eq!(json!({ "type": "dense_vector" }), ser);
+    }
+
+    #[test]
+    fn serialise_mapping_hnsw() {
+        let ser = serde_json::to_value(&field::serialize(MyHnswMapping)).unwrap();
+
+        let expected = json!({
+            "type": "dense_vector",
+            "dims": 384,
+            "index": true,
+            "similarity": "dot_product",
+            "index_options": {
+                "type": "hnsw",
+                "m": 16,
+                "ef_construction": 100
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_flat() {
+        let ser = serde_json::to_value(&field::serialize(MyFlatMapping)).unwrap();
+
+        let expected = json!({
+            "type": "dense_vector",
+            "index_options": {
+                "type": "flat"
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn deserialise_similarity_ignores_case() {
+        let similarity: Vec<VectorSimilarity> =
+            serde_json::from_str(r#"["max_inner_product","L2_NORM","Cosine"]"#).unwrap();
+
+        assert_eq!(
+            vec![
+                VectorSimilarity::MaxInnerProduct,
+                VectorSimilarity::L2Norm,
+                VectorSimilarity::Cosine,
+            ],
+            similarity
+        );
+    }
+
+    #[test]
+    fn serialise_index_options_int8_hnsw() {
+        let ser = serde_json::to_value(&DenseVectorIndexOptions::Int8Hnsw {
+            m: None,
+            ef_construction: Some(200),
+        })
+        .unwrap();
+
+        let expected = json!({
+            "type": "int8_hnsw",
+            "ef_construction": 200
+        });
+
+        assert_eq!(expected, ser);
+    }
+}
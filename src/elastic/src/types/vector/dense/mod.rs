@@ -0,0 +1,52 @@
+/*!
+Implementation of the Elasticsearch `dense_vector` type.
+
+A `dense_vector` field indexes a fixed number of floats,
+like an embedding produced by a machine learning model, for kNN search.
+
+# Examples
+
+For defining your own dense vector mapping, see [mapping details](mapping/trait.DenseVectorMapping.html).
+
+Map a `dense_vector`:
+
+```
+# use elastic::types::prelude::*;
+struct MyType {
+    pub embedding: DenseVector<DefaultDenseVectorMapping>
+}
+```
+
+Map a fixed-size array of floats as a `dense_vector` with the length of the array as its dimensions:
+
+```
+# use elastic::types::prelude::*;
+struct MyType {
+    pub embedding: [f32; 3]
+}
+```
+
+Arrays with more than 32 floats need to be serialised with the [`array`](array/index.html) module.
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/dense-vector.html)
+*/
+pub mod array;
+pub mod mapping;
+
+mod impls;
+pub use self::impls::*;
+
+pub mod prelude {
+    /*!
+    Includes all types for the `dense_vector` type.
+
+    This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+    */
+
+    pub use super::{
+        impls::*,
+        mapping::*,
+    };
+}
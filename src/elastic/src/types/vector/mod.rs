@@ -0,0 +1,22 @@
+/*!
+Implementation of the Elasticsearch vector types.
+
+Use [`dense`](dense/index.html) for indexing embeddings with a fixed number of dimensions for kNN search.
+Use [`sparse`](sparse/index.html) for indexing token weights, like those produced by learned sparse retrieval models.
+*/
+
+pub mod dense;
+pub mod sparse;
+
+pub mod prelude {
+    /*!
+    Includes all types for the vector types.
+
+    This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+    */
+
+    pub use super::{
+        dense::prelude::*,
+        sparse::prelude::*,
+    };
+}
@@ -0,0 +1,169 @@
+use super::mapping::{
+    SparseVectorFieldType,
+    SparseVectorMapping,
+};
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use std::{
+    borrow::Borrow,
+    collections::BTreeMap,
+    marker::PhantomData,
+    ops::Deref,
+};
+
+/**
+An Elasticsearch `sparse_vector` with a mapping.
+
+The vector is a map of tokens to their weights, and is serialised as a json object.
+
+# Examples
+
+Defining a `sparse_vector` with a mapping:
+
+```
+# use std::collections::BTreeMap;
+# use elastic::types::prelude::*;
+let mut weights = BTreeMap::new();
+weights.insert("rust".to_owned(), 1.2);
+weights.insert("search".to_owned(), 0.4);
+
+let vector = SparseVector::<DefaultSparseVectorMapping>::new(weights);
+```
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SparseVector<TMapping>
+where
+    TMapping: SparseVectorMapping,
+{
+    value: BTreeMap<String, f32>,
+    _m: PhantomData<TMapping>,
+}
+
+impl<TMapping> SparseVector<TMapping>
+where
+    TMapping: SparseVectorMapping,
+{
+    /** Creates a new `SparseVector` with the given mapping. */
+    pub fn new<I>(weights: I) -> SparseVector<TMapping>
+    where
+        I: Into<BTreeMap<String, f32>>,
+    {
+        SparseVector {
+            value: weights.into(),
+            _m: PhantomData,
+        }
+    }
+
+    /** Change the mapping of this vector. */
+    pub fn remap<TNewMapping>(vector: SparseVector<TMapping>) -> SparseVector<TNewMapping>
+    where
+        TNewMapping: SparseVectorMapping,
+    {
+        SparseVector::new(vector.value)
+    }
+
+    /** Get the wrapped token weights. */
+    pub fn into_inner(self) -> BTreeMap<String, f32> {
+        self.value
+    }
+}
+
+impl<TMapping> SparseVectorFieldType<TMapping> for SparseVector<TMapping> where
+    TMapping: SparseVectorMapping
+{
+}
+
+impl<TMapping> Deref for SparseVector<TMapping>
+where
+    TMapping: SparseVectorMapping,
+{
+    type Target = BTreeMap<String, f32>;
+
+    fn deref(&self) -> &BTreeMap<String, f32> {
+        &self.value
+    }
+}
+
+impl<TMapping> Borrow<BTreeMap<String, f32>> for SparseVector<TMapping>
+where
+    TMapping: SparseVectorMapping,
+{
+    fn borrow(&self) -> &BTreeMap<String, f32> {
+        &self.value
+    }
+}
+
+impl<TMapping> From<BTreeMap<String, f32>> for SparseVector<TMapping>
+where
+    TMapping: SparseVectorMapping,
+{
+    fn from(weights: BTreeMap<String, f32>) -> Self {
+        SparseVector::new(weights)
+    }
+}
+
+impl<TMapping> Serialize for SparseVector<TMapping>
+where
+    TMapping: SparseVectorMapping,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de, TMapping> Deserialize<'de> for SparseVector<TMapping>
+where
+    TMapping: SparseVectorMapping,
+{
+    fn deserialize<D>(deserializer: D) -> Result<SparseVector<TMapping>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        BTreeMap::deserialize(deserializer).map(SparseVector::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use std::collections::BTreeMap;
+
+    use crate::types::{
+        prelude::*,
+        private::field,
+    };
+
+    #[test]
+    fn serialise_mapping_default() {
+        let ser = serde_json::to_value(&field::serialize(DefaultSparseVectorMapping)).unwrap();
+
+        assert_eq!(json!({ "type": "sparse_vector" }), ser);
+    }
+
+    #[test]
+    fn serialise_sparse_vector() {
+        let mut weights = BTreeMap::new();
+        weights.insert("rust".to_owned(), 1.5);
+
+        let vector: SparseVector<DefaultSparseVectorMapping> = SparseVector::new(weights);
+
+        let ser = serde_json::to_value(&vector).unwrap();
+
+        assert_eq!(json!({ "rust": 1.5 }), ser);
+    }
+
+    #[test]
+    fn deserialise_sparse_vector() {
+        let vector: SparseVector<DefaultSparseVectorMapping> =
+            serde_json::from_str(r#"{"rust":1.5}"#).unwrap();
+
+        assert_eq!(Some(&1.5), vector.get("rust"));
+    }
+}
@@ -0,0 +1,83 @@
+/*! Mapping for the Elasticsearch `sparse_vector` type. */
+
+/** A field that will be mapped as a `sparse_vector`. */
+pub trait SparseVectorFieldType<TMapping> {}
+
+/**
+The base requirements for mapping a `sparse_vector` type.
+
+A `sparse_vector` doesn't have any parameters to map,
+so custom mappings only need to implement this trait.
+
+# Examples
+
+Define a custom `SparseVectorMapping`:
+
+```
+# use elastic::types::prelude::*;
+#[derive(Default)]
+struct MySparseVectorMapping;
+impl SparseVectorMapping for MySparseVectorMapping {}
+```
+*/
+pub trait SparseVectorMapping {}
+
+/** Default mapping for a `sparse_vector`. */
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DefaultSparseVectorMapping;
+impl SparseVectorMapping for DefaultSparseVectorMapping {}
+
+mod private {
+    use super::{
+        SparseVectorFieldType,
+        SparseVectorMapping,
+    };
+    use crate::types::private::field::{
+        FieldMapping,
+        FieldType,
+        SerializeFieldMapping,
+        StaticSerialize,
+    };
+    use serde::{
+        ser::SerializeStruct,
+        Serialize,
+        Serializer,
+    };
+
+    #[derive(Default)]
+    pub struct SparseVectorPivot;
+
+    impl<TField, TMapping> FieldType<TMapping, SparseVectorPivot> for TField
+    where
+        TField: SparseVectorFieldType<TMapping> + Serialize,
+        TMapping: SparseVectorMapping,
+    {
+    }
+
+    impl<TMapping> FieldMapping<SparseVectorPivot> for TMapping
+    where
+        TMapping: SparseVectorMapping,
+    {
+        type SerializeFieldMapping = SerializeFieldMapping<TMapping, SparseVectorPivot>;
+
+        fn data_type() -> &'static str {
+            "sparse_vector"
+        }
+    }
+
+    impl<TMapping> StaticSerialize for SerializeFieldMapping<TMapping, SparseVectorPivot>
+    where
+        TMapping: FieldMapping<SparseVectorPivot> + SparseVectorMapping,
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("mapping", 1)?;
+
+            state.serialize_field("type", TMapping::data_type())?;
+
+            state.end()
+        }
+    }
+}
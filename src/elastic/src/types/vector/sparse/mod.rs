@@ -0,0 +1,38 @@
+/*!
+Implementation of the Elasticsearch `sparse_vector` type.
+
+A `sparse_vector` field indexes a map of tokens to their weights,
+like the output of a learned sparse retrieval model such as ELSER.
+
+# Examples
+
+Map a `sparse_vector`:
+
+```
+# use elastic::types::prelude::*;
+struct MyType {
+    pub tokens: SparseVector<DefaultSparseVectorMapping>
+}
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/current/sparse-vector.html)
+*/
+pub mod mapping;
+
+mod impls;
+pub use self::impls::*;
+
+pub mod prelude {
+    /*!
+    Includes all types for the `sparse_vector` type.
+
+    This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+    */
+
+    pub use super::{
+        impls::*,
+        mapping::*,
+    };
+}
@@ -19,6 +19,7 @@ use syn::{
     Ident,
     Lit,
     LitStr,
+    Type,
     Visibility,
 };
 
@@ -42,24 +43,30 @@ pub fn expand_derive(
     crate_root: proc_macro2::TokenStream,
     input: &DeriveInput,
 ) -> Result<Vec<proc_macro2::TokenStream>, DeriveElasticTypeError> {
-    // Annotatable item for a struct with struct fields
+    // Annotatable item for a struct with struct fields, or a newtype
     let fields = match &input.data {
         Data::Struct(DataStruct {
             fields: Fields::Named(fields),
             ..
         }) => Ok(&fields.named),
+        Data::Struct(DataStruct {
+            fields: Fields::Unnamed(fields),
+            ..
+        }) if fields.unnamed.len() == 1 => {
+            return Ok(vec![expand_newtype(&crate_root, input, &fields.unnamed[0])]);
+        }
         _ => Err(DeriveElasticTypeError::InvalidInput),
     }?;
 
     // Get the serializable fields
-    let fields: Vec<(Ident, &Field)> = fields
+    let fields: Vec<(String, &Field)> = fields
         .iter()
         .map(|f| get_ser_field(f))
         .filter(|f| f.is_some())
         .map(|f| f.unwrap())
         .collect();
 
-    let mapping = get_mapping(&crate_root, input);
+    let mapping = get_mapping(&crate_root, input, &fields)?;
 
     let doc_ty_impl_block = get_doc_ty_impl_block(&crate_root, input, &fields, &mapping.ident);
 
@@ -85,10 +92,59 @@ pub fn expand_derive(
     )])
 }
 
+/**
+Derive a field type for a newtype, like `struct Email(String)`.
+
+The newtype is mapped the same as its inner type,
+unless a mapping is supplied by `#[elastic(mapping="<ident>")]`.
+*/
+fn expand_newtype(
+    crate_root: &proc_macro2::TokenStream,
+    input: &DeriveInput,
+    field: &Field,
+) -> proc_macro2::TokenStream {
+    let newtype = &input.ident;
+    let inner = &field.ty;
+
+    let mapping = get_elastic_meta_items(&input.attrs)
+        .iter()
+        .filter_map(|meta| expect_name_value("mapping", meta))
+        .next()
+        .and_then(|v| get_ident_from_lit(v).ok());
+
+    let impl_block = match mapping {
+        Some(mapping) => quote!(
+            impl<TPivot> #crate_root::__derive::WrappedFieldType<#mapping, TPivot> for #newtype
+            where
+                #mapping: #crate_root::__derive::FieldMapping<TPivot>,
+            {
+            }
+        ),
+        None => quote!(
+            impl<TMapping, TPivot> #crate_root::__derive::WrappedFieldType<TMapping, TPivot> for #newtype
+            where
+                #inner: #crate_root::__derive::FieldType<TMapping, TPivot>,
+                TMapping: #crate_root::__derive::FieldMapping<TPivot>,
+            {
+            }
+        ),
+    };
+
+    let dummy_wrapper = quote::format_ident!("_IMPL_EASTIC_TYPE_FOR_{}", newtype);
+
+    quote!(
+        #[allow(non_upper_case_globals, dead_code, unused_variables)]
+        const #dummy_wrapper: () = {
+            #impl_block
+        };
+    )
+}
+
 fn get_mapping(
     crate_root: &proc_macro2::TokenStream,
     input: &DeriveInput,
-) -> ElasticDocumentMapping {
+    fields: &[(String, &Field)],
+) -> Result<ElasticDocumentMapping, DeriveElasticTypeError> {
     // Define a struct for the mapping with a few defaults
     fn define_mapping(vis: &Visibility, name: &Ident) -> proc_macro2::TokenStream {
         quote!(
@@ -114,35 +170,91 @@ fn get_mapping(
         val.and_then(|v| get_ident_from_lit(v).ok())
     }
 
+    // Get the serialised names of fields marked with #[elastic(required)]
+    fn get_required_fields(fields: &[(String, &Field)]) -> Vec<String> {
+        fields
+            .iter()
+            .filter(|&&(_, ref field)| {
+                get_elastic_meta_items(&field.attrs)
+                    .iter()
+                    .any(|meta| expect_ident("required", meta))
+            })
+            .map(|&(ref name, _)| name.clone())
+            .collect()
+    }
+
+    // Get the types of fields marked with #[serde(flatten)], whose required fields are merged in if they're document types
+    fn get_flattened_types(fields: &[(String, &Field)]) -> Vec<Type> {
+        fields
+            .iter()
+            .filter(|&&(_, field)| is_flattened(field))
+            .map(|&(_, field)| field.ty.clone())
+            .collect()
+    }
+
     // Implement DocumentMapping for the mapping
     fn impl_document_mapping(
         crate_root: &proc_macro2::TokenStream,
         mapping: &Ident,
         properties: &Ident,
+        required: &[String],
+        flattened: &[Type],
     ) -> proc_macro2::TokenStream {
+        let use_flattened = if flattened.is_empty() {
+            proc_macro2::TokenStream::new()
+        } else {
+            quote!(use #crate_root::__derive::{FlattenedFallback as _, FlattenedProperties as _};)
+        };
+
+        let meta = if required.is_empty() && flattened.is_empty() {
+            proc_macro2::TokenStream::new()
+        } else {
+            quote!(
+                fn meta() -> Option<#crate_root::__derive::Value> {
+                    #use_flattened
+
+                    #crate_root::__derive::required_meta(
+                        &[#(#required),*],
+                        &[#((&#crate_root::__derive::Flatten::<#flattened>::new()).flattened_meta()),*],
+                    )
+                }
+            )
+        };
+
         quote!(
             impl #crate_root::__derive::ObjectMapping for #mapping {
                 type Properties = #properties;
+
+                #meta
             }
         )
     }
 
+    let required = get_required_fields(fields);
+
     if let Some(ident) = get_mapping_from_attr(input) {
-        ElasticDocumentMapping {
+        // The `_meta` for a custom mapping is up to its `ObjectMapping` implementation
+        if !required.is_empty() {
+            return Err(DeriveElasticTypeError::RequiredWithMapping);
+        }
+
+        Ok(ElasticDocumentMapping {
             ident,
             definition: proc_macro2::TokenStream::new(),
             impl_block: proc_macro2::TokenStream::new(),
-        }
+        })
     } else {
         let ident = get_default_mapping(input);
         let definition = define_mapping(&input.vis, &ident);
-        let impl_block = impl_document_mapping(&crate_root, &ident, &input.ident);
+        let flattened = get_flattened_types(fields);
+        let impl_block =
+            impl_document_mapping(&crate_root, &ident, &input.ident, &required, &flattened);
 
-        ElasticDocumentMapping {
+        Ok(ElasticDocumentMapping {
             ident,
             definition,
             impl_block,
-        }
+        })
     }
 }
 
@@ -150,7 +262,7 @@ fn get_mapping(
 fn get_doc_ty_impl_block(
     crate_root: &proc_macro2::TokenStream,
     item: &DeriveInput,
-    fields: &[(Ident, &Field)],
+    fields: &[(String, &Field)],
     mapping: &Ident,
 ) -> proc_macro2::TokenStream {
     struct MetadataBlock {
@@ -162,7 +274,7 @@ fn get_doc_ty_impl_block(
     fn get_doc_ty_methods(
         crate_root: &proc_macro2::TokenStream,
         item: &DeriveInput,
-        fields: &[(Ident, &Field)],
+        fields: &[(String, &Field)],
     ) -> MetadataBlock {
         struct ElasticMetadataMethods {
             index: proc_macro2::TokenStream,
@@ -176,7 +288,7 @@ fn get_doc_ty_impl_block(
         fn get_doc_type_methods(
             crate_root: &proc_macro2::TokenStream,
             item: &DeriveInput,
-            fields: &[(Ident, &Field)],
+            fields: &[(String, &Field)],
         ) -> ElasticMetadataMethods {
             // Get the default name for the indexed elasticsearch type name
             fn get_elastic_type_name(item: &DeriveInput) -> Lit {
@@ -338,47 +450,224 @@ fn get_doc_ty_impl_block(
 fn get_props_impl_block(
     crate_root: &proc_macro2::TokenStream,
     props_ty: &Ident,
-    fields: &[(Ident, &Field)],
+    fields: &[(String, &Field)],
 ) -> proc_macro2::TokenStream {
     // Get the serde serialisation statements for each of the fields on the type being derived
+    // Fields are sorted by name so the mapping doesn't depend on the order they're declared in
     fn get_field_ser_stmts(
         crate_root: &proc_macro2::TokenStream,
-        fields: &[(Ident, &Field)],
+        fields: &[(String, &Field)],
     ) -> Vec<proc_macro2::TokenStream> {
+        let mut fields = fields.to_vec();
+        fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+
         let fields: Vec<proc_macro2::TokenStream> = fields
-            .iter()
-            .cloned()
+            .into_iter()
             .map(|(name, field)| {
-                let lit = Lit::Str(LitStr::new(
-                    &name.to_string(),
-                    proc_macro2::Span::call_site(),
-                ));
+                let lit = Lit::Str(LitStr::new(&name, proc_macro2::Span::call_site()));
                 let ty = &field.ty;
+                let params = get_field_params(crate_root, field);
 
-                quote!(#crate_root::__derive::field_ser::<#ty, _, _, _>(state, #lit)?;)
+                match (get_field_mapping(field), params.is_empty()) {
+                    (Some(mapping), true) => {
+                        quote!(#crate_root::__derive::mapping_ser::<#mapping, _, _>(state, #lit)?;)
+                    }
+                    (Some(mapping), false) => {
+                        quote!(#crate_root::__derive::mapping_ser_with_params::<#mapping, _, _>(state, #lit, &[#(#params),*])?;)
+                    }
+                    (None, true) => {
+                        quote!(#crate_root::__derive::field_ser::<#ty, _, _, _>(state, #lit)?;)
+                    }
+                    (None, false) => {
+                        quote!(#crate_root::__derive::field_ser_with_params::<#ty, _, _, _>(state, #lit, &[#(#params),*])?;)
+                    }
+                }
             })
             .collect();
 
         fields
     }
 
-    let stmts = get_field_ser_stmts(crate_root, fields);
+    // Get the statements that collect dynamic templates for each of the fields on the type being derived
+    fn get_field_template_stmts(
+        crate_root: &proc_macro2::TokenStream,
+        fields: &[(String, &Field)],
+    ) -> Vec<proc_macro2::TokenStream> {
+        let mut fields = fields.to_vec();
+        fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        fields
+            .into_iter()
+            .map(|(name, field)| {
+                let lit = Lit::Str(LitStr::new(&name, proc_macro2::Span::call_site()));
+                let ty = &field.ty;
+
+                match get_field_mapping(field) {
+                    Some(mapping) => {
+                        quote!(#crate_root::__derive::mapping_templates::<#mapping, _>(path, #lit, templates)?;)
+                    }
+                    None => {
+                        quote!(#crate_root::__derive::field_templates::<#ty, _, _>(path, #lit, templates)?;)
+                    }
+                }
+            })
+            .collect()
+    }
+
+    // Flattened document types have their properties merged into the parent instead of being nested
+    // Other flattened fields, like a map, are mapped like any other field
+    let (flattened, fields): (Vec<(String, &Field)>, Vec<(String, &Field)>) = fields
+        .iter()
+        .cloned()
+        .partition(|&(_, field)| is_flattened(field));
+
+    let stmts = get_field_ser_stmts(crate_root, &fields);
     let stmts_len = stmts.len();
 
-    quote!(
-        impl #crate_root::__derive::PropertiesMapping for #props_ty {
-            fn props_len() -> usize { #stmts_len }
+    let flattened_names: Vec<Lit> = flattened
+        .iter()
+        .map(|&(ref name, _)| Lit::Str(LitStr::new(name, proc_macro2::Span::call_site())))
+        .collect();
+    let flattened: Vec<&Type> = flattened.iter().map(|&(_, field)| &field.ty).collect();
+    let use_flattened = if flattened.is_empty() {
+        proc_macro2::TokenStream::new()
+    } else {
+        quote!(use #crate_root::__derive::{FlattenedFallback as _, FlattenedProperties as _};)
+    };
+
+    let template_stmts = get_field_template_stmts(crate_root, &fields);
+    let templates = quote!(
+        fn dynamic_templates(path: &str, templates: &mut Vec<#crate_root::__derive::Value>) -> ::std::result::Result<(), #crate_root::__derive::JsonError> {
+            #use_flattened
+
+            #(#template_stmts)*
+            #((&#crate_root::__derive::Flatten::<#flattened>::new()).flattened_templates(path, #flattened_names, templates)?;)*
+            Ok(())
+        }
+    );
 
-            fn serialize_props<S>(state: &mut S) -> ::std::result::Result<(), S::Error>
-                where S: #crate_root::__derive::SerializeStruct {
-                #(#stmts)*
-                Ok(())
+    // Own fields are sorted here, but properties merged from flattened fields have to be sorted at runtime
+    if flattened.is_empty() {
+        quote!(
+            impl #crate_root::__derive::PropertiesMapping for #props_ty {
+                fn props_len() -> usize {
+                    #stmts_len
+                }
+
+                fn serialize_props<S>(state: &mut S) -> ::std::result::Result<(), S::Error>
+                    where S: #crate_root::__derive::SerializeStruct {
+                    #(#stmts)*
+                    Ok(())
+                }
+
+                #templates
+            }
+        )
+    } else {
+        quote!(
+            impl #crate_root::__derive::UnsortedPropertiesMapping for #props_ty {
+                fn serialize_unsorted_props<S>(state: &mut S) -> ::std::result::Result<(), S::Error>
+                    where S: #crate_root::__derive::SerializeStruct {
+                    #use_flattened
+
+                    #(#stmts)*
+                    #((&#crate_root::__derive::Flatten::<#flattened>::new()).flattened_props_ser(state, #flattened_names)?;)*
+                    Ok(())
+                }
+            }
+
+            impl #crate_root::__derive::PropertiesMapping for #props_ty {
+                fn props_len() -> usize {
+                    #use_flattened
+
+                    #stmts_len #(+ (&#crate_root::__derive::Flatten::<#flattened>::new()).flattened_props_len())*
+                }
+
+                fn serialize_props<S>(state: &mut S) -> ::std::result::Result<(), S::Error>
+                    where S: #crate_root::__derive::SerializeStruct {
+                    #crate_root::__derive::sorted_props_ser::<Self, S>(state)
+                }
+
+                #templates
             }
+        )
+    }
+}
+
+// Get the mapping type for a field from `#[elastic(mapping = "MyMapping")]`
+fn get_field_mapping(field: &Field) -> Option<Type> {
+    let val = get_elastic_meta_items(&field.attrs);
+
+    val.iter()
+        .filter_map(|meta| expect_name_value("mapping", meta))
+        .next()
+        .map(|lit| match *lit {
+            Lit::Str(ref mapping) => mapping.parse::<Type>().unwrap_or_else(|e| panic!("{}", e)),
+            _ => panic!("mapping attributes on a field must be of the form #[elastic(mapping = \"MyMapping\")]"),
+        })
+}
+
+// Get the mapping parameters that are overridden by attributes on a field
+fn get_field_params(
+    crate_root: &proc_macro2::TokenStream,
+    field: &Field,
+) -> Vec<proc_macro2::TokenStream> {
+    let val = get_elastic_meta_items(&field.attrs);
+    let mut params = Vec::new();
+
+    // Set the `index` parameter for `#[elastic(index = false)]` or `#[elastic(index = "no")]`
+    if let Some(lit) = val
+        .iter()
+        .filter_map(|meta| expect_name_value("index", meta))
+        .next()
+    {
+        let index = match *lit {
+            Lit::Bool(ref index) => index.value,
+            Lit::Str(ref index) => match index.value().as_str() {
+                "true" | "yes" => true,
+                "false" | "no" => false,
+                _ => panic!("index attributes on a field must be one of `true`, `false`, `\"yes\"` or `\"no\"`"),
+            },
+            _ => panic!("index attributes on a field must be of the form #[elastic(index = false)] or #[elastic(index = \"no\")]"),
+        };
+
+        params.push(quote!(("index", #crate_root::__derive::Value::Bool(#index))));
+    }
+
+    // Set the analyzers for `#[elastic(analyzer = "english", search_analyzer = "standard")]`
+    for param in &["analyzer", "search_analyzer"] {
+        if let Some(lit) = val
+            .iter()
+            .filter_map(|meta| expect_name_value(param, meta))
+            .next()
+        {
+            let analyzer = match *lit {
+                Lit::Str(ref analyzer) => analyzer.value(),
+                _ => panic!(
+                    "{} attributes on a field must be of the form #[elastic({} = \"name\")]",
+                    param, param
+                ),
+            };
+
+            params
+                .push(quote!((#param, #crate_root::__derive::Value::String(#analyzer.to_owned()))));
         }
-    )
+    }
+
+    params
+}
+
+fn is_flattened(field: &Field) -> bool {
+    let ctxt = serde_derive_internals::Ctxt::new();
+    let serde_field =
+        serde_attr::Field::from_ast(&ctxt, 0, field, None, &serde_attr::Default::None);
+
+    let flatten = serde_field.flatten();
+
+    ctxt.check().is_ok() && flatten
 }
 
-fn get_ser_field(field: &Field) -> Option<(Ident, &Field)> {
+fn get_ser_field(field: &Field) -> Option<(String, &Field)> {
     let ctxt = serde_derive_internals::Ctxt::new();
     let serde_field =
         serde_attr::Field::from_ast(&ctxt, 0, field, None, &serde_attr::Default::None);
@@ -393,20 +682,18 @@ fn get_ser_field(field: &Field) -> Option<(Ident, &Field)> {
         return None;
     }
 
-    Some((
-        proc_macro2::Ident::new(
-            serde_field.name().serialize_name().as_ref(),
-            proc_macro2::Span::call_site(),
-        ),
-        field,
-    ))
+    // The serialised name may not be a valid identifier, like `metrics.cpu`
+    Some((serde_field.name().serialize_name().to_owned(), field))
 }
 
 quick_error! {
     #[derive(Debug)]
     pub enum DeriveElasticTypeError {
         InvalidInput {
-            display("deriving a document type is only valid for structs")
+            display("deriving a document type is only valid for structs with named fields, or newtypes with a single field")
+        }
+        RequiredWithMapping {
+            display("`#[elastic(required)]` can't be used with a custom `#[elastic(mapping)]`, return the required fields from its `meta` instead")
         }
     }
 }
@@ -451,7 +738,7 @@ fn get_method_from_struct(item: &DeriveInput, method: &str) -> Option<MethodFrom
     None
 }
 
-fn get_method_from_fields(fields: &[(Ident, &Field)], method: &str) -> Option<MethodFromField> {
+fn get_method_from_fields(fields: &[(String, &Field)], method: &str) -> Option<MethodFromField> {
     for &(_, ref field) in fields {
         let val = get_elastic_meta_items(&field.attrs);
         let field = &field.ident;
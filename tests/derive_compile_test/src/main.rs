@@ -1,9 +1,15 @@
 /*!
 Test crate to ensure derive macros can be used in a fresh crate without any extra dependencies.
+
+`serde` is only used for `#[serde]` attributes on fields.
 */
 
 #[macro_use]
 extern crate elastic_derive;
+#[macro_use]
+extern crate serde_derive;
+
+use std::collections::HashMap;
 
 #[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
 #[elastic(date_format = "yyyy-MM-dd'T'HH:mm:ssZ")]
@@ -49,4 +55,54 @@ impl DerivedDocument3 {
     }
 }
 
+#[derive(ElasticType)]
+pub struct DerivedDocument4 {
+    #[elastic(index = "no")]
+    pub field1: String,
+    #[elastic(index = false)]
+    pub field2: i32,
+}
+
+#[derive(ElasticType)]
+pub struct DerivedDocument5 {
+    #[elastic(analyzer = "english", search_analyzer = "standard")]
+    pub field1: String,
+}
+
+#[derive(Default)]
+pub struct DerivedKeywordMapping;
+impl elastic::types::prelude::KeywordMapping for DerivedKeywordMapping {}
+
+#[derive(ElasticType)]
+pub struct DerivedDocument6 {
+    #[elastic(mapping = "DerivedKeywordMapping")]
+    pub field1: String,
+}
+
+#[derive(ElasticType)]
+pub struct DerivedNewtype(String);
+
+#[derive(ElasticType)]
+#[elastic(mapping = "DerivedKeywordMapping")]
+pub struct DerivedKeywordNewtype(String);
+
+#[derive(Serialize, ElasticType)]
+pub struct DerivedFlattenedInner {
+    pub field2: i32,
+}
+
+#[derive(Serialize, ElasticType)]
+pub struct DerivedFlattenedDocument {
+    pub field1: String,
+    #[serde(flatten)]
+    pub inner: DerivedFlattenedInner,
+}
+
+#[derive(Serialize, ElasticType)]
+pub struct DerivedFlattenedCatchAll {
+    pub field1: String,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
 fn main() {}